@@ -0,0 +1,127 @@
+use std::{
+    hint::black_box,
+    io::{self, IoSlice, Read, Write},
+};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use streams::{DualWriter, MultiWriter, SinkWriter};
+
+const SLICES: usize = 16;
+const SLICE_LEN: usize = 1024;
+
+fn write_vectored(c: &mut Criterion) {
+    let data = vec![vec![0xA5u8; SLICE_LEN]; SLICES];
+    let bufs: Vec<_> = data.iter().map(|d| IoSlice::new(d)).collect();
+
+    let mut group = c.benchmark_group("write_vectored_16_slices");
+
+    group.bench_function("scalar", |b| {
+        let mut writer1 = Vec::with_capacity(SLICES * SLICE_LEN);
+        let mut writer2 = Vec::with_capacity(SLICES * SLICE_LEN);
+
+        b.iter(|| {
+            writer1.clear();
+            writer2.clear();
+            let mut multi_writer = MultiWriter::new(vec![&mut writer1, &mut writer2]);
+
+            for buf in &data {
+                multi_writer.write_all(black_box(buf)).unwrap();
+            }
+        })
+    });
+
+    group.bench_function("vectored", |b| {
+        let mut writer1 = Vec::with_capacity(SLICES * SLICE_LEN);
+        let mut writer2 = Vec::with_capacity(SLICES * SLICE_LEN);
+
+        b.iter(|| {
+            writer1.clear();
+            writer2.clear();
+            let mut multi_writer = MultiWriter::new(vec![&mut writer1, &mut writer2]);
+
+            let written = multi_writer.write_vectored(black_box(&bufs)).unwrap();
+            assert_eq!(written, SLICES * SLICE_LEN);
+        })
+    });
+
+    group.finish();
+}
+
+fn copy_into_many_buffered(c: &mut Criterion) {
+    const INPUT_LEN: u64 = 16 * 1024 * 1024;
+
+    let mut group = c.benchmark_group("copy_into_many_buffered_16MiB");
+    group.throughput(Throughput::Bytes(INPUT_LEN));
+
+    for buf_size in [8 * 1024, 64 * 1024, 1024 * 1024] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(buf_size),
+            &buf_size,
+            |b, &buf_size| {
+                b.iter(|| {
+                    let mut reader = io::repeat(0xA5).take(INPUT_LEN);
+                    let (mut sink1, mut sink2, mut sink3) = (SinkWriter, SinkWriter, SinkWriter);
+
+                    streams::copy_into_many_buffered(
+                        &mut reader,
+                        vec![&mut sink1, &mut sink2, &mut sink3],
+                        buf_size,
+                    )
+                    .unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn two_writers(c: &mut Criterion) {
+    const WRITES: usize = 1024;
+    const WRITE_LEN: usize = 64;
+
+    let data = [0xA5u8; WRITE_LEN];
+
+    let mut group = c.benchmark_group("two_writers_64B_writes");
+    group.throughput(Throughput::Bytes((WRITES * WRITE_LEN) as u64));
+
+    group.bench_function("multi_writer", |b| {
+        let mut writer1 = Vec::with_capacity(WRITES * WRITE_LEN);
+        let mut writer2 = Vec::with_capacity(WRITES * WRITE_LEN);
+
+        b.iter(|| {
+            writer1.clear();
+            writer2.clear();
+            let mut multi_writer = MultiWriter::new(vec![&mut writer1, &mut writer2]);
+
+            for _ in 0..WRITES {
+                multi_writer.write_all(black_box(&data)).unwrap();
+            }
+        })
+    });
+
+    group.bench_function("dual_writer", |b| {
+        let mut writer1 = Vec::with_capacity(WRITES * WRITE_LEN);
+        let mut writer2 = Vec::with_capacity(WRITES * WRITE_LEN);
+
+        b.iter(|| {
+            writer1.clear();
+            writer2.clear();
+            let mut dual_writer = DualWriter::new(&mut writer1, &mut writer2);
+
+            for _ in 0..WRITES {
+                dual_writer.write_all(black_box(&data)).unwrap();
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    write_vectored,
+    copy_into_many_buffered,
+    two_writers
+);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+use clap::Parser;
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use streams::{BoxedMultiWriter, ErrorPolicy, PrefixWriter, RollingFileCopier};
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Copy log lines from standard input to standard error and a rotating log file, prefixing each line with a timestamp", long_about = None)]
+struct Args {
+    /// Also write the log to FILE, rotating to FILE.1, FILE.2, ... every MAX_BYTES bytes
+    #[arg(long, value_name = "FILE")]
+    log_file: Option<PathBuf>,
+
+    /// The maximum size of each log file, in bytes
+    #[arg(long, default_value_t = 1024 * 1024)]
+    max_bytes: u64,
+}
+
+/// Adapts a [`RollingFileCopier`] into a writer, so it can be part of a fan-out.
+struct RollingFileWriter<F: FnMut(usize) -> io::Result<File>> {
+    copier: RollingFileCopier<File, F>,
+}
+
+impl<F: FnMut(usize) -> io::Result<File>> Write for RollingFileWriter<F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let copied = self.copier.copy(&mut &buf[..])?;
+
+        Ok(copied as usize)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        // The files are unbuffered, and flushed by the copier when it rolls over.
+        Ok(())
+    }
+}
+
+/// Create the rotating log writer, opening its first file right away so a bad path is reported up front.
+fn rolling_file_writer(path: &Path, max_bytes: u64) -> io::Result<impl Write> {
+    let mut first = Some(File::create(path)?);
+    let path = path.to_path_buf();
+
+    let factory = move |index| match first.take() {
+        Some(file) => Ok(file),
+        None => {
+            let mut rotated = path.clone().into_os_string();
+            rotated.push(format!(".{index}"));
+            File::create(rotated)
+        }
+    };
+
+    Ok(RollingFileWriter {
+        copier: RollingFileCopier::new(factory, max_bytes),
+    })
+}
+
+/// A `[seconds.milliseconds] ` prefix for the current time.
+fn timestamp() -> Vec<u8> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    format!("[{}.{:03}] ", now.as_secs(), now.subsec_millis()).into_bytes()
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if args.max_bytes == 0 {
+        eprintln!("logsplit: --max-bytes must be greater than zero");
+        return ExitCode::FAILURE;
+    }
+
+    // A destination failing later on, e.g. when a rotated file can't be created, is disabled while the others keep going.
+    let mut multi_writer = BoxedMultiWriter::new(Vec::new());
+    let inner = multi_writer.multi_writer_mut();
+    inner.set_error_policy(ErrorPolicy::DisableOnError);
+    inner.push_named("standard error".to_string(), Box::new(io::stderr().lock()));
+
+    if let Some(path) = &args.log_file {
+        match rolling_file_writer(path, args.max_bytes) {
+            Ok(writer) => inner.push_named(path.display().to_string(), Box::new(writer)),
+            Err(e) => eprintln!(
+                "logsplit: {}: {e}; logging to standard error only",
+                path.display()
+            ),
+        }
+    }
+
+    // Prefixing outside the fan-out gives every destination the same timestamps.
+    let mut prefix_writer = PrefixWriter::new(multi_writer, timestamp);
+    let result = streams::copy_lines_into_many(io::stdin().lock(), vec![&mut prefix_writer]);
+
+    let multi_writer = prefix_writer.into_inner();
+    let inner = multi_writer.multi_writer();
+    for (index, e) in inner.disabled_writers() {
+        eprintln!("logsplit: {}: {e}", inner.label(*index).unwrap_or_default());
+    }
+
+    match result {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("logsplit: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
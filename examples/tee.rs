@@ -1,10 +1,11 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use std::{
     fs::{File, OpenOptions},
-    io::{self, Write},
+    io::{self, Read, Write},
     path::PathBuf,
+    process::ExitCode,
 };
-use streams::MultiWriter;
+use streams::{ErrorPolicy, MultiError, MultiWriter};
 
 #[derive(Parser, Debug)]
 #[command(version, about = "Copy standard input to each FILE and standard output", long_about = None)]
@@ -12,35 +13,135 @@ struct Args {
     #[arg(short, long)]
     append: bool,
 
+    /// Set behavior on write error; `-p` and `--output-error` without MODE mean `warn-nopipe`
+    #[arg(
+        short = 'p',
+        long,
+        value_enum,
+        value_name = "MODE",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "warn-nopipe"
+    )]
+    output_error: Option<OutputError>,
+
     #[arg(value_name = "FILE")]
     paths: Vec<PathBuf>,
 }
 
-fn main() {
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputError {
+    /// Diagnose errors writing to any output and keep writing to the others
+    Warn,
+    /// Diagnose errors writing to any output not a pipe
+    WarnNopipe,
+    /// Exit on error writing to any output
+    Exit,
+    /// Exit on error writing to any output not a pipe
+    ExitNopipe,
+}
+
+impl OutputError {
+    fn ignores_pipes(self) -> bool {
+        matches!(self, Self::WarnNopipe | Self::ExitNopipe)
+    }
+
+    fn exits(self) -> bool {
+        matches!(self, Self::Exit | Self::ExitNopipe)
+    }
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
+    let mode = args.output_error.unwrap_or(OutputError::Exit);
+    let mut failed = false;
 
-    let mut files = if args.append {
-        args.paths
-            .iter()
-            .map(|p| {
-                OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(p)
-                    .unwrap()
-            })
-            .collect::<Vec<_>>()
-    } else {
-        args.paths
-            .iter()
-            .map(|p| File::create(p).unwrap())
-            .collect::<Vec<_>>()
-    };
+    let mut files = Vec::new();
+    for path in &args.paths {
+        let file = if args.append {
+            OpenOptions::new().create(true).append(true).open(path)
+        } else {
+            File::create(path)
+        };
+
+        match file {
+            Ok(file) => files.push((path.display().to_string(), file)),
+            Err(e) => {
+                eprintln!("tee: {}: {e}", path.display());
+                failed = true;
+            }
+        }
+    }
 
-    let mut writers: Vec<_> = files.iter_mut().map(|f| f as &mut dyn Write).collect();
+    // Every writer is attempted, so that failures can be handled per writer according to `mode`.
     let mut stdout = io::stdout().lock();
-    writers.push(&mut stdout as &mut dyn Write);
+    let mut multi_writer =
+        MultiWriter::with_error_policy(Vec::new(), ErrorPolicy::ContinueAndCollect);
+    for (label, file) in &mut files {
+        multi_writer.push_named(label.clone(), file);
+    }
+    multi_writer.push_named("standard output".to_string(), &mut stdout);
+
+    let mut stdin = io::stdin().lock();
+    let mut buf = vec![0; streams::DEFAULT_BUF_SIZE];
+
+    while !multi_writer.is_empty() {
+        let n = match stdin.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => {
+                eprintln!("tee: standard input: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let errors = match multi_writer.write_all(&buf[..n]) {
+            Ok(()) => multi_writer.take_errors(),
+            Err(e) => into_errors(e),
+        };
+
+        // Failed writers are removed in reverse, so the remaining indices stay valid.
+        let mut diagnosed = Vec::new();
+        for (index, e) in errors.into_iter().rev() {
+            let label = multi_writer.label(index).unwrap_or_default().to_string();
+            multi_writer.remove(index);
+
+            if !(mode.ignores_pipes() && e.kind() == io::ErrorKind::BrokenPipe) {
+                diagnosed.push((label, e));
+            }
+        }
+
+        for (label, e) in diagnosed.iter().rev() {
+            eprintln!("tee: {label}: {e}");
+        }
+
+        if !diagnosed.is_empty() {
+            if mode.exits() {
+                return ExitCode::FAILURE;
+            }
+            failed = true;
+        }
+    }
+
+    if let Err(e) = multi_writer.flush() {
+        eprintln!("tee: {e}");
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Extract the errors of every writer from the error returned when all of them failed.
+fn into_errors(e: io::Error) -> Vec<(usize, io::Error)> {
+    let errors = e
+        .into_inner()
+        .and_then(|inner| inner.downcast::<MultiError>().ok())
+        .expect("errors are collected under `ErrorPolicy::ContinueAndCollect`");
 
-    let mut multi_writer = MultiWriter::new(writers);
-    io::copy(&mut io::stdin().lock(), &mut multi_writer).unwrap();
+    errors.into_errors()
 }
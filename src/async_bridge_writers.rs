@@ -0,0 +1,227 @@
+use std::io::{self, Write};
+
+use tokio::{
+    io::{AsyncWrite, AsyncWriteExt},
+    runtime::Handle,
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+
+/// A message sent from an [`AsyncBridgeWriter`] to the task driving its asynchronous writer.
+enum Command {
+    Write(Vec<u8>),
+    Flush(oneshot::Sender<io::Result<()>>),
+}
+
+/// Provides a synchronous [`Writer`](Write) that forwards all data to an [`AsyncWrite`] driven by a tokio task,
+/// so an asynchronous destination can be one of the writers of a plain [`MultiWriter`](crate::MultiWriter).
+///
+/// Each [`write`](AsyncBridgeWriter::write) copies the buffer and sends it over a bounded channel to a task spawned on the given runtime,
+/// which writes it into the asynchronous writer with [`write_all`](AsyncWriteExt::write_all).
+/// Once `capacity` buffers are waiting in the channel, `write` blocks until the task catches up, applying backpressure to the synchronous side.
+/// [`flush`](AsyncBridgeWriter::flush) blocks until every buffer sent before it has been written, and the asynchronous writer has been flushed.
+///
+/// When the `AsyncBridgeWriter` is dropped, the task writes the remaining buffers, flushes the asynchronous writer and completes,
+/// returning it through the [`JoinHandle`] returned by [`new`](AsyncBridgeWriter::new).
+///
+/// # Runtime
+///
+/// The task is spawned using a [`Handle`] to a tokio runtime, which must keep running for as long as data is written.
+/// As `write` and `flush` block the current thread, they must not be called from within an asynchronous context,
+/// where tokio panics on blocking calls, but from a plain thread or a blocking task like those of [`spawn_blocking`](tokio::task::spawn_blocking).
+///
+/// # Errors
+///
+/// When writing or flushing the asynchronous writer fails, the task completes, returning the error through its [`JoinHandle`].
+/// A failed flush also returns the error from [`flush`](AsyncBridgeWriter::flush).
+/// Once the task has stopped, every call returns an error of kind [`BrokenPipe`](io::ErrorKind::BrokenPipe).
+/// As writes are asynchronous, a write error is only noticed by one of the following calls, and only after a failed flush are these guaranteed to fail.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::AsyncBridgeWriter;
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+/// let (mut bridge_writer, task) = AsyncBridgeWriter::new(runtime.handle(), Vec::new(), 4);
+///
+/// let thread = std::thread::spawn(move || {
+///     bridge_writer.write_all(b"Hello, world!")?;
+///     bridge_writer.flush()
+/// });
+///
+/// let output = runtime.block_on(task)??;
+/// thread.join().unwrap()?;
+/// assert_eq!(output, b"Hello, world!");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct AsyncBridgeWriter {
+    sender: mpsc::Sender<Command>,
+}
+
+impl AsyncBridgeWriter {
+    /// Spawn a task on the runtime of `handle` driving `writer`, returning the `AsyncBridgeWriter` feeding it
+    /// and the [`JoinHandle`] of the task, which returns `writer` once the `AsyncBridgeWriter` is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new<W: AsyncWrite + Unpin + Send + 'static>(
+        handle: &Handle,
+        writer: W,
+        capacity: usize,
+    ) -> (Self, JoinHandle<io::Result<W>>) {
+        assert!(capacity > 0, "capacity must be greater than zero");
+
+        let (sender, receiver) = mpsc::channel(capacity);
+        let task = handle.spawn(drive(writer, receiver));
+
+        (Self { sender }, task)
+    }
+
+    /// Send `command` to the task, blocking while the channel is full.
+    fn send(&self, command: Command) -> io::Result<()> {
+        self.sender
+            .blocking_send(command)
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "async writer task has stopped"))
+    }
+}
+
+/// Write the buffers received from an [`AsyncBridgeWriter`] into `writer` until the `AsyncBridgeWriter` is dropped.
+async fn drive<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    mut receiver: mpsc::Receiver<Command>,
+) -> io::Result<W> {
+    while let Some(command) = receiver.recv().await {
+        match command {
+            Command::Write(buf) => writer.write_all(&buf).await?,
+            Command::Flush(reply) => {
+                if let Err(e) = writer.flush().await {
+                    // `io::Error` isn't `Clone`, so the task returns an error of the same kind.
+                    let kind = e.kind();
+                    // Close the channel before replying, so any following write fails.
+                    receiver.close();
+                    let _ = reply.send(Err(e));
+                    return Err(io::Error::from(kind));
+                }
+
+                let _ = reply.send(Ok(()));
+            }
+        }
+    }
+
+    writer.flush().await?;
+
+    Ok(writer)
+}
+
+impl Write for AsyncBridgeWriter {
+    /// Send a copy of `buf` to the task, blocking while the channel is full.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`),
+    /// but the data may not have reached the asynchronous writer yet.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.send(Command::Write(buf.to_vec()))?;
+
+        Ok(buf.len())
+    }
+
+    /// Block until every buffer sent so far has been written and the asynchronous writer has been flushed.
+    fn flush(&mut self) -> io::Result<()> {
+        let (reply, response) = oneshot::channel();
+        self.send(Command::Flush(reply))?;
+
+        response.blocking_recv().map_err(|_| {
+            io::Error::new(io::ErrorKind::BrokenPipe, "async writer task has stopped")
+        })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use tokio::io::AsyncWrite;
+
+    use crate::{AsyncBridgeWriter, MultiWriter};
+
+    /// An asynchronous writer accepting everything but failing to flush.
+    struct UnflushableWriter;
+
+    impl AsyncWrite for UnflushableWriter {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Ready(Ok(buf.len()))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Err(io::Error::from(io::ErrorKind::PermissionDenied)))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn async_bridge_writer_in_multi_writer() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        // A capacity of one makes the synchronous side wait for the task after every buffer.
+        let (bridge_writer, task) = AsyncBridgeWriter::new(runtime.handle(), Vec::<u8>::new(), 1);
+
+        let thread = std::thread::spawn(move || {
+            let mut sync_writer = Vec::<u8>::new();
+            let mut bridge_writer = bridge_writer;
+            let mut multi_writer = MultiWriter::new(vec![&mut sync_writer, &mut bridge_writer]);
+
+            for _ in 0..100 {
+                multi_writer.write_all(b"Hello, world!").unwrap();
+            }
+            multi_writer.flush().unwrap();
+
+            sync_writer
+        });
+
+        let async_writer = runtime.block_on(task).unwrap().unwrap();
+        let sync_writer = thread.join().unwrap();
+        assert_eq!(async_writer, b"Hello, world!".repeat(100));
+        assert_eq!(sync_writer, async_writer);
+    }
+
+    #[test]
+    fn async_bridge_writer_flush_error() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let (mut bridge_writer, task) =
+            AsyncBridgeWriter::new(runtime.handle(), UnflushableWriter, 4);
+
+        let thread = std::thread::spawn(move || {
+            bridge_writer.write_all(b"Hello, world!").unwrap();
+            let flush_err = bridge_writer.flush().unwrap_err();
+            let write_err = bridge_writer.write_all(b"Hello, world!").unwrap_err();
+            (flush_err.kind(), write_err.kind())
+        });
+
+        let err = runtime.block_on(task).unwrap().map(|_| ()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(
+            thread.join().unwrap(),
+            (io::ErrorKind::PermissionDenied, io::ErrorKind::BrokenPipe)
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use tokio::io::AsyncWrite;
+
+use crate::MultiWriteError;
+
+/// Provides a single [`AsyncWrite`] that writes to multiple asynchronous writers.
+///
+/// This is the asynchronous counterpart of [`MultiWriter`](crate::MultiWriter) using [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast).
+/// [`poll_write`](AsyncWrite::poll_write) only returns `Poll::Ready(Ok(buf.len()))` once every internal writer has accepted the entire buffer.
+///
+/// # Pending Writers
+///
+/// The progress of each internal writer through the current buffer is tracked,
+/// so a writer returning [`Poll::Pending`] doesn't cause the writers that already accepted the buffer to receive it again.
+/// For this to work, a `poll_write` returning [`Poll::Pending`] must be followed by another `poll_write` with the same buffer,
+/// as done by [`AsyncWriteExt::write_all`](tokio::io::AsyncWriteExt::write_all).
+///
+/// # Errors
+///
+/// If any of the internal writers fail, the error is returned immediately, wrapping a [`MultiWriteError`] reporting the index of the failing writer.
+/// The progress through the current buffer is then discarded.
+pub struct AsyncMultiWriter<'a> {
+    writers: Vec<Pin<Box<dyn AsyncWrite + Send + 'a>>>,
+    progress: Vec<usize>,
+}
+
+impl<'a> AsyncMultiWriter<'a> {
+    pub fn new(writers: Vec<Pin<Box<dyn AsyncWrite + Send + 'a>>>) -> Self {
+        let progress = vec![0; writers.len()];
+
+        Self { writers, progress }
+    }
+
+    /// Poll `op` on each internal writer, returning [`Poll::Pending`] if any of them are pending.
+    fn poll_each(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut op: impl FnMut(
+            Pin<&mut (dyn AsyncWrite + Send + 'a)>,
+            &mut Context<'_>,
+        ) -> Poll<io::Result<()>>,
+    ) -> Poll<io::Result<()>> {
+        let mut pending = false;
+
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            match op(writer.as_mut(), cx) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(e)) => {
+                    return Poll::Ready(Err(MultiWriteError::new(index, e).into_io_error()))
+                }
+                Poll::Pending => pending = true,
+            }
+        }
+
+        if pending {
+            Poll::Pending
+        } else {
+            Poll::Ready(Ok(()))
+        }
+    }
+}
+
+impl<'a> AsyncWrite for AsyncMultiWriter<'a> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+
+        for (index, writer) in this.writers.iter_mut().enumerate() {
+            let written = &mut this.progress[index];
+
+            while *written < buf.len() {
+                match writer.as_mut().poll_write(cx, &buf[*written..]) {
+                    Poll::Ready(Ok(0)) => {
+                        this.progress.fill(0);
+                        let e = io::Error::from(io::ErrorKind::WriteZero);
+                        return Poll::Ready(Err(MultiWriteError::new(index, e).into_io_error()));
+                    }
+                    Poll::Ready(Ok(n)) => *written += n,
+                    Poll::Ready(Err(e)) => {
+                        this.progress.fill(0);
+                        return Poll::Ready(Err(MultiWriteError::new(index, e).into_io_error()));
+                    }
+                    Poll::Pending => break,
+                }
+            }
+        }
+
+        if this.progress.iter().all(|&w| w == buf.len()) {
+            this.progress.fill(0);
+            Poll::Ready(Ok(buf.len()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_each(cx, |w, cx| w.poll_flush(cx))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.get_mut().poll_each(cx, |w, cx| w.poll_shutdown(cx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io,
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+    use crate::{AsyncMultiWriter, MultiWriteError};
+
+    /// Alternates between returning `Poll::Pending` and accepting at most 4 bytes.
+    #[derive(Default)]
+    struct SlowWriter {
+        buf: Vec<u8>,
+        ready: bool,
+    }
+
+    impl AsyncWrite for SlowWriter {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.ready = !self.ready;
+
+            if self.ready {
+                let n = buf.len().min(4);
+                self.buf.extend_from_slice(&buf[..n]);
+                Poll::Ready(Ok(n))
+            } else {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[tokio::test]
+    async fn async_multi_writer() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = Vec::<u8>::new();
+        let mut multi_writer =
+            AsyncMultiWriter::new(vec![Box::pin(&mut writer1), Box::pin(&mut writer2)]);
+
+        multi_writer.write_all(b"Hello, world!").await.unwrap();
+        multi_writer.flush().await.unwrap();
+
+        drop(multi_writer);
+        assert_eq!(writer1, *b"Hello, world!");
+        assert_eq!(writer2, *b"Hello, world!");
+    }
+
+    #[tokio::test]
+    async fn async_multi_writer_pending_writer() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = SlowWriter::default();
+        let mut multi_writer =
+            AsyncMultiWriter::new(vec![Box::pin(&mut writer1), Box::pin(&mut writer2)]);
+
+        multi_writer.write_all(b"Hello, world!").await.unwrap();
+
+        drop(multi_writer);
+        assert_eq!(writer1, *b"Hello, world!");
+        assert_eq!(writer2.buf, *b"Hello, world!");
+    }
+
+    #[tokio::test]
+    async fn async_multi_writer_reports_failing_index() {
+        let mut writer1 = Vec::<u8>::new();
+        // A boxed slice can't grow, so the second writer runs out of space.
+        let mut writer2 = io::Cursor::new(vec![0u8; 5].into_boxed_slice());
+        let mut multi_writer =
+            AsyncMultiWriter::new(vec![Box::pin(&mut writer1), Box::pin(&mut writer2)]);
+
+        let err = multi_writer.write_all(b"Hello, world!").await.unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+    }
+}
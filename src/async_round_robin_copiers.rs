@@ -0,0 +1,95 @@
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncWrite};
+
+/// The asynchronous counterpart of [`RoundRobinCopier`](crate::RoundRobinCopier),
+/// copying each reader into the next of its internal writers in turn, wrapping around after the last.
+///
+/// # Examples
+///
+/// ```
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// use streams::AsyncRoundRobinCopier;
+///
+/// let mut copier = AsyncRoundRobinCopier::new(vec![Vec::new(), Vec::new()]);
+/// copier.copy(&mut &b"Hello"[..]).await?;
+/// copier.copy(&mut &b"world"[..]).await?;
+///
+/// assert_eq!(copier.into_inner(), [b"Hello", b"world"]);
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+pub struct AsyncRoundRobinCopier<W: AsyncWrite + Unpin> {
+    writers: Vec<W>,
+    current: usize,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncRoundRobinCopier<W> {
+    pub fn new(writers: Vec<W>) -> Self {
+        let current = 0;
+
+        Self { writers, current }
+    }
+
+    /// The index of the writer the next call to [`copy`](AsyncRoundRobinCopier::copy) will copy into.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Consumes the `AsyncRoundRobinCopier`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// Copy the entire contents of `reader` into the current writer using [`tokio::io::copy`], then advance to the next writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// Errors during the copy are returned as-is; the copier advances to the next writer regardless.
+    pub async fn copy<R: AsyncRead + Unpin + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "round-robin copier has no writers to copy into",
+            ));
+        }
+
+        let index = self.current;
+
+        // Increment the current index, wrapping around if we exceed the number of internal writers.
+        self.current = (self.current + 1) % self.writers.len();
+
+        tokio::io::copy(reader, &mut self.writers[index]).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::AsyncRoundRobinCopier;
+
+    #[tokio::test]
+    async fn async_round_robin_copier() {
+        let mut copier = AsyncRoundRobinCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+
+        let input = b"Hello, world!";
+        for _ in 0..4 {
+            copier.copy(&mut &input[..]).await.unwrap();
+        }
+
+        let writers = copier.into_inner();
+        assert_eq!(writers[0], b"Hello, world!Hello, world!");
+        assert_eq!(writers[1], b"Hello, world!");
+        assert_eq!(writers[2], b"Hello, world!");
+    }
+
+    #[tokio::test]
+    async fn async_round_robin_copier_without_writers() {
+        let mut copier = AsyncRoundRobinCopier::new(Vec::<Vec<u8>>::new());
+
+        let err = copier.copy(&mut &b"Hello"[..]).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,233 @@
+use std::io::{self, Write};
+
+use crate::Sink;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Wraps a writer, base64-encoding everything written to it.
+///
+/// The standard alphabet of [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-4) is used, without line breaks.
+/// Placing a `Base64Writer` inside a [`MultiWriter`](crate::MultiWriter) sends a text-safe copy of a binary stream to that destination,
+/// while its siblings receive the raw bytes.
+///
+/// Base64 encodes groups of three bytes, so up to two bytes of a write may stay buffered until the following write completes their group,
+/// even when the `Base64Writer` is flushed.
+/// They are encoded, along with the `=` padding, by [`finish`](Base64Writer::finish),
+/// or when the `Base64Writer` is dropped, ignoring any errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::Base64Writer;
+///
+/// let mut output = Vec::new();
+/// let mut base64_writer = Base64Writer::new(&mut output);
+/// base64_writer.write_all(b"Hello, ")?;
+/// base64_writer.write_all(b"world!")?;
+/// base64_writer.finish()?;
+/// drop(base64_writer);
+///
+/// assert_eq!(output, b"SGVsbG8sIHdvcmxkIQ==");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct Base64Writer<W: Write> {
+    writer: W,
+    pending: [u8; 3],
+    pending_len: usize,
+}
+
+impl<W: Write> Base64Writer<W> {
+    pub fn new(writer: W) -> Self {
+        let pending = [0; 3];
+        let pending_len = 0;
+
+        Self {
+            writer,
+            pending,
+            pending_len,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Encode the buffered bytes of an incomplete group, followed by padding, ending the base64 text.
+    ///
+    /// Bytes written afterwards start a new base64 text.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.pending_len == 0 {
+            return Ok(());
+        }
+
+        let mut quartet = encode_group(&self.pending[..self.pending_len]);
+        quartet[self.pending_len + 1..].fill(b'=');
+        self.pending_len = 0;
+
+        self.writer.write_all(&quartet)
+    }
+}
+
+/// Encode up to three bytes into four base64 characters, the unused trailing ones being garbage.
+fn encode_group(group: &[u8]) -> [u8; 4] {
+    let mut bytes = [0; 3];
+    bytes[..group.len()].copy_from_slice(group);
+    let n = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+    [18, 12, 6, 0].map(|shift| ALPHABET[(n >> shift) as usize & 0x3f])
+}
+
+impl<W: Write> Write for Base64Writer<W> {
+    /// Encode every complete group of three bytes and write the result to the internal writer, buffering the remainder.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut input = buf;
+        let mut encoded = Vec::with_capacity((self.pending_len + buf.len()) / 3 * 4);
+
+        if self.pending_len > 0 {
+            let n = input.len().min(3 - self.pending_len);
+            self.pending[self.pending_len..self.pending_len + n].copy_from_slice(&input[..n]);
+            self.pending_len += n;
+            input = &input[n..];
+
+            if self.pending_len < 3 {
+                return Ok(buf.len());
+            }
+
+            encoded.extend(encode_group(&self.pending));
+            self.pending_len = 0;
+        }
+
+        let mut groups = input.chunks_exact(3);
+        for group in &mut groups {
+            encoded.extend(encode_group(group));
+        }
+
+        let rest = groups.remainder();
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+
+        self.writer.write_all(&encoded)?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush the internal writer. The bytes of an incomplete group stay buffered.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Sink for Base64Writer<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Encode the buffered bytes and padding, then flush the internal writer.
+    fn finish(mut self) -> io::Result<()> {
+        Base64Writer::finish(&mut self)?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for Base64Writer<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{Base64Writer, MultiWriter};
+
+    fn decode(text: &[u8]) -> Vec<u8> {
+        let value = |c: u8| match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => panic!("invalid base64 character {c:?}"),
+        };
+
+        let mut output = Vec::new();
+        for quartet in text.chunks(4) {
+            let digits: Vec<u8> = quartet
+                .iter()
+                .take_while(|&&c| c != b'=')
+                .copied()
+                .collect();
+            let n = digits
+                .iter()
+                .fold(0u32, |n, &c| n << 6 | u32::from(value(c)))
+                << (6 * (4 - digits.len()));
+            output.extend(&n.to_be_bytes()[1..digits.len()]);
+        }
+
+        output
+    }
+
+    #[test]
+    fn base64_writer_padding() {
+        for (input, expected) in [
+            (&b""[..], &b""[..]),
+            (b"f", b"Zg=="),
+            (b"fo", b"Zm8="),
+            (b"foo", b"Zm9v"),
+            (b"foob", b"Zm9vYg=="),
+            (b"fooba", b"Zm9vYmE="),
+            (b"foobar", b"Zm9vYmFy"),
+        ] {
+            let mut output = Vec::new();
+            let mut base64_writer = Base64Writer::new(&mut output);
+            base64_writer.write_all(input).unwrap();
+            base64_writer.finish().unwrap();
+            drop(base64_writer);
+
+            assert_eq!(output, expected);
+        }
+    }
+
+    #[test]
+    fn base64_writer_round_trip() {
+        let payload: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut raw = Vec::<u8>::new();
+        let mut base64_writer = Base64Writer::new(Vec::new());
+        let mut multi_writer = MultiWriter::new(vec![&mut raw, &mut base64_writer]);
+
+        // Write in chunks of varying sizes to split groups across write boundaries.
+        for chunk in payload.chunks(7) {
+            let (a, b) = chunk.split_at(chunk.len().min(2));
+            multi_writer.write_all(a).unwrap();
+            multi_writer.write_all(b).unwrap();
+        }
+
+        base64_writer.finish().unwrap();
+
+        assert_eq!(raw, payload);
+        assert!(base64_writer.get_ref().is_ascii());
+        assert_eq!(decode(base64_writer.get_ref()), payload);
+    }
+
+    #[test]
+    fn base64_writer_finish_on_drop() {
+        let mut output = Vec::new();
+        let mut base64_writer = Base64Writer::new(&mut output);
+        base64_writer.write_all(b"Hi").unwrap();
+        base64_writer.flush().unwrap();
+        assert!(base64_writer.get_ref().is_empty());
+
+        drop(base64_writer);
+        assert_eq!(output, b"SGk=");
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::{self, IoSlice, Write};
+
+use crate::MultiWriter;
+
+/// A [`MultiWriter`] owning its writers as boxed trait objects, so writers of differing types and lifetimes can be mixed freely.
+///
+/// Unlike with [`MultiWriter::new`], the writers are not borrowed, so owned writers like a [`File`](std::fs::File)
+/// can be combined with a locked [`Stdout`](std::io::Stdout) or a writer borrowing local data,
+/// as long as each of them outlives `'a`.
+/// Writes behave exactly like those of the wrapped [`MultiWriter`],
+/// which can be accessed with [`multi_writer`](BoxedMultiWriter::multi_writer) and [`multi_writer_mut`](BoxedMultiWriter::multi_writer_mut),
+/// e.g. to change the [`ErrorPolicy`](crate::ErrorPolicy).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::{fs::File, io::{self, Write}};
+/// use streams::BoxedMultiWriter;
+///
+/// let mut multi_writer = BoxedMultiWriter::new(vec![Box::new(File::create("output.txt")?)]);
+/// multi_writer.push_boxed(Box::new(io::stdout().lock()));
+///
+/// multi_writer.write_all(b"Hello, world!")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct BoxedMultiWriter<'a> {
+    multi_writer: MultiWriter<Box<dyn Write + 'a>>,
+}
+
+impl<'a> BoxedMultiWriter<'a> {
+    pub fn new(writers: Vec<Box<dyn Write + 'a>>) -> Self {
+        let multi_writer = MultiWriter::from_writers(writers);
+
+        Self { multi_writer }
+    }
+
+    /// Append a boxed writer, which will receive all data written from now on. See [`MultiWriter::push`].
+    pub fn push_boxed(&mut self, writer: Box<dyn Write + 'a>) {
+        self.multi_writer.push(writer);
+    }
+
+    /// The number of internal writers.
+    pub fn len(&self) -> usize {
+        self.multi_writer.len()
+    }
+
+    /// Returns `true` if there are no internal writers.
+    pub fn is_empty(&self) -> bool {
+        self.multi_writer.is_empty()
+    }
+
+    /// Get a reference to the wrapped [`MultiWriter`].
+    pub fn multi_writer(&self) -> &MultiWriter<Box<dyn Write + 'a>> {
+        &self.multi_writer
+    }
+
+    /// Get a mutable reference to the wrapped [`MultiWriter`].
+    pub fn multi_writer_mut(&mut self) -> &mut MultiWriter<Box<dyn Write + 'a>> {
+        &mut self.multi_writer
+    }
+
+    /// Consumes the `BoxedMultiWriter`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<Box<dyn Write + 'a>> {
+        self.multi_writer.into_inner()
+    }
+}
+
+impl<'a> From<MultiWriter<Box<dyn Write + 'a>>> for BoxedMultiWriter<'a> {
+    /// Wrap a [`MultiWriter`] of boxed writers, e.g. one taken from a [`BuiltMultiWriter`](crate::BuiltMultiWriter).
+    fn from(multi_writer: MultiWriter<Box<dyn Write + 'a>>) -> Self {
+        Self { multi_writer }
+    }
+}
+
+impl<'a> Write for BoxedMultiWriter<'a> {
+    /// Write a buffer into each internal writer sequentially. See [`MultiWriter::write`].
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.multi_writer.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.multi_writer.write_vectored(bufs)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.multi_writer.flush()
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.multi_writer.write_all(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use crate::{BoxedMultiWriter, ErrorPolicy};
+
+    #[test]
+    fn boxed_multi_writer() {
+        let mut borrowed = Vec::<u8>::new();
+        let mut cursor_contents = Vec::<u8>::new();
+        let mut multi_writer =
+            BoxedMultiWriter::new(vec![Box::new(Cursor::new(&mut cursor_contents))]);
+        multi_writer.push_boxed(Box::new(Vec::<u8>::new()));
+        multi_writer.push_boxed(Box::new(&mut borrowed));
+        multi_writer
+            .multi_writer_mut()
+            .set_error_policy(ErrorPolicy::ContinueAndCollect);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        multi_writer.flush().unwrap();
+        assert_eq!(multi_writer.len(), 3);
+        assert_eq!(multi_writer.multi_writer().bytes_written(), [13, 13, 13]);
+
+        drop(multi_writer);
+        assert_eq!(cursor_contents, b"Hello, world!");
+        assert_eq!(borrowed, b"Hello, world!");
+    }
+}
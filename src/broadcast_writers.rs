@@ -0,0 +1,138 @@
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::MultiWriteError;
+
+/// Identifies a writer subscribed to a [`BroadcastWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(u64);
+
+/// Provides a [`Writer`](Write) that writes to a set of subscribed writers, which can change while data is flowing.
+///
+/// A `BroadcastWriter` is a shared handle: clones refer to the same set of subscribers,
+/// so writers can be [`subscribe`](BroadcastWriter::subscribe)d and [`unsubscribe`](BroadcastWriter::unsubscribe)d
+/// from other threads while one of the handles is being written to.
+/// A subscriber only receives data written after it subscribed.
+///
+/// Each [`write`](BroadcastWriter::write) locks the set of subscribers,
+/// writes the buffer to all of them in order of subscription using [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast) semantics,
+/// then unlocks it.
+/// Errors wrap a [`MultiWriteError`] whose index is the position of the failing subscriber among the current subscribers.
+///
+/// # Poisoning
+///
+/// If a subscriber panics while being written to, the panic propagates to the writing thread and the internal lock is poisoned.
+/// The `BroadcastWriter` deliberately ignores the poisoning, as the set of subscribers itself is never left in an inconsistent state:
+/// other handles can keep writing, subscribing and unsubscribing.
+/// The panicking subscriber stays subscribed, as its state is unknown; consider unsubscribing it.
+#[derive(Clone, Default)]
+pub struct BroadcastWriter {
+    inner: Arc<Mutex<Subscribers>>,
+}
+
+#[derive(Default)]
+struct Subscribers {
+    next_id: u64,
+    writers: Vec<(SubscriptionId, Box<dyn Write + Send>)>,
+}
+
+impl BroadcastWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe `writer` to all data written from now on.
+    pub fn subscribe(&self, writer: Box<dyn Write + Send>) -> SubscriptionId {
+        let mut subscribers = self.lock();
+
+        let id = SubscriptionId(subscribers.next_id);
+        subscribers.next_id += 1;
+        subscribers.writers.push((id, writer));
+
+        id
+    }
+
+    /// Unsubscribe the writer identified by `id`, returning it, or `None` if it was not subscribed.
+    pub fn unsubscribe(&self, id: SubscriptionId) -> Option<Box<dyn Write + Send>> {
+        let mut subscribers = self.lock();
+
+        let index = subscribers.writers.iter().position(|(i, _)| *i == id)?;
+        Some(subscribers.writers.remove(index).1)
+    }
+
+    /// The number of currently subscribed writers.
+    pub fn len(&self) -> usize {
+        self.lock().writers.len()
+    }
+
+    /// Returns `true` if no writers are subscribed.
+    pub fn is_empty(&self) -> bool {
+        self.lock().writers.is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Subscribers> {
+        // See the type-level documentation on poisoning.
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Write for BroadcastWriter {
+    /// Write a buffer into each subscribed writer sequentially.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for (index, (_, writer)) in self.lock().writers.iter_mut().enumerate() {
+            writer
+                .write_all(buf)
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Flush each subscribed writer sequentially.
+    fn flush(&mut self) -> io::Result<()> {
+        for (index, (_, writer)) in self.lock().writers.iter_mut().enumerate() {
+            writer
+                .flush()
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, thread};
+
+    use crate::{mocks::SyncSharedWriter, BroadcastWriter};
+
+    #[test]
+    fn broadcast_writer() {
+        let mut broadcast_writer = BroadcastWriter::new();
+        let sink1 = SyncSharedWriter::new();
+        let sink2 = SyncSharedWriter::new();
+
+        let id1 = broadcast_writer.subscribe(Box::new(sink1.clone()));
+        broadcast_writer.write_all(b"Hello, ").unwrap();
+
+        // Subscribe from another thread through a cloned handle.
+        let handle = broadcast_writer.clone();
+        let subscriber = sink2.clone();
+        thread::spawn(move || handle.subscribe(Box::new(subscriber)))
+            .join()
+            .unwrap();
+        assert_eq!(broadcast_writer.len(), 2);
+        broadcast_writer.write_all(b"world!").unwrap();
+
+        assert!(broadcast_writer.unsubscribe(id1).is_some());
+        assert!(broadcast_writer.unsubscribe(id1).is_none());
+        broadcast_writer.write_all(b"!!").unwrap();
+
+        assert_eq!(sink1.contents(), b"Hello, world!");
+        assert_eq!(sink2.contents(), b"world!!!");
+    }
+}
@@ -0,0 +1,271 @@
+use std::io::{self, Write};
+
+use crate::{MultiWriter, DEFAULT_BUF_SIZE};
+
+/// Wraps a [`MultiWriter`], coalescing many small writes into fewer, larger writes to the internal writers.
+///
+/// Written bytes are collected in an internal buffer,
+/// which is only written to the internal writers once it reaches its capacity or when [`flush`](BufferedMultiWriter::flush) is called.
+/// Writes at least as large as the capacity bypass the buffer entirely.
+/// The bytes reaching the internal writers are identical to those written through an unbuffered [`MultiWriter`].
+///
+/// Any remaining buffered bytes are written when the `BufferedMultiWriter` is dropped.
+/// As errors can't be reported from [`Drop`], call [`flush`](BufferedMultiWriter::flush) explicitly to handle them.
+///
+/// # Errors
+///
+/// If writing the buffered bytes to the internal writers fails, the bytes are kept in the buffer,
+/// and the next write or flush retries writing them.
+/// Under [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast), the retry resumes with the writer that failed,
+/// so the writers before it, which have already received the buffered bytes, don't receive them twice.
+/// Until the retry succeeds, every write attempts it first and fails with it, rather than buffering more bytes.
+/// Like after a failed [`write_all`](Write::write_all), the failing writer itself may have accepted the beginning of the bytes,
+/// which it then receives again.
+pub struct BufferedMultiWriter<W: Write> {
+    inner: MultiWriter<W>,
+    buf: Vec<u8>,
+    capacity: usize,
+    resume: usize,
+    retry_pending: bool,
+}
+
+impl<W: Write> BufferedMultiWriter<W> {
+    /// Create a `BufferedMultiWriter` with a capacity of [`DEFAULT_BUF_SIZE`] bytes.
+    pub fn new(inner: MultiWriter<W>) -> Self {
+        Self::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Create a `BufferedMultiWriter` buffering at most `capacity` bytes before writing to the internal writers.
+    pub fn with_capacity(capacity: usize, inner: MultiWriter<W>) -> Self {
+        let buf = Vec::with_capacity(capacity);
+        let resume = 0;
+
+        Self {
+            inner,
+            buf,
+            capacity,
+            resume,
+            retry_pending: false,
+        }
+    }
+
+    /// The number of bytes the internal buffer can hold before it is written to the internal writers.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The currently buffered bytes, not yet written to the internal writers.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Get a reference to the internal [`MultiWriter`].
+    pub fn get_ref(&self) -> &MultiWriter<W> {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the internal [`MultiWriter`].
+    ///
+    /// Writing directly to the internal [`MultiWriter`] bypasses any bytes still in the buffer.
+    pub fn get_mut(&mut self) -> &mut MultiWriter<W> {
+        &mut self.inner
+    }
+
+    /// Write the buffered bytes to the internal writers that haven't received them yet, without flushing them.
+    fn flush_buf(&mut self) -> io::Result<()> {
+        if !self.buf.is_empty() {
+            self.retry_pending = true;
+            self.inner.write_all_from(&mut self.resume, &self.buf)?;
+            self.buf.clear();
+            self.resume = 0;
+            self.retry_pending = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for BufferedMultiWriter<W> {
+    /// Buffer `buf`, writing the internal buffer to the internal writers first if `buf` doesn't fit.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // A buffer that failed to be written is retried before buffering more bytes,
+        // which also can't be added to a buffer that some of the writers have received already.
+        if self.retry_pending || self.buf.len() + buf.len() > self.capacity {
+            self.flush_buf()?;
+        }
+
+        if buf.len() >= self.capacity {
+            self.inner.write_all(buf)?;
+        } else {
+            self.buf.extend_from_slice(buf);
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Write the buffered bytes to the internal writers, then flush them.
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buf()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Drop for BufferedMultiWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.flush_buf();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{
+        mocks::{FailingWriter, SharedWriter},
+        BufferedMultiWriter, ErrorPolicy, MultiWriter,
+    };
+
+    #[test]
+    fn buffered_multi_writer_matches_unbuffered() {
+        let input = b"Hello, world! This is a stream of single-byte writes.";
+
+        let mut unbuffered1 = Vec::<u8>::new();
+        let mut unbuffered2 = Vec::<u8>::new();
+        let mut multi_writer = MultiWriter::new(vec![&mut unbuffered1, &mut unbuffered2]);
+        for byte in input {
+            multi_writer.write_all(&[*byte]).unwrap();
+        }
+
+        let mut buffered1 = Vec::<u8>::new();
+        let mut buffered2 = Vec::<u8>::new();
+        let mut buffered_multi_writer = BufferedMultiWriter::with_capacity(
+            8,
+            MultiWriter::new(vec![&mut buffered1, &mut buffered2]),
+        );
+        for byte in input {
+            buffered_multi_writer.write_all(&[*byte]).unwrap();
+        }
+        buffered_multi_writer.flush().unwrap();
+        drop(buffered_multi_writer);
+
+        assert_eq!(buffered1, unbuffered1);
+        assert_eq!(buffered2, unbuffered2);
+        assert_eq!(buffered1, input);
+    }
+
+    #[test]
+    fn buffered_multi_writer_coalesces_writes() {
+        let mut writer = SharedWriter::new();
+        let probe = writer.clone();
+        let mut buffered_multi_writer =
+            BufferedMultiWriter::with_capacity(4, MultiWriter::new(vec![&mut writer]));
+
+        buffered_multi_writer.write_all(b"abc").unwrap();
+        assert_eq!(probe.write_calls(), 0);
+        assert_eq!(buffered_multi_writer.buffer(), b"abc");
+
+        buffered_multi_writer.write_all(b"de").unwrap();
+        assert_eq!(probe.contents(), b"abc");
+
+        buffered_multi_writer.write_all(b"fghij").unwrap();
+        assert_eq!(probe.contents(), b"abcdefghij");
+        assert_eq!(probe.write_calls(), 3);
+    }
+
+    #[test]
+    fn buffered_multi_writer_flushes_on_drop() {
+        let mut writer = Vec::<u8>::new();
+        let mut buffered_multi_writer =
+            BufferedMultiWriter::with_capacity(64, MultiWriter::new(vec![&mut writer]));
+
+        buffered_multi_writer.write_all(b"Hello, world!").unwrap();
+        drop(buffered_multi_writer);
+
+        assert_eq!(writer, *b"Hello, world!");
+    }
+
+    #[test]
+    fn buffered_multi_writer_retry_resumes_failing_writer() {
+        /// Fails the first `failures` calls, then appends everything.
+        struct FlakyWriter {
+            failures: usize,
+            buf: Vec<u8>,
+        }
+
+        impl Write for FlakyWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.failures > 0 {
+                    self.failures -= 1;
+                    return Err(io::Error::from(io::ErrorKind::BrokenPipe));
+                }
+
+                self.buf.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FlakyWriter {
+            failures: 3,
+            buf: Vec::new(),
+        };
+        let mut writer3 = Vec::<u8>::new();
+        let mut buffered_multi_writer = BufferedMultiWriter::with_capacity(
+            64,
+            MultiWriter::with_error_policy(
+                vec![&mut writer1, &mut writer2, &mut writer3],
+                ErrorPolicy::FailFast,
+            ),
+        );
+
+        buffered_multi_writer.write_all(b"Hello, ").unwrap();
+        assert!(buffered_multi_writer.flush().is_err());
+        assert!(buffered_multi_writer.flush().is_err());
+        assert_eq!(buffered_multi_writer.buffer(), b"Hello, ");
+
+        assert!(buffered_multi_writer.write_all(b"world!").is_err());
+        buffered_multi_writer.write_all(b"world!").unwrap();
+        buffered_multi_writer.flush().unwrap();
+        assert!(buffered_multi_writer.buffer().is_empty());
+        assert_eq!(buffered_multi_writer.get_ref().stats().writes(), 2);
+        assert_eq!(
+            buffered_multi_writer.get_ref().bytes_written(),
+            [13, 13, 13]
+        );
+        drop(buffered_multi_writer);
+
+        assert_eq!(writer1, b"Hello, world!");
+        assert_eq!(writer2.buf, b"Hello, world!");
+        assert_eq!(writer3, b"Hello, world!");
+    }
+
+    #[test]
+    fn buffered_multi_writer_retries_failing_first_writer() {
+        let output = SharedWriter::new();
+        let mut buffered_multi_writer = BufferedMultiWriter::with_capacity(
+            64,
+            MultiWriter::from_writers(vec![
+                Box::new(FailingWriter::new(io::ErrorKind::BrokenPipe)) as Box<dyn Write>,
+                Box::new(output.clone()),
+            ]),
+        );
+
+        buffered_multi_writer.write_all(b"Hello, ").unwrap();
+        assert!(buffered_multi_writer.flush().is_err());
+
+        let err = buffered_multi_writer.write_all(b"world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(buffered_multi_writer.buffer(), b"Hello, ");
+
+        buffered_multi_writer.get_mut().remove(0);
+        buffered_multi_writer.write_all(b"world!").unwrap();
+        buffered_multi_writer.flush().unwrap();
+        assert_eq!(output.contents(), b"Hello, world!");
+    }
+}
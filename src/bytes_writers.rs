@@ -0,0 +1,77 @@
+use std::io::{self, Write};
+
+use bytes::Bytes;
+
+use crate::MultiWriter;
+
+/// Write an in-memory [`Bytes`] buffer into multiple writers, returning its length.
+///
+/// Every writer receives the shared buffer itself through [`write_all`](Write::write_all), using a [`MultiWriter`],
+/// so no intermediate copy of the source is made: writing only copies the data into each destination.
+/// Handles to the same buffer may be written elsewhere, e.g. on other threads, at the same time.
+///
+/// # Errors
+///
+/// Errors wrap a [`MultiWriteError`](crate::MultiWriteError) reporting the index of the failing writer, like for a [`MultiWriter`].
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+///
+/// let buf = Bytes::from_static(b"Hello, world!");
+/// let mut output = Vec::new();
+///
+/// assert_eq!(streams::write_bytes_into_many(&buf, vec![&mut output])?, 13);
+/// assert_eq!(output, buf);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn write_bytes_into_many(buf: &Bytes, writers: Vec<&mut dyn Write>) -> io::Result<u64> {
+    let mut multi_writer = MultiWriter::new(writers);
+    multi_writer.write_all(buf)?;
+
+    Ok(buf.len() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use bytes::Bytes;
+
+    use crate::{mocks::FailingWriter, MultiWriteError};
+
+    #[test]
+    fn write_bytes_into_many() {
+        let buf = Bytes::from(b"Hello, world!".repeat(1000));
+        let shared = buf.slice(13..);
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+
+        let written = crate::write_bytes_into_many(
+            &shared,
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+        )
+        .unwrap();
+
+        assert_eq!(written, 12_987);
+        for writer in writers {
+            assert_eq!(writer, shared);
+        }
+        // The slice shares the original allocation.
+        assert_eq!(shared.as_ptr(), buf[13..].as_ptr());
+    }
+
+    #[test]
+    fn write_bytes_into_many_reports_failing_index() {
+        let buf = Bytes::from_static(b"Hello, world!");
+        let mut output = Vec::<u8>::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+
+        let err = crate::write_bytes_into_many(&buf, vec![&mut output, &mut failing]).unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+    }
+}
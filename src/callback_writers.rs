@@ -0,0 +1,115 @@
+use std::io::{self, Write};
+
+/// Provides a [`Writer`](Write) calling a closure with every written buffer,
+/// for running arbitrary logic per chunk, such as updating metrics, without implementing [`Write`] yourself.
+///
+/// Flushing does nothing unless a flush closure is given with [`with_flush`](CallbackWriter::with_flush).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{CallbackWriter, MultiWriter};
+///
+/// let mut chunks = 0;
+/// let mut callback_writer = CallbackWriter::new(|_: &[u8]| {
+///     chunks += 1;
+///     Ok(())
+/// });
+/// let mut output = Vec::new();
+/// let mut multi_writer = MultiWriter::new(vec![&mut callback_writer, &mut output]);
+/// multi_writer.write_all(b"Hello")?;
+/// multi_writer.write_all(b"world")?;
+///
+/// assert_eq!(chunks, 2);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CallbackWriter<F: FnMut(&[u8]) -> io::Result<()>, G = fn() -> io::Result<()>> {
+    on_write: F,
+    on_flush: G,
+}
+
+impl<F: FnMut(&[u8]) -> io::Result<()>> CallbackWriter<F> {
+    pub fn new(on_write: F) -> Self {
+        Self {
+            on_write,
+            on_flush: || Ok(()),
+        }
+    }
+}
+
+impl<F: FnMut(&[u8]) -> io::Result<()>, G: FnMut() -> io::Result<()>> CallbackWriter<F, G> {
+    /// Call `on_flush` whenever the `CallbackWriter` is flushed.
+    pub fn with_flush<H: FnMut() -> io::Result<()>>(self, on_flush: H) -> CallbackWriter<F, H> {
+        CallbackWriter {
+            on_write: self.on_write,
+            on_flush,
+        }
+    }
+}
+
+impl<F: FnMut(&[u8]) -> io::Result<()>, G: FnMut() -> io::Result<()>> Write
+    for CallbackWriter<F, G>
+{
+    /// Call the write closure with the buffer. Empty buffers are not passed to the closure.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors returned by the closure are returned as-is.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        (self.on_write)(buf)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        (self.on_flush)()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{CallbackWriter, MultiWriter};
+
+    #[test]
+    fn callback_writer_in_multi_writer() {
+        let mut seen = 0;
+        let mut flushes = 0;
+        let mut callback_writer = CallbackWriter::new(|buf: &[u8]| {
+            seen += buf.len();
+            Ok(())
+        })
+        .with_flush(|| {
+            flushes += 1;
+            Ok(())
+        });
+        let mut output = Vec::<u8>::new();
+        let mut multi_writer = MultiWriter::new(vec![&mut callback_writer, &mut output]);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.write_all(b"world!").unwrap();
+        multi_writer.flush().unwrap();
+
+        assert_eq!(seen, 13);
+        assert_eq!(flushes, 1);
+        assert_eq!(output, b"Hello, world!");
+    }
+
+    #[test]
+    fn callback_writer_error() {
+        let mut callback_writer =
+            CallbackWriter::new(|_: &[u8]| Err(io::Error::from(io::ErrorKind::StorageFull)));
+
+        let err = callback_writer.write(b"Hello").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+        callback_writer.flush().unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    io::{self, Write},
+    sync::mpsc::Sender,
+};
+
+/// Provides a [`Writer`](Write) sending each written buffer over a channel, to be processed by the receiver, for instance on another thread.
+///
+/// Every non-empty call to [`write`](ChannelWriter::write) sends a copy of the entire buffer as a single message,
+/// so the receiver gets the exact chunks that were written, in order.
+/// Empty buffers are not sent.
+///
+/// # Examples
+///
+/// ```
+/// use std::{io::Write, sync::mpsc, thread};
+/// use streams::{ChannelWriter, MultiWriter};
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let consumer = thread::spawn(move || receiver.iter().map(|chunk: Vec<u8>| chunk.len()).sum::<usize>());
+///
+/// let mut channel_writer = ChannelWriter::new(sender);
+/// let mut output = Vec::new();
+/// let mut multi_writer = MultiWriter::new(vec![&mut channel_writer, &mut output]);
+/// multi_writer.write_all(b"Hello, world!")?;
+///
+/// drop(channel_writer);
+/// assert_eq!(consumer.join().unwrap(), 13);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct ChannelWriter {
+    sender: Sender<Vec<u8>>,
+}
+
+impl ChannelWriter {
+    pub fn new(sender: Sender<Vec<u8>>) -> Self {
+        Self { sender }
+    }
+
+    /// Consumes the `ChannelWriter`, returning the internal sender.
+    pub fn into_inner(self) -> Sender<Vec<u8>> {
+        self.sender
+    }
+}
+
+impl Write for ChannelWriter {
+    /// Send a copy of the buffer over the channel.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`BrokenPipe`](io::ErrorKind::BrokenPipe) if the receiver has been dropped.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.sender.send(buf.to_vec()).map_err(|_| {
+            io::Error::new(io::ErrorKind::BrokenPipe, "channel receiver was dropped")
+        })?;
+
+        Ok(buf.len())
+    }
+
+    /// Does nothing, as every write is sent immediately.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        sync::mpsc,
+        thread,
+    };
+
+    use crate::ChannelWriter;
+
+    #[test]
+    fn channel_writer() {
+        let (sender, receiver) = mpsc::channel();
+        let consumer = thread::spawn(move || receiver.iter().collect::<Vec<_>>());
+
+        let mut channel_writer = ChannelWriter::new(sender);
+        channel_writer.write_all(b"Hello").unwrap();
+        assert_eq!(channel_writer.write(b"").unwrap(), 0);
+        channel_writer.write_all(b", ").unwrap();
+        channel_writer.write_all(b"world!").unwrap();
+
+        drop(channel_writer);
+        assert_eq!(
+            consumer.join().unwrap(),
+            [b"Hello".to_vec(), b", ".to_vec(), b"world!".to_vec()]
+        );
+    }
+
+    #[test]
+    fn channel_writer_receiver_dropped() {
+        let (sender, receiver) = mpsc::channel();
+        let mut channel_writer = ChannelWriter::new(sender);
+
+        drop(receiver);
+        let err = channel_writer.write(b"Hello").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+}
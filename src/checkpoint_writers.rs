@@ -0,0 +1,157 @@
+use std::io::{self, Write};
+
+/// Wraps a writer, inserting a marker into the stream every `interval` bytes, e.g. to resume an interrupted transfer.
+///
+/// The marker is produced by `marker_fn`, which is called with the offset of the boundary,
+/// counting only the bytes written to the `CheckpointWriter`, not the markers.
+/// Each marker is written right after the data leading up to its boundary, so the first one follows the first `interval` bytes,
+/// and no marker is written at offset zero.
+/// The offset is tracked across writes, so the boundaries don't depend on how the stream is split into writes.
+///
+/// All writes to the internal writer use [`write_all`](Write::write_all).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::CheckpointWriter;
+///
+/// let mut checkpoint_writer = CheckpointWriter::new(Vec::new(), 5, |offset| format!("<{offset}>").into_bytes());
+/// checkpoint_writer.write_all(b"Hello, world!")?;
+///
+/// assert_eq!(checkpoint_writer.into_inner(), b"Hello<5>, wor<10>ld!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CheckpointWriter<W: Write, F: FnMut(u64) -> Vec<u8>> {
+    writer: W,
+    interval: u64,
+    marker_fn: F,
+    offset: u64,
+}
+
+impl<W: Write, F: FnMut(u64) -> Vec<u8>> CheckpointWriter<W, F> {
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn new(writer: W, interval: u64, marker_fn: F) -> Self {
+        assert!(interval > 0, "interval must be greater than zero");
+        let offset = 0;
+
+        Self {
+            writer,
+            interval,
+            marker_fn,
+            offset,
+        }
+    }
+
+    /// The number of bytes written so far, excluding markers.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    ///
+    /// Bytes written directly to the internal writer don't count towards the offset.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the `CheckpointWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, F: FnMut(u64) -> Vec<u8>> Write for CheckpointWriter<W, F> {
+    /// Write `buf`, inserting a marker at every boundary it crosses.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    /// If the internal writer fails, the offset includes the bytes written before the failure,
+    /// and a marker that failed to be written is not retried.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut rest = buf;
+
+        while !rest.is_empty() {
+            let until_boundary = self.interval - self.offset % self.interval;
+            let n = rest
+                .len()
+                .min(until_boundary.try_into().unwrap_or(usize::MAX));
+
+            self.writer.write_all(&rest[..n])?;
+            self.offset += n as u64;
+            rest = &rest[n..];
+
+            if n as u64 == until_boundary {
+                self.writer.write_all(&(self.marker_fn)(self.offset))?;
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::CheckpointWriter;
+
+    const MAGIC: &[u8] = b"\xffCP";
+
+    /// A marker of the magic bytes followed by the offset as big-endian bytes.
+    fn marker(offset: u64) -> Vec<u8> {
+        [MAGIC, &offset.to_be_bytes()].concat()
+    }
+
+    #[test]
+    fn checkpoint_writer() {
+        let input: Vec<u8> = (0..26).map(|i| b'a' + i).collect();
+        let mut checkpoint_writer = CheckpointWriter::new(Vec::new(), 8, marker);
+
+        // Writes of differing sizes, some crossing boundaries and one ending exactly at one.
+        for chunk in [&input[..3], &input[3..8], &input[8..20], &input[20..]] {
+            checkpoint_writer.write_all(chunk).unwrap();
+        }
+        assert_eq!(checkpoint_writer.offset(), 26);
+
+        let output = checkpoint_writer.into_inner();
+        let marker_len = MAGIC.len() + 8;
+        let mut expected = Vec::new();
+        for (i, data) in input.chunks(8).enumerate() {
+            expected.extend_from_slice(data);
+            if data.len() == 8 {
+                expected.extend(marker(8 * (i as u64 + 1)));
+            }
+        }
+        assert_eq!(output, expected);
+        assert_eq!(output.len(), 26 + 3 * marker_len);
+
+        // Markers sit right after the data of each boundary.
+        for checkpoint in 1..=3 {
+            let at = checkpoint * 8 + (checkpoint - 1) * marker_len;
+            assert_eq!(&output[at..at + MAGIC.len()], MAGIC);
+            let offset = u64::from_be_bytes(
+                output[at + MAGIC.len()..at + marker_len]
+                    .try_into()
+                    .unwrap(),
+            );
+            assert_eq!(offset, checkpoint as u64 * 8);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "interval must be greater than zero")]
+    fn checkpoint_writer_zero_interval() {
+        CheckpointWriter::new(Vec::new(), 0, marker);
+    }
+}
@@ -0,0 +1,133 @@
+use std::io::{self, Read, Write};
+
+/// Shards readers across its internal writers in fixed-size chunks:
+/// the first `chunk_size` bytes go to writer 0, the next `chunk_size` bytes to writer 1, and so on, wrapping around after the last.
+///
+/// Every chunk except the last of each [`copy_all`](ChunkedShardCopier::copy_all) is exactly `chunk_size` bytes long.
+/// The next call starts a new chunk with the writer after the one that received the final chunk.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::ChunkedShardCopier;
+///
+/// let mut writers = [Vec::<u8>::new(), Vec::new()];
+/// let mut copier = ChunkedShardCopier::new(
+///     writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+///     3,
+/// );
+///
+/// copier.copy_all(&mut &b"abcdefgh"[..])?;
+/// assert_eq!(writers[0], b"abcgh");
+/// assert_eq!(writers[1], b"def");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct ChunkedShardCopier<'a> {
+    writers: Vec<&'a mut dyn Write>,
+    chunk_size: u64,
+    current: usize,
+}
+
+impl<'a> ChunkedShardCopier<'a> {
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn new(writers: Vec<&'a mut dyn Write>, chunk_size: u64) -> Self {
+        assert!(chunk_size > 0, "chunk size must be greater than zero");
+        let current = 0;
+
+        Self {
+            writers,
+            chunk_size,
+            current,
+        }
+    }
+
+    /// The index of the writer that will receive the next chunk.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Copy the entire contents of `reader`, routing each chunk to the next writer in turn.
+    ///
+    /// Returns the total number of bytes copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// Errors during the copy are returned as-is, and the next call starts a new chunk with the writer that failed.
+    pub fn copy_all<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "chunked shard copier has no writers to copy into",
+            ));
+        }
+
+        let mut copied = 0;
+
+        loop {
+            let mut chunk = reader.take(self.chunk_size);
+            let n = io::copy(&mut chunk, self.writers[self.current])?;
+            if n == 0 {
+                return Ok(copied);
+            }
+
+            copied += n;
+            self.current = (self.current + 1) % self.writers.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::ChunkedShardCopier;
+
+    #[test]
+    fn chunked_shard_copier() {
+        let input = b"0123456789abcdefghijKLMNO";
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier = ChunkedShardCopier::new(
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            10,
+        );
+
+        assert_eq!(copier.copy_all(&mut &input[..]).unwrap(), 25);
+        assert_eq!(copier.current_index(), 0);
+
+        assert_eq!(writers[0], b"0123456789");
+        assert_eq!(writers[1], b"abcdefghij");
+        assert_eq!(writers[2], b"KLMNO");
+    }
+
+    #[test]
+    fn chunked_shard_copier_continues_rotation() {
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier =
+            ChunkedShardCopier::new(writers.iter_mut().map(|w| w as &mut dyn Write).collect(), 4);
+
+        copier.copy_all(&mut &b"Hello"[..]).unwrap();
+        assert_eq!(copier.current_index(), 2);
+        copier.copy_all(&mut &b"world"[..]).unwrap();
+        assert_eq!(copier.current_index(), 1);
+
+        assert_eq!(writers[0], b"Helld");
+        assert_eq!(writers[1], b"o");
+        assert_eq!(writers[2], b"worl");
+    }
+
+    #[test]
+    fn chunked_shard_copier_without_writers() {
+        let mut copier = ChunkedShardCopier::new(Vec::new(), 4);
+
+        let err = copier.copy_all(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "chunked shard copier has no writers to copy into"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::io::{self, Write};
+
+/// Wraps a writer, splitting every write into pieces of at most `max_chunk` bytes,
+/// for destinations that reject writes larger than some device-specific maximum.
+///
+/// Each piece is passed to the internal writer with [`write_all`](Write::write_all),
+/// so a [`write`](ChunkingWriter::write) either forwards the entire buffer or fails.
+/// Placing a `ChunkingWriter` inside a [`MultiWriter`](crate::MultiWriter) protects such a writer without limiting the sizes of the other writes.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::ChunkingWriter;
+///
+/// let mut chunking_writer = ChunkingWriter::new(Vec::new(), 4);
+/// assert_eq!(chunking_writer.write(b"Hello, world!")?, 13);
+///
+/// assert_eq!(chunking_writer.into_inner(), b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct ChunkingWriter<W: Write> {
+    writer: W,
+    max_chunk: usize,
+}
+
+impl<W: Write> ChunkingWriter<W> {
+    /// # Panics
+    ///
+    /// Panics if `max_chunk` is zero.
+    pub fn new(writer: W, max_chunk: usize) -> Self {
+        assert!(
+            max_chunk > 0,
+            "maximum chunk size must be greater than zero"
+        );
+
+        Self { writer, max_chunk }
+    }
+
+    /// The maximum number of bytes passed to the internal writer at once.
+    pub fn max_chunk(&self) -> usize {
+        self.max_chunk
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the `ChunkingWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Write for ChunkingWriter<W> {
+    /// Write `buf` into the internal writer in pieces of at most [`max_chunk`](ChunkingWriter::max_chunk) bytes.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    /// If the internal writer fails, the pieces before the failing one have already been written.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for chunk in buf.chunks(self.max_chunk) {
+            self.writer.write_all(chunk)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{ChunkingWriter, MultiWriter};
+
+    /// A writer rejecting any write of more than 4 bytes.
+    #[derive(Default)]
+    struct FragileWriter {
+        buf: Vec<u8>,
+        writes: usize,
+    }
+
+    impl Write for FragileWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.len() > 4 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "write too large",
+                ));
+            }
+
+            self.writes += 1;
+            self.buf.extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn chunking_writer() {
+        let mut fragile = FragileWriter::default();
+        assert!(fragile.write(b"Hello, world!").is_err());
+
+        let mut chunking_writer = ChunkingWriter::new(fragile, 4);
+        assert_eq!(chunking_writer.write(b"Hello, world!").unwrap(), 13);
+
+        let fragile = chunking_writer.into_inner();
+        assert_eq!(fragile.buf, b"Hello, world!");
+        assert_eq!(fragile.writes, 4);
+    }
+
+    #[test]
+    fn chunking_writer_in_multi_writer() {
+        let mut output = Vec::<u8>::new();
+        let mut chunking_writer = ChunkingWriter::new(FragileWriter::default(), 4);
+        let mut multi_writer = MultiWriter::new(vec![&mut output, &mut chunking_writer]);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+
+        assert_eq!(output, b"Hello, world!");
+        assert_eq!(chunking_writer.get_ref().buf, b"Hello, world!");
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum chunk size must be greater than zero")]
+    fn chunking_writer_zero_max_chunk() {
+        ChunkingWriter::new(Vec::new(), 0);
+    }
+}
@@ -0,0 +1,113 @@
+use std::io::{self, Write};
+
+use flate2::{write::GzEncoder, Compression};
+
+use crate::Sink;
+
+/// Wraps a writer, gzip-compressing everything written to it.
+///
+/// Placing a `CompressWriter` inside a [`MultiWriter`](crate::MultiWriter) writes a compressed copy of the stream to that destination,
+/// while its siblings receive the uncompressed bytes.
+///
+/// A gzip stream must be terminated by a trailer, written by [`finish`](CompressWriter::finish),
+/// which also reports any errors and returns the internal writer.
+/// Dropping a `CompressWriter` without finishing it makes a best-effort attempt to finish the stream, ignoring errors;
+/// if that fails, the compressed output is truncated.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Read, Write};
+/// use flate2::read::GzDecoder;
+/// use streams::CompressWriter;
+///
+/// let mut compress_writer = CompressWriter::new(Vec::new());
+/// compress_writer.write_all(b"Hello, world!")?;
+/// let compressed = compress_writer.finish()?;
+///
+/// let mut decompressed = Vec::new();
+/// GzDecoder::new(&compressed[..]).read_to_end(&mut decompressed)?;
+/// assert_eq!(decompressed, b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CompressWriter<W: Write> {
+    encoder: GzEncoder<W>,
+}
+
+impl<W: Write> CompressWriter<W> {
+    /// Create a `CompressWriter` using the default compression level.
+    pub fn new(writer: W) -> Self {
+        Self::with_level(writer, Compression::default().level())
+    }
+
+    /// Create a `CompressWriter` using the given compression level, from 0 (none) to 9 (best).
+    pub fn with_level(writer: W, level: u32) -> Self {
+        let encoder = GzEncoder::new(writer, Compression::new(level));
+
+        Self { encoder }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        self.encoder.get_ref()
+    }
+
+    /// Write the remaining compressed data and the gzip trailer, returning the internal writer.
+    pub fn finish(self) -> io::Result<W> {
+        self.encoder.finish()
+    }
+}
+
+impl<W: Write> Write for CompressWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.encoder.write(buf)
+    }
+
+    /// Flush the compressed data written so far to the internal writer.
+    ///
+    /// This does not finish the gzip stream, see [`finish`](CompressWriter::finish).
+    fn flush(&mut self) -> io::Result<()> {
+        self.encoder.flush()
+    }
+}
+
+impl<W: Write> Sink for CompressWriter<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Write the gzip trailer, then flush the internal writer.
+    fn finish(self) -> io::Result<()> {
+        CompressWriter::finish(self)?.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use flate2::read::GzDecoder;
+
+    use crate::{CompressWriter, MultiWriter};
+
+    #[test]
+    fn compress_writer_round_trip() {
+        let payload = b"Hello, world! ".repeat(100);
+        let mut raw = Vec::<u8>::new();
+        let mut compress_writer = CompressWriter::with_level(Vec::new(), 9);
+        let mut multi_writer = MultiWriter::new(vec![&mut raw, &mut compress_writer]);
+
+        multi_writer.write_all(&payload).unwrap();
+        multi_writer.flush().unwrap();
+
+        let compressed = compress_writer.finish().unwrap();
+        assert!(compressed.len() < payload.len());
+
+        let mut decompressed = Vec::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, payload);
+        assert_eq!(raw, payload);
+    }
+}
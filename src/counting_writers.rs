@@ -0,0 +1,105 @@
+use std::io::{self, IoSlice, Write};
+
+/// Wraps a writer, counting the bytes passing through it.
+///
+/// The count only includes bytes the internal writer actually accepted,
+/// as reported by its [`write`](Write::write) and [`write_vectored`](Write::write_vectored) calls.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{CountingWriter, MultiWriter};
+///
+/// let mut counting_writer = CountingWriter::new(Vec::new());
+/// let mut multi_writer = MultiWriter::new(vec![&mut counting_writer]);
+/// multi_writer.write_all(b"Hello, world!")?;
+///
+/// assert_eq!(counting_writer.bytes_written(), 13);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CountingWriter<W: Write> {
+    writer: W,
+    count: u64,
+}
+
+impl<W: Write> CountingWriter<W> {
+    pub fn new(writer: W) -> Self {
+        let count = 0;
+
+        Self { writer, count }
+    }
+
+    /// The number of bytes the internal writer has accepted so far.
+    pub fn bytes_written(&self) -> u64 {
+        self.count
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    ///
+    /// Bytes written directly to the internal writer are not counted.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the `CountingWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.writer.write(buf)?;
+        self.count += n as u64;
+
+        Ok(n)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let n = self.writer.write_vectored(bufs)?;
+        self.count += n as u64;
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::CountingWriter;
+
+    #[test]
+    fn counting_writer() {
+        let mut counting_writer = CountingWriter::new(Vec::new());
+
+        counting_writer.write_all(b"Hello, ").unwrap();
+        counting_writer.write_all(b"world!").unwrap();
+        counting_writer.flush().unwrap();
+
+        assert_eq!(counting_writer.bytes_written(), 13);
+        assert_eq!(counting_writer.into_inner(), b"Hello, world!");
+    }
+
+    #[test]
+    fn counting_writer_counts_accepted_bytes() {
+        let mut buf = [0u8; 5];
+        let mut counting_writer = CountingWriter::new(&mut buf[..]);
+
+        assert_eq!(counting_writer.write(b"Hello, world!").unwrap(), 5);
+        let err = counting_writer.write_all(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        assert_eq!(counting_writer.bytes_written(), 5);
+    }
+}
@@ -0,0 +1,204 @@
+use std::io::{self, Write};
+
+use crate::{
+    line_split_writers::{strip_newline, LineBuffer},
+    Sink,
+};
+
+/// Wraps a writer, collapsing runs of consecutive identical lines into a single line, like `uniq`.
+///
+/// A line occurring `N > 1` times in a row is forwarded once, followed by a ` (repeated N times)` marker before its line ending,
+/// where `N` counts every occurrence, like `uniq -c`.
+/// Lines are compared without their line endings, and the forwarded line keeps the ending of its first occurrence.
+///
+/// As a line can only be forwarded once a different line follows it, the most recent line stays buffered until then,
+/// even when the `DedupWriter` is flushed.
+/// It is forwarded, along with any trailing bytes not terminated by a newline, by [`finish`](DedupWriter::finish),
+/// or when the `DedupWriter` is dropped, ignoring any errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::DedupWriter;
+///
+/// let mut output = Vec::new();
+/// let mut dedup_writer = DedupWriter::new(&mut output);
+/// dedup_writer.write_all(b"retrying\nretrying\nretrying\ndone\n")?;
+/// drop(dedup_writer);
+///
+/// assert_eq!(output, b"retrying (repeated 3 times)\ndone\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct DedupWriter<W: Write> {
+    writer: W,
+    lines: LineBuffer,
+    last: Option<Vec<u8>>,
+    repeats: u64,
+}
+
+impl<W: Write> DedupWriter<W> {
+    pub fn new(writer: W) -> Self {
+        let lines = LineBuffer::default();
+        let last = None;
+        let repeats = 0;
+
+        Self {
+            writer,
+            lines,
+            last,
+            repeats,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Forward the buffered line, and any trailing bytes not terminated by a newline as a final line.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(line) = self.lines.take_rest() {
+            line_completed(&mut self.writer, &mut self.last, &mut self.repeats, &line)?;
+        }
+
+        if let Some(last) = self.last.take() {
+            emit(&mut self.writer, &last, self.repeats)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Count `line` as a repeat of `last`, or forward `last` and start a new run with `line`.
+fn line_completed<W: Write>(
+    writer: &mut W,
+    last: &mut Option<Vec<u8>>,
+    repeats: &mut u64,
+    line: &[u8],
+) -> io::Result<()> {
+    match last {
+        Some(last) if strip_newline(last) == strip_newline(line) => *repeats += 1,
+        _ => {
+            if let Some(last) = last.replace(line.to_vec()) {
+                emit(writer, &last, *repeats)?;
+            }
+            *repeats = 1;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write `line`, inserting the repeat marker before its line ending if it occurred more than once.
+fn emit<W: Write>(writer: &mut W, line: &[u8], repeats: u64) -> io::Result<()> {
+    if repeats > 1 {
+        let content = strip_newline(line);
+        writer.write_all(content)?;
+        write!(writer, " (repeated {repeats} times)")?;
+        writer.write_all(&line[content.len()..])
+    } else {
+        writer.write_all(line)
+    }
+}
+
+impl<W: Write> Write for DedupWriter<W> {
+    /// Buffer `buf`, forwarding each run of identical lines once a different line ends it.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let writer = &mut self.writer;
+        let last = &mut self.last;
+        let repeats = &mut self.repeats;
+
+        self.lines
+            .push(buf, |line| line_completed(writer, last, repeats, line))?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush the internal writer. The most recent line and incomplete lines stay buffered.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Sink for DedupWriter<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Forward the buffered line, then flush the internal writer.
+    fn finish(mut self) -> io::Result<()> {
+        DedupWriter::finish(&mut self)?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for DedupWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{DedupWriter, MultiWriter};
+
+    #[test]
+    fn dedup_writer() {
+        let mut output = Vec::new();
+        let mut dedup_writer = DedupWriter::new(&mut output);
+
+        // Lines are compared without their line endings, and the first occurrence's ending is kept.
+        dedup_writer.write_all(b"a\na\nb\nc\r").unwrap();
+        dedup_writer.write_all(b"\nc\nc\na\n").unwrap();
+        dedup_writer.flush().unwrap();
+        assert_eq!(
+            dedup_writer.get_ref()[..],
+            *b"a (repeated 2 times)\nb\nc (repeated 3 times)\r\n"
+        );
+
+        dedup_writer.finish().unwrap();
+        drop(dedup_writer);
+        assert_eq!(
+            output,
+            b"a (repeated 2 times)\nb\nc (repeated 3 times)\r\na\n"
+        );
+    }
+
+    #[test]
+    fn dedup_writer_trailing_line() {
+        let mut output = Vec::new();
+        let mut dedup_writer = DedupWriter::new(&mut output);
+
+        // The unterminated final line still counts as a repeat of the previous one.
+        dedup_writer.write_all(b"x\nsame\nsa").unwrap();
+        dedup_writer.write_all(b"me\nsame").unwrap();
+
+        drop(dedup_writer);
+        assert_eq!(output, b"x\nsame (repeated 3 times)\n");
+    }
+
+    #[test]
+    fn dedup_writer_in_multi_writer() {
+        let input = b"tick\ntick\ntick\ntock\n";
+        let mut raw = Vec::<u8>::new();
+        let mut deduped = Vec::<u8>::new();
+        let mut dedup_writer = DedupWriter::new(&mut deduped);
+        let mut multi_writer = MultiWriter::new(vec![&mut raw, &mut dedup_writer]);
+
+        multi_writer.write_all(input).unwrap();
+
+        drop(dedup_writer);
+        assert_eq!(raw, input);
+        assert_eq!(deduped, b"tick (repeated 3 times)\ntock\n");
+    }
+}
@@ -0,0 +1,155 @@
+use std::io::{self, Read};
+
+use crate::DEFAULT_BUF_SIZE;
+
+/// Read and discard up to `n` bytes from `reader`, returning how many bytes were actually skipped.
+///
+/// Fewer than `n` bytes are skipped only if `reader` reaches end of file first.
+/// Use [`DrainReader`] to skip repeatedly without allocating a new buffer each time.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+///
+/// let mut reader = &b"HEADERpayload"[..];
+/// assert_eq!(streams::skip(&mut reader, 6)?, 6);
+///
+/// let mut payload = String::new();
+/// reader.read_to_string(&mut payload)?;
+/// assert_eq!(payload, "payload");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Errors from `reader` are returned as-is, except [`ErrorKind::Interrupted`](io::ErrorKind::Interrupted), which is retried.
+pub fn skip<R: Read + ?Sized>(reader: &mut R, n: u64) -> io::Result<u64> {
+    let mut buf = [0; DEFAULT_BUF_SIZE];
+    discard(reader, n, &mut buf)
+}
+
+/// Wraps a reader, allowing bytes to be skipped using a reusable internal buffer.
+///
+/// Reading from a `DrainReader` reads from the internal reader directly.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+/// use streams::DrainReader;
+///
+/// let mut drain_reader = DrainReader::new(&b"junk:Hello, world!"[..]);
+/// assert_eq!(drain_reader.skip(5)?, 5);
+///
+/// let mut output = String::new();
+/// drain_reader.read_to_string(&mut output)?;
+/// assert_eq!(output, "Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct DrainReader<R: Read> {
+    reader: R,
+    buf: Box<[u8]>,
+}
+
+impl<R: Read> DrainReader<R> {
+    pub fn new(reader: R) -> Self {
+        let buf = vec![0; DEFAULT_BUF_SIZE].into_boxed_slice();
+
+        Self { reader, buf }
+    }
+
+    /// Read and discard up to `n` bytes, returning how many bytes were actually skipped.
+    ///
+    /// Fewer than `n` bytes are skipped only if the internal reader reaches end of file first.
+    ///
+    /// # Errors
+    ///
+    /// Errors from the internal reader are returned as-is, except [`ErrorKind::Interrupted`](io::ErrorKind::Interrupted), which is retried.
+    pub fn skip(&mut self, n: u64) -> io::Result<u64> {
+        discard(&mut self.reader, n, &mut self.buf)
+    }
+
+    /// Get a reference to the internal reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Get a mutable reference to the internal reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Consume the `DrainReader`, returning the internal reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read> Read for DrainReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+fn discard<R: Read + ?Sized>(reader: &mut R, n: u64, buf: &mut [u8]) -> io::Result<u64> {
+    let mut skipped = 0;
+
+    while skipped < n {
+        let len = buf
+            .len()
+            .min((n - skipped).try_into().unwrap_or(usize::MAX));
+        match reader.read(&mut buf[..len]) {
+            Ok(0) => break,
+            Ok(read) => skipped += read as u64,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use crate::{skip, DrainReader, MultiReader};
+
+    #[test]
+    fn skip_fewer_than_available() {
+        let mut reader = Cursor::new(b"Hello, world!".to_vec());
+        assert_eq!(skip(&mut reader, 7).unwrap(), 7);
+
+        let mut output = Vec::new();
+        reader.read_to_end(&mut output).unwrap();
+        assert_eq!(output, b"world!");
+    }
+
+    #[test]
+    fn skip_exactly_all() {
+        let mut drain_reader = DrainReader::new(Cursor::new(b"Hello, world!".to_vec()));
+        assert_eq!(drain_reader.skip(13).unwrap(), 13);
+        assert_eq!(drain_reader.read(&mut [0; 8]).unwrap(), 0);
+    }
+
+    #[test]
+    fn skip_more_than_available() {
+        let payload = vec![7; 20_000];
+        let mut drain_reader = DrainReader::new(Cursor::new(payload));
+        assert_eq!(drain_reader.skip(50_000).unwrap(), 20_000);
+        assert_eq!(drain_reader.skip(1).unwrap(), 0);
+    }
+
+    #[test]
+    fn skip_across_multi_reader() {
+        let mut reader1 = Cursor::new(b"junk".to_vec());
+        let mut reader2 = Cursor::new(b"!!Hello".to_vec());
+        let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2]);
+        assert_eq!(skip(&mut multi_reader, 6).unwrap(), 6);
+
+        let mut output = Vec::new();
+        multi_reader.read_to_end(&mut output).unwrap();
+        assert_eq!(output, b"Hello");
+    }
+}
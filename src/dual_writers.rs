@@ -0,0 +1,125 @@
+use std::io::{self, Write};
+
+use crate::MultiWriteError;
+
+/// Provides a single [`Writer`](Write) that writes to exactly two writers, like `tee`, without dynamic dispatch.
+///
+/// This behaves like a two-writer [`MultiWriter`](crate::MultiWriter) using [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast),
+/// writing each buffer into `a`, then `b`, using [`write_all`](Write::write_all).
+/// As both writer types are known statically, the calls are monomorphized and can be inlined,
+/// and no `Vec` of writers is allocated, which pays off for small writes on hot paths.
+///
+/// # Errors
+///
+/// If either writer fails, the error is returned immediately, wrapping a [`MultiWriteError`] reporting index 0 for `a` and 1 for `b`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::DualWriter;
+///
+/// let mut dual_writer = DualWriter::new(Vec::new(), Vec::new());
+/// dual_writer.write_all(b"Hello, world!")?;
+///
+/// let (a, b) = dual_writer.into_inner();
+/// assert_eq!(a, b"Hello, world!");
+/// assert_eq!(b, b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct DualWriter<A: Write, B: Write> {
+    a: A,
+    b: B,
+}
+
+impl<A: Write, B: Write> DualWriter<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+
+    /// Get references to the internal writers.
+    pub fn get_ref(&self) -> (&A, &B) {
+        (&self.a, &self.b)
+    }
+
+    /// Get mutable references to the internal writers.
+    pub fn get_mut(&mut self) -> (&mut A, &mut B) {
+        (&mut self.a, &mut self.b)
+    }
+
+    /// Consumes the `DualWriter`, returning the internal writers.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: Write, B: Write> Write for DualWriter<A, B> {
+    /// Write a buffer into both internal writers, `a` first.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.a
+            .write_all(buf)
+            .map_err(|e| MultiWriteError::new(0, e).into_io_error())?;
+        self.b
+            .write_all(buf)
+            .map_err(|e| MultiWriteError::new(1, e).into_io_error())?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.a
+            .flush()
+            .map_err(|e| MultiWriteError::new(0, e).into_io_error())?;
+        self.b
+            .flush()
+            .map_err(|e| MultiWriteError::new(1, e).into_io_error())
+    }
+
+    /// Calls [`write`](DualWriter::write) and discards the returned `usize`.
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let _ = self.write(buf)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{mocks::FailingWriter, DualWriter, MultiWriteError};
+
+    #[test]
+    fn dual_writer() {
+        let mut borrowed = Vec::<u8>::new();
+        let mut dual_writer = DualWriter::new(Vec::<u8>::new(), &mut borrowed);
+
+        dual_writer.write_all(b"Hello, ").unwrap();
+        assert_eq!(dual_writer.write(b"world!").unwrap(), 6);
+        dual_writer.flush().unwrap();
+        assert_eq!(dual_writer.get_ref().0, b"Hello, world!");
+
+        let (owned, _) = dual_writer.into_inner();
+        assert_eq!(owned, b"Hello, world!");
+        assert_eq!(borrowed, b"Hello, world!");
+    }
+
+    #[test]
+    fn dual_writer_reports_failing_index() {
+        let mut dual_writer = DualWriter::new(
+            Vec::<u8>::new(),
+            FailingWriter::new(io::ErrorKind::BrokenPipe),
+        );
+
+        let err = dual_writer.write_all(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(dual_writer.get_ref().0, b"Hello, world!");
+    }
+}
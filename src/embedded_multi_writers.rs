@@ -0,0 +1,169 @@
+use core::{error::Error, fmt};
+
+use embedded_io::{ErrorKind, ErrorType, Write};
+
+use crate::MultiWriter;
+
+impl<W: Write> ErrorType for MultiWriter<W>
+where
+    W::Error: 'static,
+{
+    type Error = EmbeddedMultiWriteError<W::Error>;
+}
+
+/// Write to multiple [`embedded_io::Write`] writers sequentially, without depending on `std`.
+///
+/// # Examples
+///
+/// ```
+/// use embedded_io::Write;
+/// use streams::MultiWriter;
+///
+/// let mut multi_writer = MultiWriter::from_writers(vec![Vec::new(), Vec::new()]);
+/// multi_writer.write_all(b"Hello, world!").unwrap();
+///
+/// for writer in multi_writer.into_inner() {
+///     assert_eq!(writer, b"Hello, world!");
+/// }
+/// ```
+impl<W: Write> Write for MultiWriter<W>
+where
+    W::Error: 'static,
+{
+    /// Write a buffer into each internal writer sequentially, stopping at the first failing writer.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    /// An empty buffer returns `Ok(0)` immediately without touching the internal writers.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        for index in 0..self.len() {
+            self.get_mut()[index]
+                .write_all(buf)
+                .map_err(|source| EmbeddedMultiWriteError::new(index, source))?;
+            self.record(index, buf.len());
+        }
+        self.record_fan_out(buf.len());
+
+        Ok(buf.len())
+    }
+
+    /// Flush each internal writer sequentially, stopping at the first failing writer.
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        for (index, writer) in self.get_mut().iter_mut().enumerate() {
+            writer
+                .flush()
+                .map_err(|source| EmbeddedMultiWriteError::new(index, source))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned when one of the internal [`embedded_io`] writers of a [`MultiWriter`] fails,
+/// the [`embedded_io`] counterpart of `MultiWriteError`.
+///
+/// Unlike with `std::io::Error`, there is no type-erased error to wrap it in, so this is returned directly.
+/// Its [`kind`](embedded_io::Error::kind) is the kind of the underlying error.
+#[derive(Debug)]
+pub struct EmbeddedMultiWriteError<E> {
+    index: usize,
+    source: E,
+}
+
+impl<E> EmbeddedMultiWriteError<E> {
+    /// Create an `EmbeddedMultiWriteError` for the writer at `index` failing with `source`.
+    pub fn new(index: usize, source: E) -> Self {
+        Self { index, source }
+    }
+
+    /// The zero-based index of the internal writer that failed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Consumes the error, returning the error reported by the internal writer.
+    pub fn into_source(self) -> E {
+        self.source
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for EmbeddedMultiWriteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `embedded_io` errors are only required to implement `Debug`.
+        write!(f, "writer {} failed: {:?}", self.index, self.source)
+    }
+}
+
+impl<E: Error + 'static> Error for EmbeddedMultiWriteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl<E: embedded_io::Error + 'static> embedded_io::Error for EmbeddedMultiWriteError<E> {
+    fn kind(&self) -> ErrorKind {
+        self.source.kind()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use embedded_io::{ErrorKind, ErrorType, Write};
+
+    use crate::MultiWriter;
+
+    /// An `embedded_io` writer accepting at most 2 bytes per call.
+    #[derive(Default)]
+    struct SlowWriter {
+        buf: Vec<u8>,
+    }
+
+    impl ErrorType for SlowWriter {
+        type Error = ErrorKind;
+    }
+
+    impl Write for SlowWriter {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+            let n = buf.len().min(2);
+            self.buf.extend_from_slice(&buf[..n]);
+
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn multi_writer_embedded() {
+        let mut multi_writer =
+            MultiWriter::from_writers(vec![SlowWriter::default(), SlowWriter::default()]);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        assert_eq!(multi_writer.write(b"world!").unwrap(), 6);
+        multi_writer.flush().unwrap();
+        assert_eq!(multi_writer.bytes_written(), [13, 13]);
+        assert_eq!(multi_writer.stats().writes(), 2);
+
+        for writer in multi_writer.into_inner() {
+            assert_eq!(writer.buf, b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn multi_writer_embedded_reports_failing_index() {
+        let mut buf1 = [0u8; 16];
+        let mut buf2 = [0u8; 5];
+        let mut multi_writer = MultiWriter::from_writers(vec![&mut buf1[..], &mut buf2[..]]);
+
+        let err = multi_writer.write_all(b"Hello, world!").unwrap_err();
+        assert_eq!(err.index(), 1);
+        assert_eq!(embedded_io::Error::kind(&err), ErrorKind::WriteZero);
+    }
+}
@@ -0,0 +1,144 @@
+use core::{error::Error, fmt};
+
+use embedded_io::{ErrorKind, Read, Write};
+
+use crate::RoundRobinCopier;
+
+/// The size of the stack buffer [`RoundRobinCopier::copy_embedded`] reads into, kept small for embedded targets.
+const CHUNK_SIZE: usize = 256;
+
+impl<'a, W: Write> RoundRobinCopier<'a, W> {
+    /// Copy the entire contents of the [`embedded_io`] `reader` into the current writer, then advance to the next writer.
+    ///
+    /// This is the [`embedded_io`] counterpart of `copy`, copying in chunks of at most 256 bytes through a stack buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use streams::RoundRobinCopier;
+    ///
+    /// let mut copier = RoundRobinCopier::new(vec![Vec::new(), Vec::new()]);
+    /// copier.copy_embedded(&mut &b"Hello"[..]).unwrap();
+    /// copier.copy_embedded(&mut &b"world"[..]).unwrap();
+    ///
+    /// assert_eq!(copier.into_inner(), [b"Hello", b"world"]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddedCopyError::NoWriters`] if there are no internal writers.
+    /// Errors during the copy are returned wrapped in an [`EmbeddedCopyError`]; the copier advances to the next writer regardless.
+    pub fn copy_embedded<R: Read + ?Sized>(
+        &mut self,
+        reader: &mut R,
+    ) -> Result<u64, EmbeddedCopyError<R::Error, W::Error>> {
+        self.copy_with(
+            || EmbeddedCopyError::NoWriters,
+            |writer| {
+                let mut buf = [0; CHUNK_SIZE];
+                let mut copied = 0;
+
+                loop {
+                    let n = match reader.read(&mut buf) {
+                        Ok(0) => return Ok(copied),
+                        Ok(n) => n,
+                        Err(e) if embedded_io::Error::kind(&e) == ErrorKind::Interrupted => {
+                            continue
+                        }
+                        Err(e) => return Err(EmbeddedCopyError::Read(e)),
+                    };
+
+                    writer
+                        .write_all(&buf[..n])
+                        .map_err(EmbeddedCopyError::Write)?;
+                    copied += n as u64;
+                }
+            },
+        )
+    }
+}
+
+/// The error returned by [`RoundRobinCopier::copy_embedded`].
+///
+/// As [`embedded_io`] readers and writers each have their own error type, the errors of either side are kept apart.
+#[derive(Debug)]
+pub enum EmbeddedCopyError<R, W> {
+    /// The copier has no writers to copy into.
+    NoWriters,
+    /// Reading from the reader failed.
+    Read(R),
+    /// Writing into the current writer failed.
+    Write(W),
+}
+
+impl<R: fmt::Debug, W: fmt::Debug> fmt::Display for EmbeddedCopyError<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoWriters => write!(f, "round-robin copier has no writers to copy into"),
+            Self::Read(e) => write!(f, "failed to read: {e:?}"),
+            Self::Write(e) => write!(f, "failed to write: {e:?}"),
+        }
+    }
+}
+
+impl<R: Error + 'static, W: Error + 'static> Error for EmbeddedCopyError<R, W> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NoWriters => None,
+            Self::Read(e) => Some(e),
+            Self::Write(e) => Some(e),
+        }
+    }
+}
+
+impl<R: embedded_io::Error + 'static, W: embedded_io::Error + 'static> embedded_io::Error
+    for EmbeddedCopyError<R, W>
+{
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NoWriters => ErrorKind::InvalidInput,
+            Self::Read(e) => e.kind(),
+            Self::Write(e) => e.kind(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{EmbeddedCopyError, RoundRobinCopier};
+
+    #[test]
+    fn round_robin_copier_copy_embedded() {
+        let mut copier = RoundRobinCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+
+        // Longer than the internal buffer, so it is copied in several chunks.
+        let input = b"Hello, world!".repeat(50);
+        for _ in 0..4 {
+            copier.copy_embedded(&mut &input[..]).unwrap();
+        }
+        assert_eq!(copier.current_index(), 1);
+        assert_eq!(copier.stats(), [1300, 650, 650]);
+
+        let writers = copier.into_inner();
+        assert_eq!(writers[0], input.repeat(2));
+        assert_eq!(writers[1], input);
+        assert_eq!(writers[2], input);
+    }
+
+    #[test]
+    fn round_robin_copier_copy_embedded_errors() {
+        let mut small = [0u8; 5];
+        let mut copier = RoundRobinCopier::new(vec![&mut small[..]]);
+
+        let err = copier
+            .copy_embedded(&mut &b"Hello, world!"[..])
+            .unwrap_err();
+        assert!(matches!(err, EmbeddedCopyError::Write(_)));
+
+        let mut copier = RoundRobinCopier::new(Vec::<Vec<u8>>::new());
+        let err = copier.copy_embedded(&mut &b"Hello"[..]).unwrap_err();
+        assert!(matches!(err, EmbeddedCopyError::NoWriters));
+    }
+}
@@ -0,0 +1,268 @@
+use std::{error::Error, fmt, io};
+
+/// The error payload reported when one of the internal writers of a [`MultiWriter`](crate::MultiWriter) fails.
+///
+/// To keep the [`Write`](std::io::Write) signatures intact, this is not returned directly,
+/// but stored inside an [`io::Error`] of the same [`ErrorKind`](io::ErrorKind) as the underlying error.
+/// It can be recovered using [`io::Error::get_ref`] and [`downcast_ref`](trait@Error#method.downcast_ref):
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{MultiWriteError, MultiWriter};
+///
+/// let mut sink = Vec::new();
+/// // A zero-length slice accepts no bytes, so `write_all` fails with `WriteZero`.
+/// let mut full: &mut [u8] = &mut [];
+/// let writers: Vec<&mut dyn Write> = vec![&mut sink, &mut full];
+/// let mut multi_writer = MultiWriter::new(writers);
+///
+/// let err = multi_writer.write(b"Hello, world!").unwrap_err();
+/// let multi_write_error = err
+///     .get_ref()
+///     .and_then(|e| e.downcast_ref::<MultiWriteError>())
+///     .unwrap();
+///
+/// assert_eq!(multi_write_error.index(), 1);
+/// ```
+#[derive(Debug)]
+pub struct MultiWriteError {
+    index: usize,
+    label: Option<String>,
+    source: io::Error,
+}
+
+impl MultiWriteError {
+    /// Create a `MultiWriteError` for the writer at `index` failing with `source`, without a label.
+    pub fn new(index: usize, source: io::Error) -> Self {
+        let label = None;
+
+        Self {
+            index,
+            label,
+            source,
+        }
+    }
+
+    /// Attach the human-readable label of the failing writer, used in place of its index when displayed.
+    pub fn with_label(mut self, label: Option<String>) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// The zero-based index of the internal writer that failed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The label of the internal writer that failed, if it was given one.
+    /// See [`MultiWriter::new_named`](crate::MultiWriter::new_named).
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Consumes the error, returning the error reported by the internal writer.
+    pub fn into_source(self) -> io::Error {
+        self.source
+    }
+
+    /// Wrap the error in an [`io::Error`] of the same kind as the underlying error.
+    pub(crate) fn into_io_error(self) -> io::Error {
+        io::Error::new(self.source.kind(), self)
+    }
+}
+
+impl fmt::Display for MultiWriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "writer '{}' failed: {}", label, self.source),
+            None => write!(f, "writer {} failed: {}", self.index, self.source),
+        }
+    }
+}
+
+impl Error for MultiWriteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// An error reporting that some of a known number of writers failed, each with its own error.
+///
+/// This is the aggregate error of every writer fanning out into several writers.
+/// It is returned directly, e.g. by [`MultiWriteReport::into_result`](crate::MultiWriteReport::into_result),
+/// or, to fit the [`Write`](std::io::Write) signatures, stored inside an [`io::Error`] of kind [`Other`](io::ErrorKind::Other),
+/// e.g. by a [`MultiWriter`](crate::MultiWriter) using [`ErrorPolicy::ContinueAndCollect`](crate::ErrorPolicy::ContinueAndCollect),
+/// and recovered using [`io::Error::get_ref`].
+/// It is generic over the error type `E` of the individual failures, which defaults to [`io::Error`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use streams::MultiError;
+///
+/// let error = MultiError::new(5, vec![
+///     (1, io::Error::from(io::ErrorKind::PermissionDenied)),
+///     (3, io::Error::from(io::ErrorKind::BrokenPipe)),
+/// ]);
+///
+/// assert_eq!(error.to_string(), "2 of 5 writers failed: [1: permission denied, 3: broken pipe]");
+/// ```
+#[derive(Debug)]
+pub struct MultiError<E: Error = io::Error> {
+    total: usize,
+    errors: Vec<(usize, E)>,
+}
+
+impl<E: Error> MultiError<E> {
+    /// Create a `MultiError` for `errors` out of `total` writers, each error paired with the index of its writer.
+    pub fn new(total: usize, errors: Vec<(usize, E)>) -> Self {
+        Self { total, errors }
+    }
+
+    /// The total number of writers, including those that succeeded.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The index of each failing writer together with its error.
+    pub fn errors(&self) -> &[(usize, E)] {
+        &self.errors
+    }
+
+    /// Consumes the error, returning the errors of the failing writers.
+    pub fn into_errors(self) -> Vec<(usize, E)> {
+        self.errors
+    }
+}
+
+impl MultiError {
+    /// Wrap the error in an [`io::Error`] of kind [`Other`](io::ErrorKind::Other).
+    pub(crate) fn into_io_error(self) -> io::Error {
+        io::Error::other(self)
+    }
+}
+
+impl<E: Error> fmt::Display for MultiError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} writers failed: [",
+            self.errors.len(),
+            self.total
+        )?;
+
+        for (i, (index, error)) in self.errors.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{index}: {error}")?;
+        }
+
+        write!(f, "]")
+    }
+}
+
+impl<E: Error + 'static> Error for MultiError<E> {
+    /// The error of the first failing writer.
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.errors
+            .first()
+            .map(|(_, e)| e as &(dyn Error + 'static))
+    }
+}
+
+/// The error payload reported when a copy is cancelled, as by [`copy_into_many_cancellable`](crate::copy_into_many_cancellable).
+///
+/// Like [`MultiWriteError`], this is stored inside an [`io::Error`], of kind [`Interrupted`](io::ErrorKind::Interrupted),
+/// and can be recovered using [`io::Error::get_ref`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyCancelled {
+    copied: u64,
+}
+
+impl CopyCancelled {
+    /// Create a `CopyCancelled` for a copy cancelled after `copied` bytes were copied into every writer.
+    pub fn new(copied: u64) -> Self {
+        Self { copied }
+    }
+
+    /// The number of bytes copied into every writer before the copy was cancelled.
+    pub fn copied(&self) -> u64 {
+        self.copied
+    }
+
+    /// Wrap the error in an [`io::Error`] of kind [`Interrupted`](io::ErrorKind::Interrupted).
+    pub(crate) fn into_io_error(self) -> io::Error {
+        io::Error::new(io::ErrorKind::Interrupted, self)
+    }
+}
+
+impl fmt::Display for CopyCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "copy cancelled after {} bytes", self.copied)
+    }
+}
+
+impl Error for CopyCancelled {}
+
+#[cfg(test)]
+mod tests {
+    use std::{error::Error, fmt, io};
+
+    use crate::MultiError;
+
+    #[test]
+    fn multi_error_display() {
+        let error = MultiError::new(
+            5,
+            vec![
+                (1, io::Error::from(io::ErrorKind::PermissionDenied)),
+                (3, io::Error::from(io::ErrorKind::BrokenPipe)),
+            ],
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "2 of 5 writers failed: [1: permission denied, 3: broken pipe]"
+        );
+        assert_eq!(error.total(), 5);
+        assert_eq!(error.errors().len(), 2);
+    }
+
+    #[test]
+    fn multi_error_source() {
+        let error = MultiError::new(
+            2,
+            vec![(0, io::Error::from(io::ErrorKind::PermissionDenied))],
+        );
+
+        let source = error.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<io::Error>().unwrap().kind(),
+            io::ErrorKind::PermissionDenied
+        );
+
+        let empty = MultiError::<io::Error>::new(2, Vec::new());
+        assert!(empty.source().is_none());
+        assert_eq!(empty.to_string(), "0 of 2 writers failed: []");
+    }
+
+    #[test]
+    fn multi_error_custom_error_type() {
+        #[derive(Debug)]
+        struct Rejected;
+
+        impl fmt::Display for Rejected {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "rejected")
+            }
+        }
+
+        impl Error for Rejected {}
+
+        let error = MultiError::new(3, vec![(2, Rejected)]);
+        assert_eq!(error.to_string(), "1 of 3 writers failed: [2: rejected]");
+        assert!(error.source().unwrap().is::<Rejected>());
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::{self, Read, Write};
+
+/// Copies each reader into the next healthy writer in turn, like a [`RoundRobinCopier`](crate::RoundRobinCopier),
+/// failing over to the following writer when one fails.
+///
+/// A writer failing during a [`copy`](FailoverRoundRobinCopier::copy) is disabled and skipped by every following copy,
+/// and the same input is copied into the next healthy writer instead, so no input is lost as long as one writer is healthy.
+/// The errors that disabled the writers are kept for inspection with [`disabled_writers`](FailoverRoundRobinCopier::disabled_writers).
+///
+/// To retry the input, each reader is read to its end into memory before being written.
+/// Bytes already written to a failed writer are not rewound, so it may hold part of the input it failed on.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::FailoverRoundRobinCopier;
+///
+/// let mut full: &mut [u8] = &mut [];
+/// let mut healthy = Vec::new();
+/// let mut copier = FailoverRoundRobinCopier::new(vec![&mut full as &mut dyn Write, &mut healthy]);
+///
+/// copier.copy(&mut &b"Hello"[..])?;
+/// copier.copy(&mut &b", world!"[..])?;
+/// assert_eq!(copier.disabled_writers()[0].0, 0);
+///
+/// assert_eq!(healthy, b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct FailoverRoundRobinCopier<W: Write> {
+    writers: Vec<W>,
+    current: usize,
+    enabled: Vec<bool>,
+    disabled: Vec<(usize, io::Error)>,
+}
+
+impl<W: Write> FailoverRoundRobinCopier<W> {
+    pub fn new(writers: Vec<W>) -> Self {
+        let current = 0;
+        let enabled = vec![true; writers.len()];
+        let disabled = Vec::new();
+
+        Self {
+            writers,
+            current,
+            enabled,
+            disabled,
+        }
+    }
+
+    /// The index of the writer the next call to [`copy`](FailoverRoundRobinCopier::copy) will try first.
+    ///
+    /// If that writer turns out to be disabled, the copy starts at the next healthy writer instead.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// The index of each disabled writer together with the error that disabled it, in the order they failed.
+    pub fn disabled_writers(&self) -> &[(usize, io::Error)] {
+        &self.disabled
+    }
+
+    /// Consumes the `FailoverRoundRobinCopier`, returning the internal writers, including the disabled ones.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// Copy the entire contents of `reader` into the next healthy writer and flush it, then advance past it.
+    ///
+    /// If the writer fails to write or flush, it is disabled and the contents are copied into the following healthy writer instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers,
+    /// and of kind [`BrokenPipe`](io::ErrorKind::BrokenPipe) if every writer has been disabled, with the input being lost.
+    /// Errors while reading `reader` are returned as-is, without writing anything or disabling any writer.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "failover round-robin copier has no writers to copy into",
+            ));
+        }
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        for offset in 0..self.writers.len() {
+            let index = (self.current + offset) % self.writers.len();
+
+            if !self.enabled[index] {
+                continue;
+            }
+
+            let result = self.writers[index]
+                .write_all(&buf)
+                .and_then(|()| self.writers[index].flush());
+
+            match result {
+                Ok(()) => {
+                    self.current = (index + 1) % self.writers.len();
+                    return Ok(buf.len() as u64);
+                }
+                Err(e) => {
+                    self.enabled[index] = false;
+                    self.disabled.push((index, e));
+                }
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::BrokenPipe,
+            "every writer has been disabled",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{mocks::FailingWriter, FailoverRoundRobinCopier};
+
+    #[test]
+    fn failover_round_robin_copier() {
+        let mut writer0 = Vec::<u8>::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut writer2 = Vec::<u8>::new();
+        let mut copier = FailoverRoundRobinCopier::new(vec![
+            &mut writer0 as &mut dyn Write,
+            &mut failing,
+            &mut writer2,
+        ]);
+
+        copier.copy(&mut &b"first"[..]).unwrap();
+        assert_eq!(copier.current_index(), 1);
+
+        // The selected writer fails, so the input lands on the next one instead.
+        assert_eq!(copier.copy(&mut &b"second"[..]).unwrap(), 6);
+        assert_eq!(copier.current_index(), 0);
+        let disabled: Vec<_> = copier
+            .disabled_writers()
+            .iter()
+            .map(|(i, e)| (*i, e.kind()))
+            .collect();
+        assert_eq!(disabled, [(1, io::ErrorKind::BrokenPipe)]);
+
+        // The disabled writer is skipped from now on.
+        copier.copy(&mut &b"third"[..]).unwrap();
+        copier.copy(&mut &b"fourth"[..]).unwrap();
+        assert_eq!(copier.disabled_writers().len(), 1);
+
+        assert_eq!(writer0, b"firstthird");
+        assert_eq!(writer2, b"secondfourth");
+    }
+
+    #[test]
+    fn failover_round_robin_copier_all_disabled() {
+        let mut copier = FailoverRoundRobinCopier::new(vec![
+            FailingWriter::new(io::ErrorKind::BrokenPipe),
+            FailingWriter::new(io::ErrorKind::PermissionDenied),
+        ]);
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(err.to_string(), "every writer has been disabled");
+        assert_eq!(copier.disabled_writers().len(), 2);
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn failover_round_robin_copier_without_writers() {
+        let mut copier = FailoverRoundRobinCopier::new(Vec::<&mut dyn Write>::new());
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
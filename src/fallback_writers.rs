@@ -0,0 +1,131 @@
+use std::io::{self, Write};
+
+/// Writes to a primary writer, permanently switching to the next fallback writer whenever the current one fails.
+///
+/// When a [`write`](FallbackWriter::write) fails, the same buffer is retried on the next fallback,
+/// which then receives everything written for the rest of the stream.
+/// Only once the last fallback fails is its error returned.
+/// A writer accepting zero bytes of a non-empty buffer is considered failed as well.
+///
+/// Bytes already written to a failed writer are not rewound,
+/// so the output is split between the writers at the point of failure.
+/// Errors from [`flush`](FallbackWriter::flush) are returned without switching writers.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::FallbackWriter;
+///
+/// let mut primary: &mut [u8] = &mut [];
+/// let mut fallback = Vec::new();
+/// let mut fallback_writer = FallbackWriter::new(&mut primary, vec![&mut fallback]);
+///
+/// fallback_writer.write_all(b"Hello, world!")?;
+/// assert_eq!(fallback_writer.active_index(), 1);
+/// drop(fallback_writer);
+///
+/// assert_eq!(fallback, b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct FallbackWriter<'a> {
+    writers: Vec<&'a mut dyn Write>,
+    current: usize,
+}
+
+impl<'a> FallbackWriter<'a> {
+    pub fn new(primary: &'a mut dyn Write, fallbacks: Vec<&'a mut dyn Write>) -> Self {
+        let mut writers = Vec::with_capacity(fallbacks.len() + 1);
+        writers.push(primary);
+        writers.extend(fallbacks);
+
+        let current = 0;
+
+        Self { writers, current }
+    }
+
+    /// The index of the writer currently written to, where `0` is the primary and `1` the first fallback.
+    pub fn active_index(&self) -> usize {
+        self.current
+    }
+}
+
+impl<'a> Write for FallbackWriter<'a> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        loop {
+            let has_fallback = self.current + 1 < self.writers.len();
+
+            match self.writers[self.current].write(buf) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                // Accepting no bytes of a non-empty buffer is treated as a failure, like `write_all` does.
+                Ok(0) if !buf.is_empty() && has_fallback => self.current += 1,
+                Err(_) if has_fallback => self.current += 1,
+                result => return result,
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writers[self.current].flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{mocks::FailingWriter, FallbackWriter};
+
+    /// Accepts `remaining` writes, then fails.
+    struct BreakingWriter {
+        buf: Vec<u8>,
+        remaining: usize,
+    }
+
+    impl Write for BreakingWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.remaining == 0 {
+                return Err(io::Error::from(io::ErrorKind::BrokenPipe));
+            }
+
+            self.remaining -= 1;
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn fallback_writer() {
+        let mut primary = BreakingWriter {
+            buf: Vec::new(),
+            remaining: 1,
+        };
+        let mut fallback = Vec::<u8>::new();
+        let mut fallback_writer = FallbackWriter::new(&mut primary, vec![&mut fallback]);
+
+        fallback_writer.write_all(b"Hello").unwrap();
+        assert_eq!(fallback_writer.active_index(), 0);
+        fallback_writer.write_all(b", ").unwrap();
+        assert_eq!(fallback_writer.active_index(), 1);
+        fallback_writer.write_all(b"world!").unwrap();
+
+        drop(fallback_writer);
+        assert_eq!(primary.buf, b"Hello");
+        assert_eq!(fallback, b", world!");
+    }
+
+    #[test]
+    fn fallback_writer_all_failing() {
+        let mut primary = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut fallback = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut fallback_writer = FallbackWriter::new(&mut primary, vec![&mut fallback]);
+
+        let err = fallback_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+        assert_eq!(fallback_writer.active_index(), 1);
+    }
+}
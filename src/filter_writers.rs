@@ -0,0 +1,157 @@
+use std::io::{self, Write};
+
+use crate::{
+    line_split_writers::{strip_newline, LineBuffer},
+    Sink,
+};
+
+/// Wraps a writer, forwarding only the lines for which a predicate returns `true`.
+///
+/// Written bytes are split into lines like in a [`LineSplitWriter`](crate::LineSplitWriter),
+/// and each complete line is passed to `predicate` without its line ending.
+/// Matching lines are forwarded along with their line ending, all others are silently dropped.
+/// Placing a `FilterWriter` inside a [`MultiWriter`](crate::MultiWriter) lets one destination receive only a subset of the stream.
+///
+/// Trailing bytes not terminated by a newline are filtered as a final line by [`finish`](FilterWriter::finish),
+/// or when the `FilterWriter` is dropped, ignoring any errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::FilterWriter;
+///
+/// let mut errors = Vec::new();
+/// let mut filter_writer = FilterWriter::new(&mut errors, |line| line.starts_with(b"ERROR"));
+/// filter_writer.write_all(b"INFO started\nERROR failed\nINFO done\n")?;
+/// drop(filter_writer);
+///
+/// assert_eq!(errors, b"ERROR failed\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct FilterWriter<W: Write, P: FnMut(&[u8]) -> bool> {
+    writer: W,
+    predicate: P,
+    lines: LineBuffer,
+}
+
+impl<W: Write, P: FnMut(&[u8]) -> bool> FilterWriter<W, P> {
+    pub fn new(writer: W, predicate: P) -> Self {
+        let lines = LineBuffer::default();
+
+        Self {
+            writer,
+            predicate,
+            lines,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Filter any trailing bytes not terminated by a newline as a final line.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(line) = self.lines.take_rest() {
+            if (self.predicate)(strip_newline(&line)) {
+                self.writer.write_all(&line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write, P: FnMut(&[u8]) -> bool> Write for FilterWriter<W, P> {
+    /// Buffer `buf`, forwarding each line it completes if it matches the predicate.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let writer = &mut self.writer;
+        let predicate = &mut self.predicate;
+
+        self.lines.push(buf, |line| {
+            if predicate(strip_newline(line)) {
+                writer.write_all(line)?;
+            }
+
+            Ok(())
+        })?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush the internal writer. Incomplete lines stay buffered.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write, P: FnMut(&[u8]) -> bool> Sink for FilterWriter<W, P> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Filter the trailing bytes as a final line, then flush the internal writer.
+    fn finish(mut self) -> io::Result<()> {
+        FilterWriter::finish(&mut self)?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write, P: FnMut(&[u8]) -> bool> Drop for FilterWriter<W, P> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{FilterWriter, MultiWriter};
+
+    fn contains(line: &[u8], needle: &[u8]) -> bool {
+        line.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn filter_writer() {
+        let mut output = Vec::new();
+        let mut filter_writer = FilterWriter::new(&mut output, |line| contains(line, b"ERROR"));
+
+        filter_writer
+            .write_all(b"[INFO] starting\n[ERROR] disk ")
+            .unwrap();
+        filter_writer
+            .write_all(b"full\r\n[INFO] retrying\n[ERROR] giving up")
+            .unwrap();
+        filter_writer.flush().unwrap();
+        assert_eq!(filter_writer.get_ref()[..], *b"[ERROR] disk full\r\n");
+
+        filter_writer.finish().unwrap();
+        drop(filter_writer);
+        assert_eq!(output, b"[ERROR] disk full\r\n[ERROR] giving up");
+    }
+
+    #[test]
+    fn filter_writer_in_multi_writer() {
+        let input = b"[INFO] starting\n[ERROR] disk full\n[INFO] retrying\n";
+        let mut everything = Vec::<u8>::new();
+        let mut errors = Vec::<u8>::new();
+        let mut filter_writer = FilterWriter::new(&mut errors, |line| contains(line, b"ERROR"));
+        let mut multi_writer = MultiWriter::new(vec![&mut everything, &mut filter_writer]);
+
+        multi_writer.write_all(input).unwrap();
+
+        drop(filter_writer);
+        assert_eq!(everything, input);
+        assert_eq!(errors, b"[ERROR] disk full\n");
+    }
+}
@@ -0,0 +1,134 @@
+use std::io::{self, IoSlice, Write};
+
+/// Wraps a writer, flushing it when the `FlushOnDropWriter` is dropped.
+///
+/// This makes a writer that doesn't flush itself on drop, like a [`MultiWriter`](crate::MultiWriter) of buffered writers,
+/// behave like a [`BufWriter`](std::io::BufWriter), so data isn't lost if a call to [`flush`](Write::flush) is forgotten.
+/// Flushing on drop is best-effort: as errors can't be surfaced from [`Drop`], they are silently ignored.
+/// Call [`flush`](Write::flush) explicitly to handle them.
+///
+/// The flush on drop is a single call to the internal writer's [`flush`](Write::flush),
+/// so a wrapped [`MultiWriter`](crate::MultiWriter) flushes its writers according to its [`ErrorPolicy`](crate::ErrorPolicy),
+/// skipping those disabled under [`ErrorPolicy::DisableOnError`](crate::ErrorPolicy::DisableOnError).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{BufWriter, Write};
+/// use streams::{FlushOnDropWriter, MultiWriter};
+///
+/// let mut output = Vec::new();
+/// let mut buffered = BufWriter::new(&mut output);
+///
+/// {
+///     let mut multi_writer = FlushOnDropWriter::new(MultiWriter::new(vec![&mut buffered]));
+///     multi_writer.write_all(b"Hello, world!")?;
+/// }
+///
+/// assert_eq!(buffered.get_ref().as_slice(), b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct FlushOnDropWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> FlushOnDropWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+}
+
+impl<W: Write> Write for FlushOnDropWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.writer.write_vectored(bufs)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.writer.write_all(buf)
+    }
+}
+
+impl<W: Write> Drop for FlushOnDropWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.writer.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        io::{self, BufWriter, Write},
+        rc::Rc,
+    };
+
+    use crate::{mocks::SharedWriter, ErrorPolicy, FlushOnDropWriter, MultiWriter};
+
+    #[test]
+    fn flush_on_drop_writer_multi_writer() {
+        let shared1 = SharedWriter::new();
+        let shared2 = SharedWriter::new();
+        let mut writer1 = BufWriter::new(shared1.clone());
+        let mut writer2 = BufWriter::new(shared2.clone());
+
+        {
+            let mut multi_writer = MultiWriter::new(vec![&mut writer1, &mut writer2]);
+            multi_writer.write_all(b"Hello, world!").unwrap();
+        }
+        assert!(shared1.contents().is_empty());
+
+        {
+            let mut multi_writer =
+                FlushOnDropWriter::new(MultiWriter::new(vec![&mut writer1, &mut writer2]));
+            multi_writer.write_all(b"!!").unwrap();
+        }
+        assert_eq!(shared1.contents(), b"Hello, world!!!");
+        assert_eq!(shared2.contents(), b"Hello, world!!!");
+    }
+
+    #[test]
+    fn flush_on_drop_writer_skips_disabled_writers() {
+        /// Fails every write, counting the calls to `flush`.
+        struct FlushCounter(Rc<Cell<usize>>);
+
+        impl Write for FlushCounter {
+            fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.set(self.0.get() + 1);
+                Ok(())
+            }
+        }
+
+        let flushes = Rc::new(Cell::new(0));
+        let mut multi_writer = MultiWriter::from_writers(vec![
+            Box::new(Vec::new()) as Box<dyn Write>,
+            Box::new(FlushCounter(flushes.clone())),
+        ]);
+        multi_writer.set_error_policy(ErrorPolicy::DisableOnError);
+        let mut multi_writer = FlushOnDropWriter::new(multi_writer);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(flushes.get(), 0);
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    hash::Hasher,
+    io::{self, IoSlice, Write},
+};
+
+/// Wraps a writer, feeding every byte written to it into a digest, e.g. a hasher or checksum.
+///
+/// The digest is any [`DigestUpdate`], which includes every closure `FnMut(&[u8])`,
+/// so digests from other crates (like sha2 or crc) can be plugged in without this crate depending on them.
+/// [`std::hash::Hasher`]s are supported through [`HashingWriter::with_hasher`].
+///
+/// Only the bytes the internal writer actually accepted are fed into the digest.
+///
+/// # Examples
+///
+/// ```
+/// use std::{collections::hash_map::DefaultHasher, hash::Hasher, io::Write};
+/// use streams::HashingWriter;
+///
+/// let mut hashing_writer = HashingWriter::with_hasher(Vec::new(), DefaultHasher::new());
+/// hashing_writer.write_all(b"Hello, world!")?;
+/// let (output, hash) = hashing_writer.finalize();
+///
+/// let mut reference = DefaultHasher::new();
+/// reference.write(b"Hello, world!");
+/// assert_eq!(hash, reference.finish());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct HashingWriter<W: Write, D: DigestUpdate> {
+    writer: W,
+    digest: D,
+}
+
+/// A digest that can be fed bytes incrementally, as used by [`HashingWriter`].
+pub trait DigestUpdate {
+    fn update(&mut self, bytes: &[u8]);
+}
+
+impl<F: FnMut(&[u8])> DigestUpdate for F {
+    fn update(&mut self, bytes: &[u8]) {
+        self(bytes)
+    }
+}
+
+/// Adapts a [`std::hash::Hasher`] to a [`DigestUpdate`]. See [`HashingWriter::with_hasher`].
+pub struct HasherUpdate<H: Hasher>(pub H);
+
+impl<H: Hasher> DigestUpdate for HasherUpdate<H> {
+    fn update(&mut self, bytes: &[u8]) {
+        self.0.write(bytes)
+    }
+}
+
+impl<W: Write, D: DigestUpdate> HashingWriter<W, D> {
+    pub fn new(writer: W, digest: D) -> Self {
+        Self { writer, digest }
+    }
+
+    /// Get a reference to the digest.
+    pub fn digest(&self) -> &D {
+        &self.digest
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `HashingWriter`, returning the internal writer and the digest.
+    pub fn into_inner(self) -> (W, D) {
+        (self.writer, self.digest)
+    }
+}
+
+impl<W: Write, H: Hasher> HashingWriter<W, HasherUpdate<H>> {
+    /// Create a `HashingWriter` feeding the written bytes into `hasher`.
+    pub fn with_hasher(writer: W, hasher: H) -> Self {
+        Self::new(writer, HasherUpdate(hasher))
+    }
+
+    /// Consumes the `HashingWriter`, returning the internal writer and the [`finish`](Hasher::finish)ed hash.
+    pub fn finalize(self) -> (W, u64) {
+        let hash = self.digest.0.finish();
+
+        (self.writer, hash)
+    }
+}
+
+impl<W: Write, D: DigestUpdate> Write for HashingWriter<W, D> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.writer.write(buf)?;
+        self.digest.update(&buf[..n]);
+
+        Ok(n)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut remaining = self.writer.write_vectored(bufs)?;
+        let n = remaining;
+
+        for buf in bufs {
+            let len = buf.len().min(remaining);
+            self.digest.update(&buf[..len]);
+            remaining -= len;
+        }
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::Hasher,
+        io::{IoSlice, Write},
+    };
+
+    use crate::HashingWriter;
+
+    /// The 32-bit FNV-1a hash of `bytes`, starting from `hash`.
+    fn fnv1a(mut hash: u32, bytes: &[u8]) -> u32 {
+        for byte in bytes {
+            hash ^= u32::from(*byte);
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+
+        hash
+    }
+
+    #[test]
+    fn hashing_writer_with_hasher() {
+        let mut hashing_writer = HashingWriter::with_hasher(Vec::new(), DefaultHasher::new());
+
+        hashing_writer.write_all(b"Hello, ").unwrap();
+        let bufs = [IoSlice::new(b"wor"), IoSlice::new(b"ld!")];
+        assert_eq!(hashing_writer.write_vectored(&bufs).unwrap(), 6);
+        let (output, hash) = hashing_writer.finalize();
+
+        let mut reference = DefaultHasher::new();
+        reference.write(b"Hello, ");
+        reference.write(b"wor");
+        reference.write(b"ld!");
+
+        assert_eq!(output, b"Hello, world!");
+        assert_eq!(hash, reference.finish());
+    }
+
+    #[test]
+    fn hashing_writer_with_closure() {
+        let mut hash = 0x811c_9dc5;
+        let mut hashing_writer = HashingWriter::new(Vec::new(), |b: &[u8]| hash = fnv1a(hash, b));
+
+        hashing_writer.write_all(b"Hello, ").unwrap();
+        hashing_writer.write_all(b"world!").unwrap();
+        let (output, _) = hashing_writer.into_inner();
+
+        assert_eq!(output, b"Hello, world!");
+        // Reference value for FNV-1a (32-bit) of "Hello, world!".
+        assert_eq!(hash, 0xed90_f094);
+    }
+}
@@ -0,0 +1,164 @@
+use std::io::{self, Read, Write};
+
+use crate::{MultiWriteError, DEFAULT_BUF_SIZE};
+
+/// Stripes readers across its internal writers one byte at a time:
+/// with `n` writers, writer 0 receives bytes `0, n, 2n, ...`, writer 1 receives bytes `1, n + 1, 2n + 1, ...`, and so on.
+///
+/// The stride is always exactly the number of writers.
+/// The input is read in chunks of [`DEFAULT_BUF_SIZE`] bytes, and each writer receives its share of a chunk in a single [`write_all`](Write::write_all),
+/// rather than one call per byte.
+/// The stripe continues across calls to [`copy_all`](InterleaveCopier::copy_all):
+/// the next call starts with the writer after the one that received the final byte.
+///
+/// Reassembling the original stream is left to the caller.
+///
+/// # Examples
+///
+/// ```
+/// use streams::InterleaveCopier;
+///
+/// let mut copier = InterleaveCopier::new(vec![Vec::new(), Vec::new(), Vec::new()]);
+/// copier.copy_all(&mut &b"ABCDEF"[..])?;
+///
+/// assert_eq!(copier.into_inner(), [b"AD", b"BE", b"CF"]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct InterleaveCopier<W: Write> {
+    writers: Vec<W>,
+    current: usize,
+}
+
+impl<W: Write> InterleaveCopier<W> {
+    pub fn new(writers: Vec<W>) -> Self {
+        let current = 0;
+
+        Self { writers, current }
+    }
+
+    /// The index of the writer that will receive the next byte.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Consumes the `InterleaveCopier`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// Copy the entire contents of `reader`, distributing its bytes cyclically across the writers.
+    ///
+    /// Returns the total number of bytes copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// Errors from the reader are returned as-is.
+    /// If a writer fails, the returned error wraps a [`MultiWriteError`] reporting its index;
+    /// the writers before it have already received their share of the chunk being copied, and the stripe is not advanced past that chunk.
+    pub fn copy_all<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "interleave copier has no writers to copy into",
+            ));
+        }
+
+        let stride = self.writers.len();
+        let mut buf = vec![0; DEFAULT_BUF_SIZE];
+        let mut stripe = Vec::with_capacity(DEFAULT_BUF_SIZE / stride + 1);
+        let mut copied = 0;
+
+        loop {
+            let n = match reader.read(&mut buf) {
+                Ok(0) => return Ok(copied),
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            for (index, writer) in self.writers.iter_mut().enumerate() {
+                // The first byte of this chunk belongs to the current writer, so writer `index` starts `offset` bytes in.
+                let offset = (index + stride - self.current) % stride;
+
+                stripe.clear();
+                stripe.extend(buf[..n].iter().skip(offset).step_by(stride));
+
+                writer
+                    .write_all(&stripe)
+                    .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+            }
+
+            copied += n as u64;
+            self.current = (self.current + n) % stride;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{mocks::FailingWriter, InterleaveCopier, MultiWriteError};
+
+    #[test]
+    fn interleave_copier() {
+        let mut copier = InterleaveCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+
+        assert_eq!(copier.copy_all(&mut &b"ABCDEF"[..]).unwrap(), 6);
+        assert_eq!(copier.current_index(), 0);
+
+        assert_eq!(copier.into_inner(), [b"AD", b"BE", b"CF"]);
+    }
+
+    #[test]
+    fn interleave_copier_continues_stripe() {
+        let mut copier = InterleaveCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+
+        copier.copy_all(&mut &b"ABCD"[..]).unwrap();
+        assert_eq!(copier.current_index(), 1);
+        copier.copy_all(&mut &b"EFGHI"[..]).unwrap();
+        assert_eq!(copier.current_index(), 0);
+
+        assert_eq!(copier.into_inner(), [b"ADG", b"BEH", b"CFI"]);
+    }
+
+    #[test]
+    fn interleave_copier_large_input() {
+        let input: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+        let mut copier = InterleaveCopier::new(vec![Vec::<u8>::new(), Vec::new()]);
+
+        assert_eq!(copier.copy_all(&mut &input[..]).unwrap(), 20_000);
+
+        let writers = copier.into_inner();
+        let evens: Vec<u8> = input.iter().copied().step_by(2).collect();
+        let odds: Vec<u8> = input.iter().copied().skip(1).step_by(2).collect();
+        assert_eq!(writers[0], evens);
+        assert_eq!(writers[1], odds);
+    }
+
+    #[test]
+    fn interleave_copier_reports_failing_index() {
+        let mut sink = Vec::<u8>::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut copier = InterleaveCopier::new(vec![&mut sink as &mut dyn Write, &mut failing]);
+
+        let err = copier.copy_all(&mut &b"ABCDEF"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(copier.current_index(), 0);
+    }
+
+    #[test]
+    fn interleave_copier_without_writers() {
+        let mut copier = InterleaveCopier::new(Vec::<Vec<u8>>::new());
+
+        let err = copier.copy_all(&mut &b"ABCDEF"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
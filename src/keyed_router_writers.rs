@@ -0,0 +1,259 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+    io::{self, Write},
+};
+
+use crate::{
+    line_split_writers::{strip_newline, LineBuffer},
+    Sink,
+};
+
+/// Provides a [`Writer`](Write) routing each line to a writer selected by a key extracted from the line,
+/// for instance to split a combined log into one file per tenant in a single pass.
+///
+/// Written bytes are split into lines like in a [`LineSplitWriter`](crate::LineSplitWriter),
+/// and each complete line is passed to `key_fn` without its line ending.
+/// The line is then forwarded, along with its line ending, to the writer associated with the returned key,
+/// which is created by `factory` the first time the key is seen and cached afterwards.
+/// Lines for which `key_fn` returns `None` go to the default writer set with [`with_default`](KeyedRouterWriter::with_default),
+/// or are silently dropped if there is none.
+///
+/// Trailing bytes not terminated by a newline are routed as a final line by [`finish`](KeyedRouterWriter::finish),
+/// or when the `KeyedRouterWriter` is dropped, ignoring any errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::KeyedRouterWriter;
+///
+/// let mut router = KeyedRouterWriter::new(
+///     |line: &[u8]| line.split(|&b| b == b' ').next().map(<[u8]>::to_vec),
+///     |_tenant: &Vec<u8>| Ok(Vec::new()),
+/// );
+/// router.write_all(b"acme login\nglobex logout\nacme logout\n")?;
+///
+/// assert_eq!(router.get(&b"acme".to_vec()).unwrap(), b"acme login\nacme logout\n");
+/// assert_eq!(router.get(&b"globex".to_vec()).unwrap(), b"globex logout\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct KeyedRouterWriter<K, W, KF, F>
+where
+    K: Eq + Hash,
+    W: Write,
+    KF: FnMut(&[u8]) -> Option<K>,
+    F: FnMut(&K) -> io::Result<W>,
+{
+    key_fn: KF,
+    factory: F,
+    writers: HashMap<K, W>,
+    default: Option<W>,
+    lines: LineBuffer,
+}
+
+impl<K, W, KF, F> KeyedRouterWriter<K, W, KF, F>
+where
+    K: Eq + Hash,
+    W: Write,
+    KF: FnMut(&[u8]) -> Option<K>,
+    F: FnMut(&K) -> io::Result<W>,
+{
+    pub fn new(key_fn: KF, factory: F) -> Self {
+        let writers = HashMap::new();
+        let default = None;
+        let lines = LineBuffer::default();
+
+        Self {
+            key_fn,
+            factory,
+            writers,
+            default,
+            lines,
+        }
+    }
+
+    /// Route lines without a key to `writer` instead of dropping them.
+    pub fn with_default(mut self, writer: W) -> Self {
+        self.default = Some(writer);
+        self
+    }
+
+    /// The writer associated with `key`, if a line with that key has been routed.
+    pub fn get(&self, key: &K) -> Option<&W> {
+        self.writers.get(key)
+    }
+
+    /// The writers created so far, by key.
+    pub fn writers(&self) -> &HashMap<K, W> {
+        &self.writers
+    }
+
+    /// The default writer, if one was set.
+    pub fn default_writer(&self) -> Option<&W> {
+        self.default.as_ref()
+    }
+
+    /// Route any trailing bytes not terminated by a newline as a final line.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(line) = self.lines.take_rest() {
+            route(
+                &mut self.key_fn,
+                &mut self.factory,
+                &mut self.writers,
+                &mut self.default,
+                &line,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Forward `line` to the writer for its key, creating the writer if necessary.
+fn route<K: Eq + Hash, W: Write>(
+    key_fn: &mut impl FnMut(&[u8]) -> Option<K>,
+    factory: &mut impl FnMut(&K) -> io::Result<W>,
+    writers: &mut HashMap<K, W>,
+    default: &mut Option<W>,
+    line: &[u8],
+) -> io::Result<()> {
+    let writer = match key_fn(strip_newline(line)) {
+        Some(key) => match writers.entry(key) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let writer = factory(entry.key())?;
+                entry.insert(writer)
+            }
+        },
+        None => match default {
+            Some(writer) => writer,
+            None => return Ok(()),
+        },
+    };
+
+    writer.write_all(line)
+}
+
+impl<K, W, KF, F> Write for KeyedRouterWriter<K, W, KF, F>
+where
+    K: Eq + Hash,
+    W: Write,
+    KF: FnMut(&[u8]) -> Option<K>,
+    F: FnMut(&K) -> io::Result<W>,
+{
+    /// Buffer `buf`, routing each line it completes.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    ///
+    /// # Errors
+    ///
+    /// Errors from the factory and the selected writer are returned as-is.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let key_fn = &mut self.key_fn;
+        let factory = &mut self.factory;
+        let writers = &mut self.writers;
+        let default = &mut self.default;
+
+        self.lines
+            .push(buf, |line| route(key_fn, factory, writers, default, line))?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush every writer created so far and the default writer. Incomplete lines stay buffered.
+    fn flush(&mut self) -> io::Result<()> {
+        for writer in self.writers.values_mut().chain(&mut self.default) {
+            writer.flush()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K, W, KF, F> Sink for KeyedRouterWriter<K, W, KF, F>
+where
+    K: Eq + Hash,
+    W: Write,
+    KF: FnMut(&[u8]) -> Option<K>,
+    F: FnMut(&K) -> io::Result<W>,
+{
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Route the trailing bytes as a final line, then flush every writer.
+    fn finish(mut self) -> io::Result<()> {
+        KeyedRouterWriter::finish(&mut self)?;
+        self.flush()
+    }
+}
+
+impl<K, W, KF, F> Drop for KeyedRouterWriter<K, W, KF, F>
+where
+    K: Eq + Hash,
+    W: Write,
+    KF: FnMut(&[u8]) -> Option<K>,
+    F: FnMut(&K) -> io::Result<W>,
+{
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::KeyedRouterWriter;
+
+    /// The leading token of a line of the form `tenant: message`.
+    fn tenant(line: &[u8]) -> Option<String> {
+        let (tenant, _) = std::str::from_utf8(line).ok()?.split_once(": ")?;
+        Some(tenant.to_string())
+    }
+
+    #[test]
+    fn keyed_router_writer() {
+        let mut created = Vec::new();
+        let mut router = KeyedRouterWriter::new(tenant, |key: &String| {
+            created.push(key.clone());
+            Ok(Vec::<u8>::new())
+        })
+        .with_default(Vec::new());
+
+        router.write_all(b"acme: started\nglobex: sta").unwrap();
+        router
+            .write_all(b"rted\nno tenant\nacme: done\r\nglobex: done")
+            .unwrap();
+        router.finish().unwrap();
+
+        assert_eq!(router.writers().len(), 2);
+        assert_eq!(
+            router.get(&"acme".to_string()).unwrap(),
+            b"acme: started\nacme: done\r\n"
+        );
+        assert_eq!(
+            router.get(&"globex".to_string()).unwrap(),
+            b"globex: started\nglobex: done"
+        );
+        assert_eq!(router.default_writer().unwrap(), b"no tenant\n");
+
+        drop(router);
+        assert_eq!(created, ["acme", "globex"]);
+    }
+
+    #[test]
+    fn keyed_router_writer_factory_error() {
+        let mut router = KeyedRouterWriter::new(tenant, |key: &String| match key.as_str() {
+            "acme" => Ok(Vec::<u8>::new()),
+            _ => Err(io::Error::from(io::ErrorKind::PermissionDenied)),
+        });
+
+        // Lines without a key are dropped without a default writer.
+        router.write_all(b"no tenant\nacme: ok\n").unwrap();
+        let err = router.write_all(b"initech: denied\n").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(router.get(&"acme".to_string()).unwrap(), b"acme: ok\n");
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::{self, Read, Write};
+
+/// Copies each reader into whichever internal writer has received the fewest bytes so far.
+///
+/// Ties are broken by selecting the writer with the lowest index.
+/// Compared to [`RoundRobinCopier`](crate::RoundRobinCopier), this evens out the distribution of bytes when copies vary in length.
+pub struct LeastLoadedCopier<'a> {
+    writers: Vec<&'a mut dyn Write>,
+    loads: Vec<u64>,
+}
+
+impl<'a> LeastLoadedCopier<'a> {
+    pub fn new(writers: Vec<&'a mut dyn Write>) -> Self {
+        let loads = vec![0; writers.len()];
+
+        Self { writers, loads }
+    }
+
+    /// The number of bytes each internal writer has received so far, indexed like the writers.
+    pub fn loads(&self) -> &[u64] {
+        &self.loads
+    }
+
+    /// Copy the entire contents of `reader` into the least loaded writer.
+    ///
+    /// If the copy fails, the bytes copied before the failure are not counted towards the writer's load.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        let index = self
+            .loads
+            .iter()
+            .enumerate()
+            .min_by_key(|&(_, load)| load)
+            .map(|(index, _)| index)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "least-loaded copier has no writers to copy into",
+                )
+            })?;
+
+        let copied = io::copy(reader, self.writers[index])?;
+        self.loads[index] += copied;
+
+        Ok(copied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::LeastLoadedCopier;
+
+    #[test]
+    fn least_loaded_copier() {
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier =
+            LeastLoadedCopier::new(writers.iter_mut().map(|w| w as &mut dyn Write).collect());
+
+        copier.copy(&mut &[b'a'; 100][..]).unwrap();
+        copier.copy(&mut &[b'b'; 10][..]).unwrap();
+        copier.copy(&mut &[b'c'; 10][..]).unwrap();
+        assert_eq!(copier.loads(), [100, 10, 10]);
+
+        // Ties are broken by the lowest index.
+        copier.copy(&mut &[b'd'; 5][..]).unwrap();
+        assert_eq!(copier.loads(), [100, 15, 10]);
+
+        assert_eq!(writers[0], [b'a'; 100]);
+        assert_eq!(writers[1], b"bbbbbbbbbbddddd");
+        assert_eq!(writers[2], [b'c'; 10]);
+    }
+
+    #[test]
+    fn least_loaded_copier_without_writers() {
+        let mut copier = LeastLoadedCopier::new(Vec::new());
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "least-loaded copier has no writers to copy into"
+        );
+    }
+}
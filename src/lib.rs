@@ -1,5 +1,261 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "tokio")]
+mod async_bridge_writers;
+#[cfg(feature = "tokio")]
+mod async_multi_writers;
+#[cfg(feature = "tokio")]
+mod async_round_robin_copiers;
+#[cfg(feature = "std")]
+mod base64_writers;
+#[cfg(feature = "std")]
+mod boxed_multi_writers;
+#[cfg(feature = "std")]
+mod broadcast_writers;
+#[cfg(feature = "std")]
+mod buffered_multi_writers;
+#[cfg(feature = "bytes")]
+mod bytes_writers;
+#[cfg(feature = "std")]
+mod callback_writers;
+#[cfg(feature = "std")]
+mod channel_writers;
+#[cfg(feature = "std")]
+mod checkpoint_writers;
+#[cfg(feature = "std")]
+mod chunked_shard_copiers;
+#[cfg(feature = "std")]
+mod chunking_writers;
+#[cfg(feature = "flate2")]
+mod compress_writers;
+#[cfg(feature = "std")]
+mod counting_writers;
+#[cfg(feature = "std")]
+mod dedup_writers;
+#[cfg(feature = "std")]
+mod drain_readers;
+#[cfg(feature = "std")]
+mod dual_writers;
+#[cfg(feature = "embedded-io")]
+mod embedded_multi_writers;
+#[cfg(feature = "embedded-io")]
+mod embedded_round_robin_copiers;
+#[cfg(feature = "std")]
+mod errors;
+#[cfg(feature = "std")]
+mod failover_round_robin_copiers;
+#[cfg(feature = "std")]
+mod fallback_writers;
+#[cfg(feature = "std")]
+mod filter_writers;
+#[cfg(feature = "std")]
+mod flush_on_drop_writers;
+#[cfg(feature = "std")]
+mod hashing_writers;
+#[cfg(feature = "std")]
+mod interleave_copiers;
+#[cfg(feature = "std")]
+mod keyed_router_writers;
+#[cfg(feature = "std")]
+mod least_loaded_copiers;
+#[cfg(feature = "std")]
+mod limit_readers;
+#[cfg(feature = "std")]
+mod limit_writers;
+#[cfg(feature = "std")]
+mod line_split_writers;
+#[cfg(feature = "std")]
+mod map_writers;
+#[cfg(feature = "std")]
+mod merge_readers;
+#[cfg(all(test, feature = "std"))]
+mod mocks;
+#[cfg(feature = "std")]
+mod multi_readers;
+#[cfg(feature = "std")]
+mod multi_writer_builders;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
 mod multi_writers;
+#[cfg(feature = "std")]
+mod newline_writers;
+#[cfg(feature = "std")]
+mod parallel_multi_writers;
+#[cfg(feature = "std")]
+mod prefix_writers;
+#[cfg(feature = "std")]
+pub mod prelude;
+#[cfg(feature = "std")]
+mod priority_multi_writers;
+#[cfg(feature = "std")]
+mod progress_readers;
+#[cfg(feature = "std")]
+mod random_copiers;
+#[cfg(feature = "std")]
+mod range_scatter_copiers;
+#[cfg(feature = "std")]
+mod rate_limited_writers;
+#[cfg(feature = "std")]
+mod record_framing_writers;
+#[cfg(feature = "std")]
+mod retry_writers;
+#[cfg(feature = "std")]
+mod ring_buffer_writers;
+#[cfg(feature = "std")]
+mod rolling_file_copiers;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
 mod round_robin_copiers;
+#[cfg(feature = "std")]
+mod seekable_multi_writers;
+#[cfg(feature = "std")]
+mod shared_multi_writers;
+#[cfg(feature = "std")]
+mod sink_writers;
+#[cfg(feature = "std")]
+mod sinks;
+#[cfg(feature = "std")]
+mod size_rotating_copiers;
+#[cfg(feature = "std")]
+mod staging_multi_writers;
+#[cfg(feature = "std")]
+mod strategy_copiers;
+#[cfg(feature = "std")]
+mod tee_readers;
+#[cfg(feature = "std")]
+mod timeout_writers;
+#[cfg(feature = "std")]
+mod transform_multi_writers;
+#[cfg(feature = "std")]
+mod transform_readers;
+#[cfg(feature = "std")]
+mod wc_writers;
+#[cfg(feature = "std")]
+mod weighted_round_robin_copiers;
 
+#[cfg(feature = "tokio")]
+pub use async_bridge_writers::*;
+#[cfg(feature = "tokio")]
+pub use async_multi_writers::*;
+#[cfg(feature = "tokio")]
+pub use async_round_robin_copiers::*;
+#[cfg(feature = "std")]
+pub use base64_writers::*;
+#[cfg(feature = "std")]
+pub use boxed_multi_writers::*;
+#[cfg(feature = "std")]
+pub use broadcast_writers::*;
+#[cfg(feature = "std")]
+pub use buffered_multi_writers::*;
+#[cfg(feature = "bytes")]
+pub use bytes_writers::*;
+#[cfg(feature = "std")]
+pub use callback_writers::*;
+#[cfg(feature = "std")]
+pub use channel_writers::*;
+#[cfg(feature = "std")]
+pub use checkpoint_writers::*;
+#[cfg(feature = "std")]
+pub use chunked_shard_copiers::*;
+#[cfg(feature = "std")]
+pub use chunking_writers::*;
+#[cfg(feature = "flate2")]
+pub use compress_writers::*;
+#[cfg(feature = "std")]
+pub use counting_writers::*;
+#[cfg(feature = "std")]
+pub use dedup_writers::*;
+#[cfg(feature = "std")]
+pub use drain_readers::*;
+#[cfg(feature = "std")]
+pub use dual_writers::*;
+#[cfg(feature = "embedded-io")]
+pub use embedded_multi_writers::*;
+#[cfg(feature = "embedded-io")]
+pub use embedded_round_robin_copiers::*;
+#[cfg(feature = "std")]
+pub use errors::*;
+#[cfg(feature = "std")]
+pub use failover_round_robin_copiers::*;
+#[cfg(feature = "std")]
+pub use fallback_writers::*;
+#[cfg(feature = "std")]
+pub use filter_writers::*;
+#[cfg(feature = "std")]
+pub use flush_on_drop_writers::*;
+#[cfg(feature = "std")]
+pub use hashing_writers::*;
+#[cfg(feature = "std")]
+pub use interleave_copiers::*;
+#[cfg(feature = "std")]
+pub use keyed_router_writers::*;
+#[cfg(feature = "std")]
+pub use least_loaded_copiers::*;
+#[cfg(feature = "std")]
+pub use limit_readers::*;
+#[cfg(feature = "std")]
+pub use limit_writers::*;
+#[cfg(feature = "std")]
+pub use line_split_writers::*;
+#[cfg(feature = "std")]
+pub use map_writers::*;
+#[cfg(feature = "std")]
+pub use merge_readers::*;
+#[cfg(feature = "std")]
+pub use multi_readers::*;
+#[cfg(feature = "std")]
+pub use multi_writer_builders::*;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
 pub use multi_writers::*;
+#[cfg(feature = "std")]
+pub use newline_writers::*;
+#[cfg(feature = "std")]
+pub use parallel_multi_writers::*;
+#[cfg(feature = "std")]
+pub use prefix_writers::*;
+#[cfg(feature = "std")]
+pub use priority_multi_writers::*;
+#[cfg(feature = "std")]
+pub use progress_readers::*;
+#[cfg(feature = "std")]
+pub use random_copiers::*;
+#[cfg(feature = "std")]
+pub use range_scatter_copiers::*;
+#[cfg(feature = "std")]
+pub use rate_limited_writers::*;
+#[cfg(feature = "std")]
+pub use record_framing_writers::*;
+#[cfg(feature = "std")]
+pub use retry_writers::*;
+#[cfg(feature = "std")]
+pub use ring_buffer_writers::*;
+#[cfg(feature = "std")]
+pub use rolling_file_copiers::*;
+#[cfg(any(feature = "std", feature = "embedded-io"))]
 pub use round_robin_copiers::*;
+#[cfg(feature = "std")]
+pub use seekable_multi_writers::*;
+#[cfg(feature = "std")]
+pub use shared_multi_writers::*;
+#[cfg(feature = "std")]
+pub use sink_writers::*;
+#[cfg(feature = "std")]
+pub use sinks::*;
+#[cfg(feature = "std")]
+pub use size_rotating_copiers::*;
+#[cfg(feature = "std")]
+pub use staging_multi_writers::*;
+#[cfg(feature = "std")]
+pub use strategy_copiers::*;
+#[cfg(feature = "std")]
+pub use tee_readers::*;
+#[cfg(feature = "std")]
+pub use timeout_writers::*;
+#[cfg(feature = "std")]
+pub use transform_multi_writers::*;
+#[cfg(feature = "std")]
+pub use transform_readers::*;
+#[cfg(feature = "std")]
+pub use wc_writers::*;
+#[cfg(feature = "std")]
+pub use weighted_round_robin_copiers::*;
@@ -0,0 +1,117 @@
+use std::io::{self, Read};
+
+/// Wraps a borrowed reader, yielding at most `limit` bytes before reporting end of file.
+///
+/// This behaves like [`Read::take`], but borrows a `&mut dyn Read` instead of consuming the reader,
+/// so it can cap a reader held for a [`MultiReader`](crate::MultiReader) or a fan-out copy.
+/// Bytes past the limit are left unread in the internal reader.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+/// use streams::LimitReader;
+///
+/// let mut reader = &b"Hello, world!"[..];
+/// let mut output = Vec::new();
+/// io::copy(&mut LimitReader::new(&mut reader, 5), &mut output)?;
+///
+/// assert_eq!(output, b"Hello");
+/// assert_eq!(reader, b", world!");
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct LimitReader<'a> {
+    reader: &'a mut dyn Read,
+    remaining: u64,
+}
+
+impl<'a> LimitReader<'a> {
+    pub fn new(reader: &'a mut dyn Read, limit: u64) -> Self {
+        Self {
+            reader,
+            remaining: limit,
+        }
+    }
+
+    /// The number of bytes that can still be read before reaching the limit.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Consumes the `LimitReader`, returning the internal reader.
+    pub fn into_inner(self) -> &'a mut dyn Read {
+        self.reader
+    }
+}
+
+impl<'a> Read for LimitReader<'a> {
+    /// Read from the internal reader, shortening reads that would cross the limit.
+    ///
+    /// Returns `Ok(0)` without reading once the limit has been reached.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining == 0 {
+            return Ok(0);
+        }
+
+        let len = buf
+            .len()
+            .min(self.remaining.try_into().unwrap_or(usize::MAX));
+        let n = self.reader.read(&mut buf[..len])?;
+        self.remaining -= n as u64;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use crate::LimitReader;
+
+    fn read_limited(input: &[u8], limit: u64) -> (Vec<u8>, u64) {
+        let mut reader = input;
+        let mut limit_reader = LimitReader::new(&mut reader, limit);
+
+        let mut output = Vec::new();
+        limit_reader.read_to_end(&mut output).unwrap();
+
+        (output, limit_reader.remaining())
+    }
+
+    #[test]
+    fn limit_reader_smaller_than_source() {
+        assert_eq!(read_limited(b"Hello, world!", 5), (b"Hello".to_vec(), 0));
+    }
+
+    #[test]
+    fn limit_reader_equal_to_source() {
+        assert_eq!(
+            read_limited(b"Hello, world!", 13),
+            (b"Hello, world!".to_vec(), 0)
+        );
+    }
+
+    #[test]
+    fn limit_reader_larger_than_source() {
+        assert_eq!(
+            read_limited(b"Hello, world!", 20),
+            (b"Hello, world!".to_vec(), 7)
+        );
+    }
+
+    #[test]
+    fn limit_reader_read_spanning_limit() {
+        let mut reader = &b"Hello, world!"[..];
+        let mut limit_reader = LimitReader::new(&mut reader, 8);
+
+        let mut buf = [0; 5];
+        assert_eq!(limit_reader.read(&mut buf).unwrap(), 5);
+        assert_eq!(limit_reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf[..3], *b", w");
+        assert_eq!(limit_reader.read(&mut buf).unwrap(), 0);
+
+        limit_reader.into_inner();
+        assert_eq!(reader, b"orld!");
+    }
+}
@@ -0,0 +1,140 @@
+use std::io::{self, Write};
+
+/// Wraps a writer, capping the total number of bytes written to it.
+///
+/// Writes crossing the limit are shortened, so exactly `limit` bytes reach the internal writer and no more.
+/// Once the limit has been reached, further writes behave according to the [`LimitBehavior`].
+///
+/// Within a [`MultiWriter`](crate::MultiWriter), either behavior makes the fan-out fail at the limit,
+/// as [`write_all`](Write::write_all) turns `Ok(0)` into a [`WriteZero`](io::ErrorKind::WriteZero) error;
+/// use [`ErrorPolicy::ContinueAndCollect`](crate::ErrorPolicy::ContinueAndCollect) to keep writing to the other writers.
+pub struct LimitWriter<W: Write> {
+    writer: W,
+    limit: u64,
+    written: u64,
+    on_limit: LimitBehavior,
+}
+
+/// Determines how a [`LimitWriter`] responds to writes once its limit has been reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitBehavior {
+    /// Return `Ok(0)`, signaling that no more bytes can be accepted.
+    #[default]
+    ReturnZero,
+    /// Return an error of kind [`Other`](io::ErrorKind::Other).
+    Error,
+}
+
+impl<W: Write> LimitWriter<W> {
+    /// Create a `LimitWriter` using [`LimitBehavior::ReturnZero`].
+    pub fn new(writer: W, limit: u64) -> Self {
+        Self::with_behavior(writer, limit, LimitBehavior::default())
+    }
+
+    pub fn with_behavior(writer: W, limit: u64, on_limit: LimitBehavior) -> Self {
+        let written = 0;
+
+        Self {
+            writer,
+            limit,
+            written,
+            on_limit,
+        }
+    }
+
+    /// The number of bytes that can still be written before reaching the limit.
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.written
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `LimitWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> Write for LimitWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let remaining = self.remaining();
+
+        if remaining == 0 {
+            return match self.on_limit {
+                LimitBehavior::ReturnZero => Ok(0),
+                LimitBehavior::Error => Err(io::Error::other("write limit reached")),
+            };
+        }
+
+        let len = buf
+            .len()
+            .min(usize::try_from(remaining).unwrap_or(usize::MAX));
+        let n = self.writer.write(&buf[..len])?;
+        self.written += n as u64;
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{LimitBehavior, LimitWriter};
+
+    #[test]
+    fn limit_writer_under_limit() {
+        let mut limit_writer = LimitWriter::new(Vec::new(), 20);
+
+        limit_writer.write_all(b"Hello, world!").unwrap();
+
+        assert_eq!(limit_writer.remaining(), 7);
+        assert_eq!(limit_writer.into_inner(), b"Hello, world!");
+    }
+
+    #[test]
+    fn limit_writer_exact_limit() {
+        let mut limit_writer = LimitWriter::new(Vec::new(), 13);
+
+        limit_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(limit_writer.remaining(), 0);
+        assert_eq!(limit_writer.write(b"!").unwrap(), 0);
+
+        assert_eq!(limit_writer.into_inner(), b"Hello, world!");
+    }
+
+    #[test]
+    fn limit_writer_over_limit() {
+        let mut limit_writer = LimitWriter::new(Vec::new(), 5);
+
+        assert_eq!(limit_writer.write(b"Hello, world!").unwrap(), 5);
+        assert_eq!(limit_writer.write(b"Hello, world!").unwrap(), 0);
+
+        let err = limit_writer.write_all(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(limit_writer.into_inner(), b"Hello");
+    }
+
+    #[test]
+    fn limit_writer_error_behavior() {
+        let mut limit_writer = LimitWriter::with_behavior(Vec::new(), 5, LimitBehavior::Error);
+
+        assert_eq!(limit_writer.write(b"Hello, world!").unwrap(), 5);
+
+        let err = limit_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        assert_eq!(err.to_string(), "write limit reached");
+        assert_eq!(limit_writer.into_inner(), b"Hello");
+    }
+}
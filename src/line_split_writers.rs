@@ -0,0 +1,203 @@
+use std::io::{self, Write};
+
+use crate::Sink;
+
+/// Wraps a callback, splitting the bytes written into lines and calling the callback with each complete line.
+///
+/// Unlike [`std::io::LineWriter`], which forwards complete lines to another writer,
+/// a `LineSplitWriter` hands each line to `on_line` individually,
+/// e.g. to parse structured logs while a sibling writer in a [`MultiWriter`](crate::MultiWriter) persists the raw bytes.
+///
+/// Lines end with `\n`, which is stripped along with a preceding `\r` unless the writer was created with [`keep_newlines`](LineSplitWriter::keep_newlines).
+/// Bytes are buffered until their line is complete, so lines may span multiple writes.
+///
+/// Trailing bytes not terminated by a newline are passed to `on_line` by [`finish`](LineSplitWriter::finish),
+/// or when the `LineSplitWriter` is dropped.
+/// [`flush`](LineSplitWriter::flush) does not emit incomplete lines.
+pub struct LineSplitWriter<F: FnMut(&[u8])> {
+    on_line: F,
+    keep_newlines: bool,
+    lines: LineBuffer,
+}
+
+impl<F: FnMut(&[u8])> LineSplitWriter<F> {
+    /// Create a `LineSplitWriter` passing lines to `on_line` without their line endings.
+    pub fn new(on_line: F) -> Self {
+        let keep_newlines = false;
+        let lines = LineBuffer::default();
+
+        Self {
+            on_line,
+            keep_newlines,
+            lines,
+        }
+    }
+
+    /// Create a `LineSplitWriter` passing lines to `on_line` including their line endings (`\n` or `\r\n`).
+    pub fn keep_newlines(on_line: F) -> Self {
+        let mut line_split_writer = Self::new(on_line);
+        line_split_writer.keep_newlines = true;
+
+        line_split_writer
+    }
+
+    /// Pass any trailing bytes not terminated by a newline to `on_line` as a final line.
+    ///
+    /// As `on_line` can't fail, this always succeeds.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(line) = self.lines.take_rest() {
+            (self.on_line)(&line);
+        }
+
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&[u8])> Write for LineSplitWriter<F> {
+    /// Buffer `buf`, calling `on_line` with each line it completes.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let keep_newlines = self.keep_newlines;
+        let on_line = &mut self.on_line;
+
+        self.lines.push(buf, |line| {
+            on_line(if keep_newlines {
+                line
+            } else {
+                strip_newline(line)
+            });
+            Ok(())
+        })?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<F: FnMut(&[u8])> Sink for LineSplitWriter<F> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Pass the trailing bytes to `on_line` as a final line.
+    fn finish(mut self) -> io::Result<()> {
+        LineSplitWriter::finish(&mut self)
+    }
+}
+
+impl<F: FnMut(&[u8])> Drop for LineSplitWriter<F> {
+    fn drop(&mut self) {
+        // `finish` never fails.
+        let _ = self.finish();
+    }
+}
+
+/// Splits a stream of bytes into lines terminated by `\n`, buffering incomplete lines across calls.
+#[derive(Default)]
+pub(crate) struct LineBuffer {
+    partial: Vec<u8>,
+}
+
+impl LineBuffer {
+    /// Split `buf` into lines, calling `on_line` with each complete line, including its `\n`.
+    ///
+    /// The line is assembled in the internal buffer only if it started in a previous call.
+    pub(crate) fn push(
+        &mut self,
+        mut buf: &[u8],
+        mut on_line: impl FnMut(&[u8]) -> io::Result<()>,
+    ) -> io::Result<()> {
+        while let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+            let (line, rest) = buf.split_at(pos + 1);
+
+            if self.partial.is_empty() {
+                on_line(line)?;
+            } else {
+                self.partial.extend_from_slice(line);
+                let result = on_line(&self.partial);
+                self.partial.clear();
+                result?;
+            }
+
+            buf = rest;
+        }
+
+        self.partial.extend_from_slice(buf);
+
+        Ok(())
+    }
+
+    /// Take the buffered bytes of an incomplete line, if there are any.
+    pub(crate) fn take_rest(&mut self) -> Option<Vec<u8>> {
+        if self.partial.is_empty() {
+            None
+        } else {
+            Some(std::mem::take(&mut self.partial))
+        }
+    }
+}
+
+/// Strip a trailing `\n` or `\r\n` from `line`.
+pub(crate) fn strip_newline(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::LineSplitWriter;
+
+    #[test]
+    fn line_split_writer_multiple_lines() {
+        let mut lines = Vec::new();
+        let mut line_split_writer = LineSplitWriter::new(|line| lines.push(line.to_vec()));
+
+        line_split_writer.write_all(b"one\ntwo\n\nthree\n").unwrap();
+        drop(line_split_writer);
+
+        assert_eq!(lines, [&b"one"[..], b"two", b"", b"three"]);
+    }
+
+    #[test]
+    fn line_split_writer_split_line() {
+        let mut lines = Vec::new();
+        let mut line_split_writer = LineSplitWriter::new(|line| lines.push(line.to_vec()));
+
+        line_split_writer.write_all(b"Hello, ").unwrap();
+        line_split_writer.write_all(b"world!\nHello").unwrap();
+        line_split_writer
+            .write_all(b" again\nunterminated")
+            .unwrap();
+        line_split_writer.finish().unwrap();
+        drop(line_split_writer);
+
+        assert_eq!(
+            lines,
+            [&b"Hello, world!"[..], b"Hello again", b"unterminated"]
+        );
+    }
+
+    #[test]
+    fn line_split_writer_crlf() {
+        let mut stripped = Vec::new();
+        let mut line_split_writer = LineSplitWriter::new(|line| stripped.push(line.to_vec()));
+        line_split_writer.write_all(b"one\r\ntwo\r").unwrap();
+        line_split_writer.write_all(b"\nthree\n").unwrap();
+        drop(line_split_writer);
+
+        let mut kept = Vec::new();
+        let mut line_split_writer = LineSplitWriter::keep_newlines(|line| kept.push(line.to_vec()));
+        line_split_writer.write_all(b"one\r\ntwo\r").unwrap();
+        line_split_writer.write_all(b"\nthree\n").unwrap();
+        drop(line_split_writer);
+
+        assert_eq!(stripped, [&b"one"[..], b"two", b"three"]);
+        assert_eq!(kept, [&b"one\r\n"[..], b"two\r\n", b"three\n"]);
+    }
+}
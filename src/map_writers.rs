@@ -0,0 +1,118 @@
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+/// Wraps a writer, transforming each buffer before forwarding it.
+///
+/// Each call to [`write`](MapWriter::write) passes the entire buffer to `transform`
+/// and writes the result to the internal writer using [`write_all`](Write::write_all).
+/// Since the transformed buffer may differ in length from the input, the returned `usize` is relative to the input:
+/// it is always exactly `buf.len()` on success.
+///
+/// `transform` sees the stream in whatever chunks it was written in.
+/// Stateful transforms spanning chunk boundaries (e.g. multi-byte encodings) are the caller's responsibility.
+///
+/// # Examples
+///
+/// ```
+/// use std::{borrow::Cow, io::Write};
+/// use streams::MapWriter;
+///
+/// let mut map_writer = MapWriter::new(Vec::new(), |buf| Cow::Owned(buf.to_ascii_uppercase()));
+/// map_writer.write_all(b"Hello, world!")?;
+///
+/// assert_eq!(map_writer.into_inner(), b"HELLO, WORLD!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct MapWriter<W: Write, F: FnMut(&[u8]) -> Cow<'_, [u8]>> {
+    writer: W,
+    transform: F,
+}
+
+impl<W: Write, F: FnMut(&[u8]) -> Cow<'_, [u8]>> MapWriter<W, F> {
+    pub fn new(writer: W, transform: F) -> Self {
+        Self { writer, transform }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `MapWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, F: FnMut(&[u8]) -> Cow<'_, [u8]>> Write for MapWriter<W, F> {
+    /// Transform `buf` and write the result to the internal writer.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write_all(&(self.transform)(buf))?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{borrow::Cow, io::Write};
+
+    use crate::{MapWriter, MultiWriter};
+
+    fn rot13(buf: &[u8]) -> Cow<'_, [u8]> {
+        buf.iter()
+            .map(|&b| match b {
+                b'a'..=b'z' => (b - b'a' + 13) % 26 + b'a',
+                b'A'..=b'Z' => (b - b'A' + 13) % 26 + b'A',
+                _ => b,
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn map_writer_rot13() {
+        let mut raw = Vec::<u8>::new();
+        let mut rot13_writer = MapWriter::new(Vec::new(), rot13);
+        let mut multi_writer = MultiWriter::new(vec![&mut raw, &mut rot13_writer]);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.write_all(b"world!").unwrap();
+
+        assert_eq!(raw, b"Hello, world!");
+        assert_eq!(rot13_writer.into_inner(), b"Uryyb, jbeyq!");
+    }
+
+    #[test]
+    fn map_writer_differing_length() {
+        let mut map_writer = MapWriter::new(Vec::new(), |buf: &[u8]| {
+            if buf.contains(&b'&') {
+                Cow::Owned(
+                    buf.iter()
+                        .flat_map(|&b| {
+                            if b == b'&' {
+                                b"&amp;".to_vec()
+                            } else {
+                                vec![b]
+                            }
+                        })
+                        .collect(),
+                )
+            } else {
+                Cow::Borrowed(buf)
+            }
+        });
+
+        assert_eq!(map_writer.write(b"a & b").unwrap(), 5);
+        assert_eq!(map_writer.write(b", c").unwrap(), 3);
+        assert_eq!(map_writer.into_inner(), b"a &amp; b, c");
+    }
+}
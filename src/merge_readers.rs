@@ -0,0 +1,198 @@
+use std::io::{self, BufRead, Read};
+
+/// Provides a single [`Reader`](Read) that interleaves the lines of multiple buffered readers, taking one line from each in turn.
+///
+/// Unlike a [`MultiReader`](crate::MultiReader), which concatenates its readers, this emits the first line of every reader,
+/// then the second line of every reader, and so on.
+/// Once a reader is exhausted, it is skipped, and the remaining readers keep taking turns until every one of them is exhausted.
+/// A final line without a trailing newline gets one appended, so it never runs into the line of the next reader.
+///
+/// `MergeReader` also implements [`BufRead`], returning at most one line from [`fill_buf`](BufRead::fill_buf).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, BufRead, Read};
+/// use streams::MergeReader;
+///
+/// let mut reader1 = &b"a1\na2\na3\n"[..];
+/// let mut reader2 = &b"b1\n"[..];
+/// let mut merge_reader = MergeReader::new(vec![&mut reader1 as &mut dyn BufRead, &mut reader2]);
+///
+/// let mut output = String::new();
+/// merge_reader.read_to_string(&mut output)?;
+/// assert_eq!(output, "a1\nb1\na2\na3\n");
+/// # Ok::<(), io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Errors from the internal readers are returned as-is, and the same reader is tried again on the next call,
+/// continuing the line it was reading.
+pub struct MergeReader<'a> {
+    readers: Vec<&'a mut dyn BufRead>,
+    exhausted: Vec<bool>,
+    current: usize,
+    line: Vec<u8>,
+    pos: usize,
+    partial: Vec<u8>,
+}
+
+impl<'a> MergeReader<'a> {
+    pub fn new(readers: Vec<&'a mut dyn BufRead>) -> Self {
+        let exhausted = vec![false; readers.len()];
+        let current = 0;
+        let line = Vec::new();
+        let pos = 0;
+        let partial = Vec::new();
+
+        Self {
+            readers,
+            exhausted,
+            current,
+            line,
+            pos,
+            partial,
+        }
+    }
+
+    /// Read the next line into `line`, from the next reader in turn that isn't exhausted, unless the current line hasn't been consumed yet.
+    /// Leaves `line` empty once every reader is exhausted.
+    fn next_line(&mut self) -> io::Result<()> {
+        while self.pos == self.line.len() {
+            self.line.clear();
+            self.pos = 0;
+
+            if !self.exhausted.contains(&false) {
+                return Ok(());
+            }
+
+            let index = self.current;
+            self.current = (self.current + 1) % self.readers.len();
+
+            if self.exhausted[index] {
+                continue;
+            }
+
+            let result = self.readers[index].read_until(b'\n', &mut self.partial);
+            if let Err(e) = result {
+                // Try the same reader again on the next call, keeping the part of the line read so far.
+                self.current = index;
+                return Err(e);
+            }
+
+            if !self.partial.ends_with(b"\n") {
+                // `read_until` only stops before a newline at end of file.
+                self.exhausted[index] = true;
+                if !self.partial.is_empty() {
+                    self.partial.push(b'\n');
+                }
+            }
+
+            std::mem::swap(&mut self.line, &mut self.partial);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Read for MergeReader<'a> {
+    /// Read from the current line, reading the next line from the next reader in turn once it has been consumed.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+
+        Ok(n)
+    }
+}
+
+impl<'a> BufRead for MergeReader<'a> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.next_line()?;
+
+        Ok(&self.line[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.line.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufRead, BufReader, Cursor, Read};
+
+    use crate::MergeReader;
+
+    #[test]
+    fn merge_reader() {
+        let mut reader1 = Cursor::new(b"a1\na2\na3\na4\n".to_vec());
+        let mut reader2 = Cursor::new(b"b1\nb2\n".to_vec());
+        let mut merge_reader = MergeReader::new(vec![&mut reader1, &mut reader2]);
+
+        let lines: Vec<_> = merge_reader.by_ref().lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["a1", "b1", "a2", "b2", "a3", "a4"]);
+
+        let mut buf = [0; 4];
+        assert_eq!(merge_reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn merge_reader_small_reads() {
+        // Tiny internal buffers and a tiny read buffer, so lines are split across reads on both sides.
+        let mut reader1 = BufReader::with_capacity(2, &b"first line\nthird"[..]);
+        let mut reader2 = BufReader::with_capacity(3, &b"second line\n\nfourth\n"[..]);
+        let mut reader3 = &b""[..];
+        let mut merge_reader = MergeReader::new(vec![&mut reader1, &mut reader2, &mut reader3]);
+
+        let mut output = Vec::new();
+        let mut buf = [0; 3];
+        loop {
+            let n = merge_reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            output.extend_from_slice(&buf[..n]);
+        }
+
+        // The unterminated last line of the first reader gets a newline.
+        assert_eq!(output, b"first line\nsecond line\nthird\n\nfourth\n");
+    }
+
+    #[test]
+    fn merge_reader_error_resumes_line() {
+        /// Returns "par", then an error, then "tial\n".
+        struct FlakyReader {
+            calls: usize,
+        }
+
+        impl Read for FlakyReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.calls += 1;
+                let chunk: &[u8] = match self.calls {
+                    1 => b"par",
+                    2 => return Err(io::Error::from(io::ErrorKind::TimedOut)),
+                    3 => b"tial\n",
+                    _ => b"",
+                };
+                buf[..chunk.len()].copy_from_slice(chunk);
+
+                Ok(chunk.len())
+            }
+        }
+
+        let mut flaky = BufReader::new(FlakyReader { calls: 0 });
+        let mut other = &b"other\n"[..];
+        let mut merge_reader = MergeReader::new(vec![&mut flaky as &mut dyn BufRead, &mut other]);
+
+        let mut output = Vec::new();
+        let err = merge_reader.read_to_end(&mut output).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(output.is_empty());
+
+        merge_reader.read_to_end(&mut output).unwrap();
+        assert_eq!(output, b"partial\nother\n");
+    }
+}
@@ -0,0 +1,107 @@
+//! Mock readers and writers shared by the unit tests.
+
+use std::{
+    cell::RefCell,
+    io::{self, Write},
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+/// A writer that fails every call with the given [`io::ErrorKind`].
+pub(crate) struct FailingWriter {
+    pub(crate) kind: io::ErrorKind,
+}
+
+impl FailingWriter {
+    pub(crate) fn new(kind: io::ErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::from(self.kind))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Err(io::Error::from(self.kind))
+    }
+}
+
+/// A writer accepting no bytes at all, returning `Ok(0)` from every call to `write`.
+pub(crate) struct ZeroWriter;
+
+impl Write for ZeroWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// A writer appending into a shared buffer, so the written contents can be inspected while the writer is borrowed.
+#[derive(Clone, Default)]
+pub(crate) struct SharedWriter {
+    pub(crate) buf: Rc<RefCell<Vec<u8>>>,
+    pub(crate) writes: Rc<RefCell<usize>>,
+}
+
+impl SharedWriter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// A copy of everything written so far.
+    pub(crate) fn contents(&self) -> Vec<u8> {
+        self.buf.borrow().clone()
+    }
+
+    /// The number of calls to `write` so far.
+    pub(crate) fn write_calls(&self) -> usize {
+        *self.writes.borrow()
+    }
+}
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        *self.writes.borrow_mut() += 1;
+        self.buf.borrow_mut().extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Like [`SharedWriter`], but [`Send`], for tests involving threads.
+#[derive(Clone, Default)]
+pub(crate) struct SyncSharedWriter {
+    pub(crate) buf: Arc<Mutex<Vec<u8>>>,
+}
+
+impl SyncSharedWriter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// A copy of everything written so far.
+    pub(crate) fn contents(&self) -> Vec<u8> {
+        self.buf.lock().unwrap().clone()
+    }
+}
+
+impl Write for SyncSharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.lock().unwrap().extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    io::{self, IoSliceMut, Read, Write},
+    sync::mpsc,
+    thread,
+};
+
+use crate::DEFAULT_BUF_SIZE;
+
+/// Provides a single [`Reader`](Read) that reads from multiple readers sequentially, concatenating them into one stream.
+///
+/// Each internal reader is drained until it reports end of file (returns `Ok(0)`),
+/// after which reading continues with the next.
+/// [`read`](MultiReader::read) only returns `Ok(0)` once every internal reader is exhausted.
+///
+/// A single [`read`](MultiReader::read) keeps reading until the caller's buffer is full or every internal reader is exhausted,
+/// topping it up from the following readers whenever one ends,
+/// so copying with [`io::copy`] doesn't degrade to short reads at the boundaries between readers.
+/// As a consequence, a read may block on an internal reader after having already received some bytes,
+/// for instance on a pipe or socket waiting for more data, before returning them.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+/// use streams::MultiReader;
+///
+/// let mut reader1 = &b"Hello, "[..];
+/// let mut reader2 = &b"world!"[..];
+/// let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2]);
+///
+/// let mut output = Vec::new();
+/// io::copy(&mut multi_reader, &mut output)?;
+/// assert_eq!(output, b"Hello, world!");
+/// # Ok::<(), io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Errors from the current internal reader are returned as-is, and the same reader is tried again on the next call.
+/// If some bytes were already read into the caller's buffer, they are returned first, and the error is returned by the next call.
+pub struct MultiReader<'a> {
+    readers: Vec<&'a mut dyn Read>,
+    current: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a> MultiReader<'a> {
+    pub fn new(readers: Vec<&'a mut dyn Read>) -> Self {
+        let current = 0;
+        let error = None;
+
+        Self {
+            readers,
+            current,
+            error,
+        }
+    }
+}
+
+impl<'a> Read for MultiReader<'a> {
+    /// Fill `buf` from the current internal reader, advancing to the next whenever one is exhausted.
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+
+        let mut filled = 0;
+
+        while filled < buf.len() {
+            let Some(reader) = self.readers.get_mut(self.current) else {
+                break;
+            };
+
+            match reader.read(&mut buf[filled..]) {
+                Ok(0) => self.current += 1,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) if filled == 0 => return Err(e),
+                Err(e) => {
+                    self.error = Some(e);
+                    break;
+                }
+            }
+        }
+
+        Ok(filled)
+    }
+
+    /// Fill `bufs` in order, like [`read`](MultiReader::read) does for a single buffer.
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        let mut filled = 0;
+
+        for buf in bufs {
+            let n = match self.read(buf) {
+                Ok(n) => n,
+                Err(e) if filled == 0 => return Err(e),
+                Err(e) => {
+                    self.error = Some(e);
+                    break;
+                }
+            };
+            filled += n;
+
+            if n < buf.len() {
+                break;
+            }
+        }
+
+        Ok(filled)
+    }
+}
+
+/// Copy the entire contents of multiple readers into a single writer, returning the total number of bytes copied.
+///
+/// The readers are drained in order: reader 0 is copied entirely before reader 1 is read, and so on.
+/// Uses a [`MultiReader`] and [`std::io::copy`].
+pub fn copy_many_into(readers: Vec<&mut dyn Read>, writer: &mut dyn Write) -> io::Result<u64> {
+    let mut multi_reader = MultiReader::new(readers);
+    io::copy(&mut multi_reader, writer)
+}
+
+/// Copy the entire contents of multiple readers into a single writer concurrently, returning the total number of bytes copied.
+///
+/// Each reader is read on its own scoped thread in chunks of up to [`DEFAULT_BUF_SIZE`] bytes,
+/// and the chunks are written to `writer` on the calling thread as they arrive.
+/// Chunks from the same reader keep their relative order, but chunks from different readers are interleaved nondeterministically;
+/// use [`copy_many_into`] if the readers must be concatenated.
+///
+/// # Errors
+///
+/// The first error is returned, whether it comes from a reader or the writer.
+/// The copy stops at that point, but readers that are blocked in a read call are waited for before the function returns.
+pub fn copy_many_into_parallel<R: Read + Send, W: Write + ?Sized>(
+    readers: Vec<R>,
+    writer: &mut W,
+) -> io::Result<u64> {
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::sync_channel::<io::Result<Vec<u8>>>(readers.len());
+
+        for mut reader in readers {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                let mut buf = vec![0; DEFAULT_BUF_SIZE];
+                loop {
+                    let chunk = match reader.read(&mut buf) {
+                        Ok(0) => return,
+                        Ok(n) => Ok(buf[..n].to_vec()),
+                        Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                        Err(e) => Err(e),
+                    };
+
+                    let failed = chunk.is_err();
+                    // The receiver is only dropped once copying has been aborted.
+                    if sender.send(chunk).is_err() || failed {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let mut copied = 0;
+        for chunk in receiver {
+            let chunk = chunk?;
+            writer.write_all(&chunk)?;
+            copied += chunk.len() as u64;
+        }
+
+        Ok(copied)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor, IoSliceMut, Read};
+
+    use crate::{copy_many_into, copy_many_into_parallel, mocks::FailingWriter, MultiReader};
+
+    #[test]
+    fn multi_reader() {
+        let mut reader1 = Cursor::new(b"Hello".to_vec());
+        let mut reader2 = Cursor::new(b", ".to_vec());
+        let mut reader3 = Cursor::new(b"world!".to_vec());
+        let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2, &mut reader3]);
+
+        let mut output = Vec::new();
+        multi_reader.read_to_end(&mut output).unwrap();
+
+        assert_eq!(output, b"Hello, world!");
+        assert_eq!(multi_reader.read(&mut [0; 8]).unwrap(), 0);
+    }
+
+    #[test]
+    fn multi_reader_skips_empty_reader() {
+        let mut reader1 = Cursor::new(b"Hello, ".to_vec());
+        let mut reader2 = Cursor::new(Vec::new());
+        let mut reader3 = Cursor::new(b"world!".to_vec());
+        let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2, &mut reader3]);
+
+        let mut buf = [0; 64];
+        assert_eq!(multi_reader.read(&mut buf).unwrap(), 13);
+        assert_eq!(buf[..13], *b"Hello, world!");
+        assert_eq!(multi_reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn multi_reader_read_spans_readers() {
+        let mut reader1 = &b"Hello, "[..];
+        let mut reader2 = &b"world!"[..];
+        let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2]);
+
+        let mut buf = [0; 10];
+        assert_eq!(multi_reader.read(&mut buf).unwrap(), 10);
+        assert_eq!(buf, *b"Hello, wor");
+        assert_eq!(multi_reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(buf[..3], *b"ld!");
+    }
+
+    #[test]
+    fn multi_reader_read_vectored() {
+        let mut reader1 = &b"Hello, "[..];
+        let mut reader2 = &b"world!"[..];
+        let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2]);
+
+        let (mut first, mut second) = ([0; 4], [0; 16]);
+        let mut bufs = [IoSliceMut::new(&mut first), IoSliceMut::new(&mut second)];
+        assert_eq!(multi_reader.read_vectored(&mut bufs).unwrap(), 13);
+        assert_eq!(first, *b"Hell");
+        assert_eq!(second[..9], *b"o, world!");
+    }
+
+    #[test]
+    fn multi_reader_defers_error() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::ConnectionReset))
+            }
+        }
+
+        let mut reader1 = &b"Hello"[..];
+        let mut reader2 = FailingReader;
+        let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2]);
+
+        let mut buf = [0; 16];
+        assert_eq!(multi_reader.read(&mut buf).unwrap(), 5);
+        let err = multi_reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+        let err = multi_reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+    }
+
+    #[test]
+    fn copy_many_into_concatenates() {
+        let mut reader1 = Cursor::new(b"Hello".to_vec());
+        let mut reader2 = Cursor::new(b", ".to_vec());
+        let mut reader3 = Cursor::new(b"world!".to_vec());
+
+        let mut output = Vec::new();
+        let copied =
+            copy_many_into(vec![&mut reader1, &mut reader2, &mut reader3], &mut output).unwrap();
+
+        assert_eq!(copied, 13);
+        assert_eq!(output, b"Hello, world!");
+    }
+
+    #[test]
+    fn copy_many_into_parallel_copies_everything() {
+        let readers: Vec<_> = (0..4u8)
+            .map(|i| Cursor::new(vec![i; 3 * crate::DEFAULT_BUF_SIZE + 100]))
+            .collect();
+
+        let mut output = Vec::new();
+        let copied = copy_many_into_parallel(readers, &mut output).unwrap();
+
+        assert_eq!(copied, output.len() as u64);
+        for i in 0..4u8 {
+            let count = output.iter().filter(|&&b| b == i).count();
+            assert_eq!(count, 3 * crate::DEFAULT_BUF_SIZE + 100);
+        }
+    }
+
+    #[test]
+    fn copy_many_into_parallel_writer_error() {
+        let readers = vec![&b"Hello"[..], &b"world"[..]];
+        let mut writer = FailingWriter::new(io::ErrorKind::BrokenPipe);
+
+        let result = copy_many_into_parallel(readers, &mut writer);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    io::{self, IoSlice, Write},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use crate::{ErrorPolicy, LimitWriter, MultiWriter};
+
+/// Assembles a [`MultiWriter`] of boxed writers, wrapping each in the adapters of this crate as it is added.
+///
+/// The built [`BuiltMultiWriter`] wraps the `MultiWriter`, keeping track of the bytes counted for the writers added with
+/// [`add_counted`](MultiWriterBuilder::add_counted).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{ErrorPolicy, MultiWriterBuilder};
+///
+/// let mut full = Vec::new();
+/// let mut preview = Vec::new();
+///
+/// let mut multi_writer = MultiWriterBuilder::new()
+///     .add_counted(&mut full)
+///     .add_limited(&mut preview, 5)
+///     .with_error_policy(ErrorPolicy::ContinueAndCollect)
+///     .build()?;
+///
+/// multi_writer.write_all(b"Hello, world!")?;
+/// assert_eq!(multi_writer.counted_bytes(0), Some(13));
+/// drop(multi_writer);
+///
+/// assert_eq!(full, b"Hello, world!");
+/// assert_eq!(preview, b"Hello");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct MultiWriterBuilder<'a> {
+    writers: Vec<BuilderEntry<'a>>,
+    policy: ErrorPolicy,
+    require_writers: bool,
+}
+
+impl<'a> Default for MultiWriterBuilder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> MultiWriterBuilder<'a> {
+    pub fn new() -> Self {
+        let writers = Vec::new();
+        let policy = ErrorPolicy::default();
+
+        Self {
+            writers,
+            policy,
+            require_writers: false,
+        }
+    }
+
+    /// Add `writer` as-is.
+    #[expect(
+        clippy::should_implement_trait,
+        reason = "a builder adding writers, not an arithmetic type"
+    )]
+    pub fn add(mut self, writer: impl Write + 'a) -> Self {
+        self.writers.push((None, Box::new(writer), None));
+        self
+    }
+
+    /// Add `writer` with a human-readable label. See [`MultiWriter::new_named`].
+    pub fn add_named(mut self, label: impl Into<String>, writer: impl Write + 'a) -> Self {
+        self.writers
+            .push((Some(label.into()), Box::new(writer), None));
+        self
+    }
+
+    /// Add `writer`, counting the bytes it accepts.
+    ///
+    /// Like for a [`CountingWriter`](crate::CountingWriter), only bytes the writer actually accepted are counted.
+    /// The count can be read from the built writer with [`BuiltMultiWriter::counted_bytes`].
+    pub fn add_counted(mut self, writer: impl Write + 'a) -> Self {
+        let count = Arc::new(AtomicU64::new(0));
+        let writer = SharedCountWriter {
+            writer,
+            count: count.clone(),
+        };
+        self.writers.push((None, Box::new(writer), Some(count)));
+        self
+    }
+
+    /// Add `writer` wrapped in a [`LimitWriter`], capping the number of bytes written to it at `limit`.
+    pub fn add_limited(self, writer: impl Write + 'a, limit: u64) -> Self {
+        self.add(LimitWriter::new(writer, limit))
+    }
+
+    /// Handle failing writers according to `policy`. Defaults to [`ErrorPolicy::FailFast`].
+    pub fn with_error_policy(mut self, policy: ErrorPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Make [`build`](MultiWriterBuilder::build) fail if no writers were added.
+    pub fn require_writers(mut self, required: bool) -> Self {
+        self.require_writers = required;
+        self
+    }
+
+    /// Build the `MultiWriter`, with the writers in the order they were added.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if no writers were added and [`require_writers`](MultiWriterBuilder::require_writers) is enabled.
+    pub fn build(self) -> io::Result<BuiltMultiWriter<'a>> {
+        if self.require_writers && self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "multi-writer builder has no writers",
+            ));
+        }
+
+        let mut multi_writer = MultiWriter::from_writers(Vec::with_capacity(self.writers.len()));
+        multi_writer.set_error_policy(self.policy);
+        let mut counts = Vec::with_capacity(self.writers.len());
+
+        for (label, writer, count) in self.writers {
+            match label {
+                Some(label) => multi_writer.push_named(label, writer),
+                None => multi_writer.push(writer),
+            }
+            counts.push(count);
+        }
+
+        Ok(BuiltMultiWriter {
+            multi_writer,
+            counts,
+        })
+    }
+}
+
+/// The [`MultiWriter`] built by a [`MultiWriterBuilder`], along with the counts of the writers added with
+/// [`add_counted`](MultiWriterBuilder::add_counted).
+///
+/// Writes behave exactly like those of the wrapped `MultiWriter`,
+/// which can be accessed with [`multi_writer`](BuiltMultiWriter::multi_writer) and [`multi_writer_mut`](BuiltMultiWriter::multi_writer_mut),
+/// e.g. to inspect the collected errors.
+pub struct BuiltMultiWriter<'a> {
+    multi_writer: MultiWriter<Box<dyn Write + 'a>>,
+    counts: Vec<Option<Arc<AtomicU64>>>,
+}
+
+impl<'a> BuiltMultiWriter<'a> {
+    /// The number of bytes the writer added at `index` has accepted so far,
+    /// or `None` if it was not added with [`add_counted`](MultiWriterBuilder::add_counted).
+    ///
+    /// Writers are indexed in the order they were added to the builder,
+    /// even if writers are pushed to or removed from the wrapped [`MultiWriter`] later on.
+    /// Unlike [`MultiWriter::bytes_written`], this also counts the bytes a writer accepted before failing.
+    pub fn counted_bytes(&self, index: usize) -> Option<u64> {
+        let count = self.counts.get(index)?.as_ref()?;
+
+        Some(count.load(Ordering::Relaxed))
+    }
+
+    /// Get a reference to the wrapped [`MultiWriter`].
+    pub fn multi_writer(&self) -> &MultiWriter<Box<dyn Write + 'a>> {
+        &self.multi_writer
+    }
+
+    /// Get a mutable reference to the wrapped [`MultiWriter`].
+    pub fn multi_writer_mut(&mut self) -> &mut MultiWriter<Box<dyn Write + 'a>> {
+        &mut self.multi_writer
+    }
+
+    /// Consumes the `BuiltMultiWriter`, returning the wrapped [`MultiWriter`] and dropping the counts.
+    pub fn into_multi_writer(self) -> MultiWriter<Box<dyn Write + 'a>> {
+        self.multi_writer
+    }
+}
+
+impl<'a> Write for BuiltMultiWriter<'a> {
+    /// Write a buffer into each internal writer sequentially. See [`MultiWriter::write`].
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.multi_writer.write(buf)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.multi_writer.write_vectored(bufs)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.multi_writer.flush()
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.multi_writer.write_all(buf)
+    }
+}
+
+/// A writer added to a [`MultiWriterBuilder`], with its label and the count of a counted writer.
+type BuilderEntry<'a> = (Option<String>, Box<dyn Write + 'a>, Option<Arc<AtomicU64>>);
+
+/// Counts the bytes accepted by `writer` into a counter shared with the [`BuiltMultiWriter`]. See [`MultiWriterBuilder::add_counted`].
+struct SharedCountWriter<W: Write> {
+    writer: W,
+    count: Arc<AtomicU64>,
+}
+
+impl<W: Write> Write for SharedCountWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.writer.write(buf)?;
+        self.count.fetch_add(n as u64, Ordering::Relaxed);
+
+        Ok(n)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let n = self.writer.write_vectored(bufs)?;
+        self.count.fetch_add(n as u64, Ordering::Relaxed);
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{mocks::SharedWriter, ErrorPolicy, MultiWriteError, MultiWriterBuilder};
+
+    #[test]
+    fn multi_writer_builder() {
+        let plain = SharedWriter::new();
+        let counted = SharedWriter::new();
+        let limited = SharedWriter::new();
+
+        let mut multi_writer = MultiWriterBuilder::new()
+            .add(plain.clone())
+            .add_counted(counted.clone())
+            .add_named("limited", crate::LimitWriter::new(limited.clone(), 100))
+            .add_limited(Vec::new(), 7)
+            .with_error_policy(ErrorPolicy::ContinueAndCollect)
+            .build()
+            .unwrap();
+        assert_eq!(multi_writer.multi_writer().len(), 4);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        assert_eq!(multi_writer.counted_bytes(1), Some(7));
+        multi_writer.write_all(b"world!").unwrap();
+        assert_eq!(multi_writer.counted_bytes(1), Some(13));
+        assert_eq!(multi_writer.counted_bytes(0), None);
+        assert_eq!(multi_writer.counted_bytes(4), None);
+
+        let errors = multi_writer.multi_writer_mut().take_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 3);
+        assert_eq!(multi_writer.multi_writer().bytes_written(), [13, 13, 13, 7]);
+        assert_eq!(multi_writer.multi_writer().label(2), Some("limited"));
+
+        assert_eq!(plain.contents(), b"Hello, world!");
+        assert_eq!(counted.contents(), b"Hello, world!");
+        assert_eq!(limited.contents(), b"Hello, world!");
+    }
+
+    #[test]
+    fn multi_writer_builder_counted_partial_write() {
+        let mut multi_writer = MultiWriterBuilder::new()
+            .add(Vec::new())
+            .add_counted(crate::LimitWriter::new(Vec::new(), 5))
+            .with_error_policy(ErrorPolicy::ContinueAndCollect)
+            .build()
+            .unwrap();
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(multi_writer.multi_writer().errors().len(), 1);
+        assert_eq!(multi_writer.multi_writer().bytes_written(), [13, 0]);
+        assert_eq!(multi_writer.counted_bytes(1), Some(5));
+
+        multi_writer.multi_writer_mut().remove(0);
+        assert_eq!(multi_writer.counted_bytes(1), Some(5));
+    }
+
+    #[test]
+    fn multi_writer_builder_fail_fast() {
+        let mut multi_writer = MultiWriterBuilder::new()
+            .add(Vec::new())
+            .add_limited(Vec::new(), 5)
+            .build()
+            .unwrap();
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+    }
+
+    #[test]
+    fn multi_writer_builder_require_writers() {
+        assert!(MultiWriterBuilder::new()
+            .build()
+            .unwrap()
+            .multi_writer()
+            .is_empty());
+
+        let err = MultiWriterBuilder::new()
+            .require_writers(true)
+            .build()
+            .map(|_| ())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -1,4 +1,16 @@
-use std::io::{self, Read, Write};
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::{
+    io::{self, BufRead, IoSlice, Read, Write},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+#[cfg(feature = "std")]
+use crate::{CopyCancelled, MultiError, MultiWriteError};
+
+/// The default size of the buffers used to copy and buffer data, matching the capacity of a [`std::io::BufWriter`].
+#[cfg(feature = "std")]
+pub const DEFAULT_BUF_SIZE: usize = 8 * 1024;
 
 /// Provides a single [`Writer`](Write) that writes to multiple writers sequentially.
 ///
@@ -11,10 +23,14 @@ use std::io::{self, Read, Write};
 ///
 /// If any of the internal writers fail during the iteration,
 /// execution will immediately halt and the error will be returned.
-/// Currently, this implementation provides no method of determining which writer failed.
+/// The returned [`io::Error`] has the same [`ErrorKind`](io::ErrorKind) as the underlying error
+/// and wraps a [`MultiWriteError`] reporting the index of the writer that failed.
 ///
 /// Keep in mind that some of the internal writes may have been succesfully executed even if a following write fails.
 ///
+/// This default behavior corresponds to [`ErrorPolicy::FailFast`].
+/// See [`ErrorPolicy::ContinueAndCollect`] for an alternative that attempts every writer before reporting failures.
+///
 /// # Blocking
 ///
 /// `MultiWriter` is blocking to the extent that its internal writers are blocking.
@@ -30,141 +46,2729 @@ use std::io::{self, Read, Write};
 /// writing only the same number of bytes as the first.
 /// To avoid assigning arbitary and non-obvious meaning to the order of the internal writers [`Vec`],
 /// such an implementation should consider adopting a master-slaves pattern and make it obvious that the first writer's result will impact the others.
-pub struct MultiWriter<'a> {
-    writers: Vec<&'a mut dyn Write>,
+/// This pattern is available through [`MultiWriter::new_master_slaves`].
+///
+/// # Writer Types
+///
+/// `MultiWriter` is generic over the type `W` of its internal writers.
+/// The constructors [`new`](MultiWriter::new), [`with_error_policy`](MultiWriter::with_error_policy) and [`new_named`](MultiWriter::new_named)
+/// borrow writers of differing types as trait objects, creating a `MultiWriter<&mut dyn Write>`.
+/// [`from_writers`](MultiWriter::from_writers) accepts any `Vec<W>`, such as owned writers or boxed trait objects,
+/// which can be moved around, returned from functions and stored in structs freely, and reclaimed with [`into_inner`](MultiWriter::into_inner).
+///
+/// # I/O Traits
+///
+/// The [`std::io::Write`] implementation and everything built on it, like error policies and labels in errors, require the default `std` feature.
+/// With the `embedded-io` feature, a `MultiWriter` of `embedded_io::Write` writers implements `embedded_io::Write` as well,
+/// which only requires `alloc`, so it can be used with the `std` feature disabled.
+/// It always fails fast, returning an `EmbeddedMultiWriteError` that reports the index of the failing writer.
+pub struct MultiWriter<W> {
+    writers: Vec<W>,
+    labels: Vec<Option<String>>,
+    bytes_written: Vec<u64>,
+    write_calls: Vec<u64>,
+    writes: u64,
+    total_bytes: u64,
+    #[cfg(feature = "std")]
+    policy: ErrorPolicy,
+    #[cfg(feature = "std")]
+    errors: Vec<(usize, io::Error)>,
+    #[cfg(feature = "std")]
+    enabled: Vec<bool>,
+    #[cfg(feature = "std")]
+    disabled: Vec<(usize, io::Error)>,
+    #[cfg(feature = "std")]
+    master_slaves: bool,
+    #[cfg(feature = "std")]
+    deferred: Option<io::Error>,
 }
 
-impl<'a> MultiWriter<'a> {
+#[cfg(feature = "std")]
+impl<'a> MultiWriter<&'a mut dyn Write> {
+    /// Create a `MultiWriter` using the default [`ErrorPolicy::FailFast`].
+    ///
+    /// # Examples
+    ///
+    /// Copy a reader into a file and standard output, like `tee`:
+    ///
+    /// ```no_run
+    /// use std::{fs::File, io::{self, Write}};
+    /// use streams::MultiWriter;
+    ///
+    /// let mut file = File::create("output.txt")?;
+    /// let mut stdout = io::stdout().lock();
+    ///
+    /// let mut multi_writer = MultiWriter::new(vec![&mut file, &mut stdout]);
+    /// io::copy(&mut io::stdin().lock(), &mut multi_writer)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
     pub fn new(writers: Vec<&'a mut dyn Write>) -> Self {
-        Self { writers }
+        Self::with_error_policy(writers, ErrorPolicy::default())
+    }
+
+    /// Create a `MultiWriter` that handles failing internal writers according to `policy`.
+    pub fn with_error_policy(writers: Vec<&'a mut dyn Write>, policy: ErrorPolicy) -> Self {
+        let mut multi_writer = Self::from_writers(writers);
+        multi_writer.set_error_policy(policy);
+
+        multi_writer
+    }
+
+    /// Create a `MultiWriter` where each writer is given a human-readable label.
+    ///
+    /// The label of a failing writer is reported by [`MultiWriteError::label`] and used in its [`Display`](std::fmt::Display) output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use streams::MultiWriter;
+    ///
+    /// let mut local = Vec::new();
+    /// let mut backup: &mut [u8] = &mut [];
+    /// let mut multi_writer = MultiWriter::new_named(vec![
+    ///     ("local".to_string(), &mut local),
+    ///     ("backup".to_string(), &mut backup),
+    /// ]);
+    ///
+    /// let err = multi_writer.write(b"Hello, world!").unwrap_err();
+    /// assert_eq!(err.to_string(), "writer 'backup' failed: failed to write whole buffer");
+    /// ```
+    pub fn new_named(writers: Vec<(String, &'a mut dyn Write)>) -> Self {
+        let (labels, writers): (Vec<_>, _) = writers.into_iter().unzip();
+        let mut multi_writer = Self::new(writers);
+        multi_writer.labels = labels.into_iter().map(Some).collect();
+
+        multi_writer
+    }
+
+    /// Create a `MultiWriter` using the master-slaves pattern described in the [`MultiWriter`] documentation.
+    ///
+    /// [`write`](MultiWriter::write) calls [`write`](Write::write) once on the first writer, the master,
+    /// then calls [`write_all`](Write::write_all) on each of the remaining writers, the slaves, in order,
+    /// with only the bytes the master accepted, and returns the master's `usize`.
+    /// Short writes are thus propagated to the caller, and every writer always receives the same bytes.
+    ///
+    /// If the master fails or accepts no bytes, the slaves are not written to.
+    /// Once the master has accepted some bytes, the write succeeds, returning their number,
+    /// so a caller retrying a failed write never hands the master the same bytes twice.
+    /// Failing slaves are handled according to the [`ErrorPolicy`], which only applies to the slaves:
+    ///
+    /// - Under [`ErrorPolicy::FailFast`], the following slaves are not written to,
+    ///   and the error of the failing slave is returned by the next call to [`write`](MultiWriter::write) or [`flush`](MultiWriter::flush)
+    ///   before anything is written or flushed.
+    /// - Under [`ErrorPolicy::ContinueAndCollect`], the errors are kept for inspection with [`MultiWriter::errors`].
+    /// - Under [`ErrorPolicy::DisableOnError`], failing slaves are disabled, even if that leaves only the master.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use streams::MultiWriter;
+    ///
+    /// let mut master = [0; 5];
+    /// let mut master_slice = &mut master[..];
+    /// let mut slave = Vec::new();
+    /// let mut multi_writer = MultiWriter::new_master_slaves(vec![&mut master_slice, &mut slave]);
+    ///
+    /// assert_eq!(multi_writer.write(b"Hello, world!")?, 5);
+    /// assert_eq!(slave, b"Hello");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn new_master_slaves(writers: Vec<&'a mut dyn Write>) -> Self {
+        let mut multi_writer = Self::new(writers);
+        multi_writer.master_slaves = true;
+
+        multi_writer
     }
 }
 
-impl<'a> Write for MultiWriter<'a> {
-    /// Write a buffer into each internal writer sequentially.
+impl<W> MultiWriter<W> {
+    /// Create a `MultiWriter` from writers of any single type `W`, using the default [`ErrorPolicy::FailFast`].
     ///
-    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`). See [`MultiWriter`] for more information.
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        for writer in &mut self.writers {
-            writer.write_all(buf)?;
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use std::io::Write;
+    /// use streams::MultiWriter;
+    ///
+    /// let mut multi_writer = MultiWriter::from_writers(vec![Vec::new(), Vec::new()]);
+    /// multi_writer.write_all(b"Hello, world!")?;
+    ///
+    /// for writer in multi_writer.into_inner() {
+    ///     assert_eq!(writer, b"Hello, world!");
+    /// }
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn from_writers(writers: Vec<W>) -> Self {
+        let labels = vec![None; writers.len()];
+        let bytes_written = vec![0; writers.len()];
+        let write_calls = vec![0; writers.len()];
+        #[cfg(feature = "std")]
+        let enabled = vec![true; writers.len()];
+
+        Self {
+            writers,
+            labels,
+            bytes_written,
+            write_calls,
+            writes: 0,
+            total_bytes: 0,
+            #[cfg(feature = "std")]
+            policy: ErrorPolicy::default(),
+            #[cfg(feature = "std")]
+            errors: Vec::new(),
+            #[cfg(feature = "std")]
+            enabled,
+            #[cfg(feature = "std")]
+            disabled: Vec::new(),
+            #[cfg(feature = "std")]
+            master_slaves: false,
+            #[cfg(feature = "std")]
+            deferred: None,
         }
+    }
 
-        Ok(buf.len())
+    /// Consumes the `MultiWriter`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
     }
 
-    /// Flush each internal output stream sequentially, ensuring that all intermediately buffered contents reach their destinations.
-    fn flush(&mut self) -> io::Result<()> {
-        for writer in &mut self.writers {
-            writer.flush()?;
+    /// Get a reference to the internal writers, indexed like in errors.
+    pub fn get_ref(&self) -> &[W] {
+        &self.writers
+    }
+
+    /// Get a mutable reference to the internal writers, indexed like in errors.
+    ///
+    /// Bytes written directly to the internal writers are not counted by [`bytes_written`](MultiWriter::bytes_written).
+    pub fn get_mut(&mut self) -> &mut [W] {
+        &mut self.writers
+    }
+
+    /// The label of the writer at `index`, if it was given one.
+    pub fn label(&self, index: usize) -> Option<&str> {
+        self.labels.get(index)?.as_deref()
+    }
+
+    /// The number of bytes each internal writer has accepted so far, indexed like the writers.
+    ///
+    /// Only writes that an internal writer accepted completely are counted,
+    /// so under [`ErrorPolicy::ContinueAndCollect`] the counts of failing writers fall behind the others.
+    pub fn bytes_written(&self) -> &[u64] {
+        &self.bytes_written
+    }
+
+    /// A snapshot of the statistics of this `MultiWriter` and its internal writers. See [`MultiWriterStats`].
+    pub fn stats(&self) -> MultiWriterStats {
+        MultiWriterStats {
+            writes: self.writes,
+            total_bytes: self.total_bytes,
+            writer_bytes: self.bytes_written.clone(),
+            writer_writes: self.write_calls.clone(),
         }
+    }
 
-        Ok(())
+    /// Count a write of `len` bytes accepted completely by the writer at `index`.
+    pub(crate) fn record(&mut self, index: usize, len: usize) {
+        self.bytes_written[index] += len as u64;
+        self.write_calls[index] += 1;
     }
 
-    /// Calls [`write`](MultiWriter::write) and discards the returned `usize`.
-    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        // Explicitely drop the returned `usize` to make `cargo clippy` happy.
-        let _ = self.write(buf)?;
+    /// Count a successful write of `len` bytes into the `MultiWriter` itself.
+    pub(crate) fn record_fan_out(&mut self, len: usize) {
+        self.writes += 1;
+        self.total_bytes += len as u64;
+    }
 
-        Ok(())
+    /// The number of internal writers.
+    pub fn len(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns `true` if there are no internal writers.
+    pub fn is_empty(&self) -> bool {
+        self.writers.is_empty()
     }
 }
 
-/// Copy the entire contents of a reader into multiple writers.
+#[cfg(feature = "std")]
+impl<W: Write> MultiWriter<W> {
+    /// Call `f` with the index and a mutable reference of each internal writer in order, including disabled ones,
+    /// for instance to inspect or flush some of them without taking the `MultiWriter` apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{BufWriter, Write};
+    /// use streams::MultiWriter;
+    ///
+    /// let mut multi_writer = MultiWriter::from_writers(vec![BufWriter::new(Vec::new()), BufWriter::new(Vec::new())]);
+    /// multi_writer.write_all(b"Hello, world!")?;
+    ///
+    /// // Only flush the first writer.
+    /// multi_writer.for_each_writer(|index, writer| {
+    ///     if index == 0 {
+    ///         writer.flush().unwrap();
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(multi_writer.get_ref()[0].get_ref(), b"Hello, world!");
+    /// assert!(multi_writer.get_ref()[1].get_ref().is_empty());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn for_each_writer(&mut self, mut f: impl FnMut(usize, &mut W)) {
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            f(index, writer);
+        }
+    }
+
+    /// Handle failing internal writers according to `policy` from now on.
+    pub fn set_error_policy(&mut self, policy: ErrorPolicy) {
+        self.policy = policy;
+    }
+
+    fn error(&self, index: usize, source: io::Error) -> io::Error {
+        MultiWriteError::new(index, source)
+            .with_label(self.labels[index].clone())
+            .into_io_error()
+    }
+
+    /// The [`ErrorPolicy`] used by this `MultiWriter`.
+    pub fn error_policy(&self) -> ErrorPolicy {
+        self.policy
+    }
+
+    /// Errors collected under [`ErrorPolicy::ContinueAndCollect`] from writes that succeeded on at least one internal writer.
+    ///
+    /// Each entry holds the index of a failing writer and the first error it returned, in the order the writers first failed.
+    /// Later errors of a writer that already has an entry are dropped, so there is at most one entry per writer,
+    /// no matter how many writes it has failed since the errors were last [taken](MultiWriter::take_errors).
+    pub fn errors(&self) -> &[(usize, io::Error)] {
+        &self.errors
+    }
+
+    /// Take the collected errors, leaving the `MultiWriter` with none. See [`errors`](MultiWriter::errors).
+    pub fn take_errors(&mut self) -> Vec<(usize, io::Error)> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// The writers disabled under [`ErrorPolicy::DisableOnError`], in the order they failed,
+    /// each with its index and the error that disabled it.
+    pub fn disabled_writers(&self) -> &[(usize, io::Error)] {
+        &self.disabled
+    }
+
+    /// Append a writer, which will receive all data written from now on.
+    ///
+    /// The new writer gets the index [`len`](MultiWriter::len) had before the call.
+    pub fn push(&mut self, writer: W) {
+        self.writers.push(writer);
+        self.labels.push(None);
+        self.bytes_written.push(0);
+        self.write_calls.push(0);
+        self.enabled.push(true);
+    }
+
+    /// Append a writer with a human-readable label. See [`push`](MultiWriter::push) and [`new_named`](MultiWriter::new_named).
+    pub fn push_named(&mut self, label: String, writer: W) {
+        self.writers.push(writer);
+        self.labels.push(Some(label));
+        self.bytes_written.push(0);
+        self.write_calls.push(0);
+        self.enabled.push(true);
+    }
+
+    /// Remove and return the writer at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// Removing a writer shifts all writers after it one index to the left,
+    /// so errors reported by later writes use the new indices.
+    /// The errors collected under [`ErrorPolicy::ContinueAndCollect`] and the [`disabled_writers`](MultiWriter::disabled_writers)
+    /// are updated to the new indices as well, dropping the entries of the removed writer.
+    pub fn remove(&mut self, index: usize) -> Option<W> {
+        if index < self.writers.len() {
+            self.labels.remove(index);
+            self.bytes_written.remove(index);
+            self.write_calls.remove(index);
+            self.enabled.remove(index);
+            for entries in [&mut self.errors, &mut self.disabled] {
+                entries.retain(|(i, _)| *i != index);
+                for (i, _) in entries {
+                    if *i > index {
+                        *i -= 1;
+                    }
+                }
+            }
+            Some(self.writers.remove(index))
+        } else {
+            None
+        }
+    }
+}
+
+/// A snapshot of the statistics of a [`MultiWriter`], taken by [`MultiWriter::stats`].
 ///
-/// Uses a [`MultiWriter`] and [`std::io::copy`].
-pub fn copy_into_many<R: Read + ?Sized>(
-    reader: &mut R,
-    writers: Vec<&mut dyn Write>,
-) -> io::Result<u64> {
-    let mut multi_writer = MultiWriter { writers };
-    io::copy(reader, &mut multi_writer)
+/// Unlike wrapping each internal writer in a [`CountingWriter`](crate::CountingWriter),
+/// this also counts the writes into the `MultiWriter` itself, which are fanned out to the internal writers.
+///
+/// With the `serde` feature enabled, it can be serialized, with a field for each of its accessors.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiWriterStats {
+    writes: u64,
+    total_bytes: u64,
+    writer_bytes: Vec<u64>,
+    writer_writes: Vec<u64>,
 }
 
-/// Utility macro to avoid manually casting writers to `&mut dyn std::io::Write`.
-#[macro_export]
-macro_rules! copy_into_many {
-    ($reader:expr,$writers:expr) => {{
-        $crate::copy_into_many(
-            $reader,
-            $writers
-                .iter_mut()
-                .map(|w| w as &mut dyn std::io::Write)
-                .collect(),
-        )
-    }};
+impl MultiWriterStats {
+    /// The number of successful, non-empty writes into the `MultiWriter`.
+    pub fn writes(&self) -> u64 {
+        self.writes
+    }
+
+    /// The total number of bytes the `MultiWriter` reported as written, summed over its successful writes.
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// The number of bytes each internal writer has accepted, like [`MultiWriter::bytes_written`].
+    pub fn writer_bytes(&self) -> &[u64] {
+        &self.writer_bytes
+    }
+
+    /// The number of writes each internal writer has accepted completely, indexed like the writers.
+    ///
+    /// A single write into the `MultiWriter` counts once per internal writer,
+    /// even if that writer needed several calls to [`write`](Write::write) to accept the data.
+    pub fn writer_writes(&self) -> &[u64] {
+        &self.writer_writes
+    }
 }
 
-/// Utility macro to avoid collecting writers into a `Vec<&mut dyn std::io::Write>`.
-#[macro_export]
-macro_rules! copy_into_all {
-    ($reader:expr,$($writer:expr),*) => {{
-        let writers = vec![$(&mut $writer as &mut dyn std::io::Write,)*];
-        $crate::copy_into_many(
-            $reader,
-            writers,
-        )
-    }};
+/// Collect writers into a `MultiWriter` using the default [`ErrorPolicy::FailFast`].
+///
+/// Borrowed writers must all be borrowed for the same lifetime, which the resulting `MultiWriter` cannot outlive.
+/// To collect writers of differing types, cast them to `&mut dyn Write` first.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::MultiWriter;
+///
+/// let mut outputs = [Vec::new(), Vec::new()];
+/// let mut multi_writer: MultiWriter<_> = outputs.iter_mut().collect();
+/// multi_writer.write_all(b"Hello, world!")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+impl<W: Write> FromIterator<W> for MultiWriter<W> {
+    fn from_iter<I: IntoIterator<Item = W>>(iter: I) -> Self {
+        Self::from_writers(iter.into_iter().collect())
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::{
-        collections::VecDeque,
-        io::{Cursor, Write},
-    };
+/// Create a `MultiWriter` from a vector of writers, like [`MultiWriter::from_writers`].
+#[cfg(feature = "std")]
+impl<W: Write> From<Vec<W>> for MultiWriter<W> {
+    fn from(writers: Vec<W>) -> Self {
+        Self::from_writers(writers)
+    }
+}
 
-    #[test]
-    fn multi_writer() {
-        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
-        let mut multi_writer =
-            crate::MultiWriter::new(writers.iter_mut().map(|w| w as &mut dyn Write).collect());
+/// Consume the `MultiWriter`, iterating over its internal writers, like [`MultiWriter::into_inner`].
+#[cfg(feature = "std")]
+impl<W: Write> IntoIterator for MultiWriter<W> {
+    type Item = W;
+    type IntoIter = std::vec::IntoIter<W>;
 
-        let input = b"Hello, world!";
-        multi_writer.write_all(input).unwrap();
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_inner().into_iter()
+    }
+}
 
-        for writer in writers {
-            assert_eq!(writer[..], *b"Hello, world!");
+/// Append writers as if by calling [`push`](MultiWriter::push) on each of them.
+#[cfg(feature = "std")]
+impl<W: Write> Extend<W> for MultiWriter<W> {
+    fn extend<I: IntoIterator<Item = W>>(&mut self, iter: I) {
+        for writer in iter {
+            self.push(writer);
         }
     }
+}
 
-    #[test]
-    fn copy_into_many_vec() {
-        let input = b"Hello, world!";
-        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+/// Determines how a [`MultiWriter`] reacts when one of its internal writers fails.
+///
+/// A writer accepting zero bytes of a non-empty buffer, by returning `Ok(0)` from [`write`](Write::write), has failed as well:
+/// its [`write_all`](Write::write_all) returns an error of kind [`WriteZero`](io::ErrorKind::WriteZero),
+/// which is handled like any other error of that writer under every policy, reporting its index.
+/// The exception is the master of a [`new_master_slaves`](MultiWriter::new_master_slaves) `MultiWriter`, whose `Ok(0)` is returned from `write` as-is.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorPolicy {
+    /// Stop at the first failing writer and return its error, leaving the following writers untouched.
+    #[default]
+    FailFast,
+    /// Attempt every writer, collecting the errors of those that fail.
+    ///
+    /// If at least one writer succeeds, [`write`](MultiWriter::write) still returns `buf.len()`
+    /// and the errors are kept for inspection with [`MultiWriter::errors`].
+    /// As the `Ok` result does not tell a partial failure apart from a complete success,
+    /// check [`MultiWriter::errors`], or [`MultiWriter::take_errors`] after each write, to learn which writers fell behind.
+    /// If every writer fails, an error wrapping a [`MultiError`] with all of the collected errors is returned instead.
+    ContinueAndCollect,
+    /// Attempt every writer, disabling those that fail.
+    ///
+    /// A disabled writer is skipped by every following write and flush, while the others keep receiving data,
+    /// so unlike under [`ErrorPolicy::ContinueAndCollect`], a failing writer is not retried with each chunk.
+    /// The errors that disabled them are kept for inspection with [`MultiWriter::disabled_writers`].
+    ///
+    /// Writers stay disabled when switching to another policy.
+    /// If every writer is disabled, [`write`](MultiWriter::write) returns an error of kind [`BrokenPipe`](io::ErrorKind::BrokenPipe).
+    DisableOnError,
+}
 
-        crate::copy_into_many(
-            &mut &input[..],
-            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
-        )
-        .unwrap();
+#[cfg(feature = "std")]
+impl<W: Write> MultiWriter<W> {
+    /// Keep the error of the writer at `index` for [`MultiWriter::errors`].
+    fn collect_error(&mut self, index: usize, e: io::Error) {
+        // Keep only the first error of each writer, so a writer failing every write doesn't grow the list without bound.
+        if !self.errors.iter().any(|(i, _)| *i == index) {
+            self.errors.push((index, e));
+        }
+    }
 
-        for writer in writers {
-            assert_eq!(writer[..], *b"Hello, world!");
+    /// Apply `op`, writing `len` bytes, to internal writers sequentially, handling failures according to the [`ErrorPolicy`].
+    /// Only the writers from index `first` on are written to.
+    fn fan_out(
+        &mut self,
+        first: usize,
+        len: usize,
+        mut op: impl FnMut(&mut W) -> io::Result<()>,
+    ) -> io::Result<()> {
+        match self.policy {
+            ErrorPolicy::FailFast => {
+                for index in first..self.writers.len() {
+                    if !self.enabled[index] {
+                        continue;
+                    }
+
+                    if let Err(e) = op(&mut self.writers[index]) {
+                        return Err(self.error(index, e));
+                    }
+
+                    self.record(index, len);
+                }
+            }
+            ErrorPolicy::ContinueAndCollect => {
+                let mut errors = Vec::new();
+                let mut attempted = 0;
+
+                for index in first..self.writers.len() {
+                    if !self.enabled[index] {
+                        continue;
+                    }
+
+                    attempted += 1;
+                    match op(&mut self.writers[index]) {
+                        Ok(()) => self.record(index, len),
+                        Err(e) => errors.push((index, e)),
+                    }
+                }
+
+                if !errors.is_empty() && errors.len() == attempted {
+                    return Err(MultiError::new(self.writers.len(), errors).into_io_error());
+                }
+
+                for (index, e) in errors {
+                    self.collect_error(index, e);
+                }
+            }
+            ErrorPolicy::DisableOnError => {
+                for index in first..self.writers.len() {
+                    if !self.enabled[index] {
+                        continue;
+                    }
+
+                    match op(&mut self.writers[index]) {
+                        Ok(()) => self.record(index, len),
+                        Err(e) => {
+                            self.enabled[index] = false;
+                            self.disabled.push((index, e));
+                        }
+                    }
+                }
+
+                if first < self.writers.len() && !self.enabled[first..].contains(&true) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::BrokenPipe,
+                        "every writer has been disabled",
+                    ));
+                }
+            }
         }
+
+        Ok(())
     }
+}
 
-    #[test]
-    fn copy_into_many_macro() {
-        let input = b"Hello, world!";
-        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+#[cfg(feature = "std")]
+impl<W: Write> Write for MultiWriter<W> {
+    /// Write a buffer into each internal writer sequentially.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`). See [`MultiWriter`] for more information.
+    ///
+    /// An empty buffer returns `Ok(0)` immediately without touching the internal writers.
+    /// For writers relying on the default [`write_all`](Write::write_all), which does nothing for an empty buffer, this makes no difference.
+    ///
+    /// A `MultiWriter` created with [`new_master_slaves`](MultiWriter::new_master_slaves) instead returns the number of bytes the master accepted.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
 
-        crate::copy_into_many!(&mut &input[..], writers).unwrap();
+        if self.master_slaves {
+            return self.write_master_slaves(buf);
+        }
 
-        for writer in writers {
-            assert_eq!(writer[..], *b"Hello, world!");
+        self.fan_out(0, buf.len(), |writer| writer.write_all(buf))?;
+        self.record_fan_out(buf.len());
+
+        Ok(buf.len())
+    }
+
+    /// Write a sequence of buffers into each internal writer sequentially.
+    ///
+    /// The buffers are forwarded to each internal writer using [`write_vectored`](Write::write_vectored)
+    /// until that writer has accepted every byte, so each writer receives the complete sequence regardless of short writes on the others.
+    ///
+    /// The returned `usize` will always be exactly the total length of the buffers,
+    /// except for a `MultiWriter` created with [`new_master_slaves`](MultiWriter::new_master_slaves),
+    /// which writes the first non-empty buffer like [`write`](MultiWriter::write).
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let len = bufs.iter().map(|b| b.len()).sum();
+
+        if len == 0 {
+            return Ok(0);
+        }
+
+        if self.master_slaves {
+            let buf = bufs.iter().find(|b| !b.is_empty()).unwrap();
+            return self.write_master_slaves(buf);
         }
+
+        self.fan_out(0, len, |writer| write_all_vectored(writer, bufs))?;
+        self.record_fan_out(len);
+
+        Ok(len)
     }
 
-    #[test]
-    fn copy_into_all_macro() {
-        let input = b"Hello, world!";
-        let mut writer1 = Vec::new();
-        let mut writer2 = Cursor::new(Vec::new());
-        let mut writer3 = VecDeque::new();
+    /// Flush each internal output stream sequentially, ensuring that all intermediately buffered contents reach their destinations.
+    ///
+    /// If an internal writer fails, the returned error wraps a [`MultiWriteError`] reporting its index.
+    ///
+    /// Under [`ErrorPolicy::DisableOnError`], a writer failing to flush is disabled like one failing to write,
+    /// the remaining writers are still flushed, and the flush only fails if every writer has been disabled.
+    ///
+    /// Under [`ErrorPolicy::ContinueAndCollect`], every writer is flushed even if some of them fail,
+    /// and the returned error instead wraps a [`MultiError`] with the errors of all writers that failed to flush.
+    /// The flush returns an error if any writer failed to flush, even though writes only fail once every writer has,
+    /// and these errors are not kept for [`MultiWriter::errors`].
+    fn flush(&mut self) -> io::Result<()> {
+        if let Some(e) = self.deferred.take() {
+            return Err(e);
+        }
 
-        crate::copy_into_all!(&mut &input[..], writer1, writer2, writer3).unwrap();
+        let mut errors = Vec::new();
+
+        for index in 0..self.writers.len() {
+            if !self.enabled[index] {
+                continue;
+            }
+
+            if let Err(e) = self.writers[index].flush() {
+                match self.policy {
+                    ErrorPolicy::FailFast => return Err(self.error(index, e)),
+                    ErrorPolicy::ContinueAndCollect => errors.push((index, e)),
+                    ErrorPolicy::DisableOnError => {
+                        self.enabled[index] = false;
+                        self.disabled.push((index, e));
+                    }
+                }
+            }
+        }
+
+        if self.policy == ErrorPolicy::DisableOnError
+            && !self.writers.is_empty()
+            && !self.enabled.contains(&true)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "every writer has been disabled",
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(MultiError::new(self.writers.len(), errors).into_io_error());
+        }
+
+        Ok(())
+    }
+
+    /// Calls [`write`](MultiWriter::write) and discards the returned `usize`.
+    ///
+    /// A `MultiWriter` created with [`new_master_slaves`](MultiWriter::new_master_slaves) instead calls
+    /// [`write`](MultiWriter::write) repeatedly until the master has accepted the entire buffer, like the default implementation.
+    fn write_all(&mut self, mut buf: &[u8]) -> io::Result<()> {
+        if !self.master_slaves {
+            // Explicitely drop the returned `usize` to make `cargo clippy` happy.
+            let _ = self.write(buf)?;
+
+            return Ok(());
+        }
+
+        while !buf.is_empty() {
+            match self.write(buf) {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => buf = &buf[n..],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Write> MultiWriter<W> {
+    /// Write all of `buf` into the writers from index `*first` on, to resume a failed write of the same buffer.
+    ///
+    /// Under [`ErrorPolicy::FailFast`], the writers before a failing one have received `buf` completely,
+    /// so `*first` is advanced to the failing writer before its error is returned.
+    /// A `MultiWriter` created with [`new_master_slaves`](MultiWriter::new_master_slaves) always writes into every writer.
+    pub(crate) fn write_all_from(&mut self, first: &mut usize, buf: &[u8]) -> io::Result<()> {
+        if self.master_slaves || buf.is_empty() {
+            return self.write_all(buf);
+        }
+
+        let result = self.fan_out(*first, buf.len(), |writer| writer.write_all(buf));
+        if let Err(e) = result {
+            if self.policy == ErrorPolicy::FailFast {
+                if let Some(error) = e
+                    .get_ref()
+                    .and_then(|e| e.downcast_ref::<MultiWriteError>())
+                {
+                    *first = error.index();
+                }
+            }
+
+            return Err(e);
+        }
+        self.record_fan_out(buf.len());
+
+        Ok(())
+    }
+
+    /// Write into the master, then forward the accepted bytes to the slaves. See [`new_master_slaves`](MultiWriter::new_master_slaves).
+    fn write_master_slaves(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(e) = self.deferred.take() {
+            return Err(e);
+        }
+
+        let Some(master) = self.writers.first_mut() else {
+            return Ok(0);
+        };
+
+        let n = match master.write(buf) {
+            Ok(n) => n,
+            Err(e) => return Err(self.error(0, e)),
+        };
+        self.record(0, n);
+
+        if n == 0 {
+            return Ok(0);
+        }
+
+        // The master has consumed `n` bytes, so failing slaves must not turn the write into an error.
+        for index in 1..self.writers.len() {
+            if !self.enabled[index] {
+                continue;
+            }
+
+            match self.writers[index].write_all(&buf[..n]) {
+                Ok(()) => self.record(index, n),
+                Err(e) => match self.policy {
+                    ErrorPolicy::FailFast => {
+                        self.deferred = Some(self.error(index, e));
+                        break;
+                    }
+                    ErrorPolicy::ContinueAndCollect => self.collect_error(index, e),
+                    ErrorPolicy::DisableOnError => {
+                        self.enabled[index] = false;
+                        self.disabled.push((index, e));
+                    }
+                },
+            }
+        }
+        self.record_fan_out(n);
+
+        Ok(n)
+    }
+}
+
+/// Write every byte of `bufs` into `writer`,
+/// using [`write_vectored`](Write::write_vectored) and advancing past the bytes accepted by each call.
+///
+/// This mirrors the unstable `Write::write_all_vectored`.
+#[cfg(feature = "std")]
+fn write_all_vectored<W: Write + ?Sized>(writer: &mut W, bufs: &[IoSlice<'_>]) -> io::Result<()> {
+    let mut bufs = bufs.to_vec();
+    let mut bufs = &mut bufs[..];
+
+    // Skip leading empty buffers, so a zero return value always indicates a failure to write.
+    IoSlice::advance_slices(&mut bufs, 0);
+
+    while !bufs.is_empty() {
+        match writer.write_vectored(bufs) {
+            Ok(0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::WriteZero,
+                    "failed to write whole buffer",
+                ))
+            }
+            Ok(n) => IoSlice::advance_slices(&mut bufs, n),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Copy the entire contents of a reader into multiple writers.
+///
+/// Uses a [`MultiWriter`] and [`std::io::copy`].
+///
+/// The writers are not flushed, so data buffered by them, e.g. by a [`BufWriter`](std::io::BufWriter),
+/// may not have reached its destination yet when this returns.
+/// This leaves it up to the caller to decide when to flush, for instance when the writers keep streaming data afterwards.
+/// Use [`copy_into_many_flush`] to flush every writer once the copy is complete.
+#[cfg(feature = "std")]
+pub fn copy_into_many<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+) -> io::Result<u64> {
+    let mut multi_writer = MultiWriter::new(writers);
+    io::copy(reader, &mut multi_writer)
+}
+
+/// Copy the entire contents of a reader into multiple writers using `policy`, then flush every writer.
+///
+/// This behaves like [`copy_into_many`], but only returns once [`flush`](Write::flush) succeeded on every writer,
+/// so for writers like a [`BufWriter`](std::io::BufWriter) wrapping a file, the data has been handed to the file.
+///
+/// # Errors
+///
+/// Errors while copying are returned like by a [`MultiWriter`] using `policy`, without flushing.
+/// Flush errors are reported like by [`MultiWriter::flush`] using `policy`:
+/// under [`ErrorPolicy::FailFast`], the error wraps a [`MultiWriteError`] reporting the index of the first writer that failed to flush,
+/// and the following writers are not flushed, while under [`ErrorPolicy::ContinueAndCollect`],
+/// every writer is flushed and the error wraps a [`MultiError`] with the errors of all writers that failed to flush.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{BufWriter, Write};
+///
+/// let mut output = Vec::new();
+/// let mut buffered = BufWriter::new(&mut output);
+///
+/// let copied = streams::copy_into_many_flush(
+///     &mut &b"Hello, world!"[..],
+///     vec![&mut buffered],
+///     streams::ErrorPolicy::FailFast,
+/// )?;
+///
+/// assert_eq!(copied, 13);
+/// assert_eq!(buffered.get_ref().as_slice(), b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_into_many_flush<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    policy: ErrorPolicy,
+) -> io::Result<u64> {
+    let mut multi_writer = MultiWriter::with_error_policy(writers, policy);
+    let copied = io::copy(reader, &mut multi_writer)?;
+    multi_writer.flush()?;
+
+    Ok(copied)
+}
+
+/// Copy the entire contents of a reader into multiple boxed writers.
+///
+/// This behaves like [`copy_into_many`], borrowing each boxed writer as a `&mut dyn Write`.
+#[cfg(feature = "std")]
+pub fn copy_into_many_boxed<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: &mut [Box<dyn Write>],
+) -> io::Result<u64> {
+    copy_into_many(
+        reader,
+        writers
+            .iter_mut()
+            .map(|w| &mut **w as &mut dyn Write)
+            .collect(),
+    )
+}
+
+/// Copy the entire contents of a reader into multiple writers, returning the number of bytes each writer received.
+///
+/// This behaves like [`copy_into_many`], but reports one count per writer, in the order of the writers,
+/// instead of the total number of bytes read.
+/// As the data is read once and fanned out using [`ErrorPolicy::FailFast`], every count equals the length of the input on success.
+///
+/// To obtain differing counts under [`ErrorPolicy::ContinueAndCollect`],
+/// copy into a [`MultiWriter`] created with [`MultiWriter::with_error_policy`] and inspect [`MultiWriter::bytes_written`].
+#[cfg(feature = "std")]
+pub fn copy_into_many_counted<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+) -> io::Result<Vec<u64>> {
+    let mut multi_writer = MultiWriter::new(writers);
+    io::copy(reader, &mut multi_writer)?;
+
+    Ok(multi_writer.bytes_written().to_vec())
+}
+
+/// Copy the entire contents of a reader into multiple writers, reporting progress along the way.
+///
+/// Unlike [`copy_into_many`], this reads the input in chunks of [`DEFAULT_BUF_SIZE`] bytes using a manual loop.
+/// After each chunk has been written to every writer, `on_progress` is called with the cumulative number of bytes copied so far,
+/// so it is called repeatedly for inputs larger than a single chunk, and its final value equals the returned total.
+#[cfg(feature = "std")]
+pub fn copy_into_many_with_progress<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    mut on_progress: impl FnMut(u64),
+) -> io::Result<u64> {
+    let mut multi_writer = MultiWriter::new(writers);
+    let mut buf = vec![0; DEFAULT_BUF_SIZE];
+
+    copy_chunks(reader, &mut multi_writer, &mut buf, |copied| {
+        on_progress(copied);
+        Ok(())
+    })
+}
+
+/// Copy the entire contents of a reader into multiple writers, using an internal buffer of `buf_size` bytes.
+///
+/// [`copy_into_many`] relies on [`io::copy`], which uses a fixed-size buffer of 8 KiB.
+/// Larger buffers reduce the number of read and write calls (and thus syscalls for unbuffered files and pipes)
+/// at the cost of allocating `buf_size` bytes for the duration of the copy.
+///
+/// # Errors
+///
+/// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if `buf_size` is zero.
+#[cfg(feature = "std")]
+pub fn copy_into_many_buffered<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    buf_size: usize,
+) -> io::Result<u64> {
+    if buf_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "buffer size must be greater than zero",
+        ));
+    }
+
+    let mut multi_writer = MultiWriter::new(writers);
+    let mut buf = vec![0; buf_size];
+
+    copy_chunks(reader, &mut multi_writer, &mut buf, |_| Ok(()))
+}
+
+/// Copy the entire contents of a reader into multiple writers, holding at most one chunk of `chunk_size` bytes in memory.
+///
+/// The copy strictly alternates between reading one chunk into a single fixed buffer of `chunk_size` bytes
+/// and writing that chunk completely into every writer, in order, before the next chunk is read.
+/// The reader is never read ahead of the slowest writer, so memory use is bounded by `chunk_size` regardless of the input size,
+/// and a slow writer applies backpressure to the reader instead of causing data to be buffered.
+/// Any buffering beyond that is up to the writers themselves.
+///
+/// # Errors
+///
+/// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if `chunk_size` is zero.
+/// Errors from the writers wrap a [`MultiWriteError`] reporting the index of the failing writer, like for a [`MultiWriter`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// let mut output = Vec::new();
+/// let copied = streams::copy_into_many_streaming(&mut &b"Hello, world!"[..], vec![&mut output], 4)?;
+///
+/// assert_eq!(copied, 13);
+/// assert_eq!(output, b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_into_many_streaming<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    chunk_size: usize,
+) -> io::Result<u64> {
+    if chunk_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "chunk size must be greater than zero",
+        ));
+    }
+
+    let mut multi_writer = MultiWriter::new(writers);
+    let mut buf = vec![0; chunk_size];
+
+    // `copy_chunks` writes each chunk into every writer before reading the next one, which is the documented contract.
+    copy_chunks(reader, &mut multi_writer, &mut buf, |_| Ok(()))
+}
+
+/// Copy the entire contents of a reader into multiple writers, sizing the internal buffer according to `size_hint`.
+///
+/// This behaves like [`copy_into_many`], reading the input in chunks of at most [`DEFAULT_BUF_SIZE`] bytes,
+/// but an input known to be smaller than that, as given by `size_hint`, is read using a buffer of just its size.
+/// See [`copy_into_many_buffered_source_hinted`] to pre-allocate memory for the entire input.
+///
+/// An incorrect hint only affects performance, not correctness: the input is read to the end regardless.
+#[cfg(feature = "std")]
+pub fn copy_into_many_hinted<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    size_hint: Option<u64>,
+) -> io::Result<u64> {
+    let buf_size = size_hint.map_or(DEFAULT_BUF_SIZE, |size_hint| {
+        // Leave room to read the end of file along with the last bytes, and never use an empty buffer.
+        size_hint.saturating_add(1).min(DEFAULT_BUF_SIZE as u64) as usize
+    });
+
+    let mut multi_writer = MultiWriter::new(writers);
+    let mut buf = vec![0; buf_size];
+
+    copy_chunks(reader, &mut multi_writer, &mut buf, |_| Ok(()))
+}
+
+/// Copy the entire contents of a reader into multiple writers, reading it completely before writing anything.
+///
+/// Unlike [`copy_into_many`], which streams the input, this reads the whole input into memory first
+/// and then writes it to each writer with a single [`write_all`](Write::write_all),
+/// so the writers only need to be open for the time it takes to write the data, not to read it.
+/// The tradeoff is memory: the entire input is held in memory at once.
+/// To bound that, pass a `max_len`, in bytes, that the input may not exceed.
+///
+/// # Errors
+///
+/// If the input is longer than `max_len`, an error of kind [`Other`](io::ErrorKind::Other) is returned
+/// as soon as the excess is read, before any writer is written to.
+/// Errors from the reader are returned as-is, also before any writer is written to.
+/// Errors from the writers wrap a [`MultiWriteError`] reporting the index of the failing writer, like for a [`MultiWriter`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Write};
+///
+/// let mut output = Vec::new();
+/// let copied = streams::copy_into_many_buffered_source(&mut &b"Hello, world!"[..], vec![&mut output], Some(64))?;
+/// assert_eq!(copied, 13);
+///
+/// let err = streams::copy_into_many_buffered_source(&mut &b"Hello, world!"[..], vec![&mut output], Some(5)).unwrap_err();
+/// assert_eq!(err.kind(), io::ErrorKind::Other);
+/// # Ok::<(), io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_into_many_buffered_source<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    max_len: Option<u64>,
+) -> io::Result<u64> {
+    copy_into_many_buffered_source_hinted(reader, writers, max_len, None)
+}
+
+/// Like [`copy_into_many_buffered_source`], pre-allocating the memory for the input according to `size_hint`.
+///
+/// When the size of the input is known in advance, e.g. the length of a file,
+/// passing it as `size_hint` avoids the reallocations of growing the buffer while reading.
+/// The hint is capped at `max_len`, and if the allocation fails, reading proceeds as without a hint.
+///
+/// An incorrect hint only affects performance, not correctness: the input is read to the end regardless.
+#[cfg(feature = "std")]
+pub fn copy_into_many_buffered_source_hinted<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    max_len: Option<u64>,
+    size_hint: Option<u64>,
+) -> io::Result<u64> {
+    let mut contents = Vec::new();
+
+    if let Some(size_hint) = size_hint {
+        let capacity = size_hint.min(max_len.unwrap_or(u64::MAX));
+        // A failed allocation only means the buffer grows while reading instead.
+        let _ = contents.try_reserve_exact(capacity.try_into().unwrap_or(usize::MAX));
+    }
+
+    match max_len {
+        Some(max_len) => {
+            // Read one byte past the cap to tell an input of exactly `max_len` bytes from a longer one.
+            reader
+                .take(max_len.saturating_add(1))
+                .read_to_end(&mut contents)?;
+
+            if contents.len() as u64 > max_len {
+                return Err(io::Error::other(format!(
+                    "source exceeds the maximum of {max_len} bytes"
+                )));
+            }
+        }
+        None => {
+            reader.read_to_end(&mut contents)?;
+        }
+    }
+
+    let mut multi_writer = MultiWriter::new(writers);
+    multi_writer.write_all(&contents)?;
+
+    Ok(contents.len() as u64)
+}
+
+/// Copy the entire contents of a reader into multiple writers, stopping early once `cancel` is set.
+///
+/// Like [`copy_into_many_with_progress`], this reads the input in chunks of [`DEFAULT_BUF_SIZE`] bytes using a manual loop.
+/// `cancel` is checked before reading each chunk, so a cancellation takes effect as soon as the chunk being copied has reached every writer.
+///
+/// # Errors
+///
+/// If the copy is cancelled, an error of kind [`Interrupted`](io::ErrorKind::Interrupted) is returned,
+/// wrapping a [`CopyCancelled`] reporting the number of bytes copied.
+/// Take care not to retry such errors blindly, as is commonly done for [`Interrupted`](io::ErrorKind::Interrupted).
+///
+/// # Examples
+///
+/// ```
+/// use std::{io::Write, sync::atomic::AtomicBool};
+/// use streams::CopyCancelled;
+///
+/// let cancel = AtomicBool::new(true);
+/// let mut output = Vec::new();
+///
+/// let err = streams::copy_into_many_cancellable(&mut &b"Hello, world!"[..], vec![&mut output], &cancel).unwrap_err();
+/// let cancelled = err.get_ref().and_then(|e| e.downcast_ref::<CopyCancelled>()).unwrap();
+/// assert_eq!(cancelled.copied(), 0);
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_into_many_cancellable<R: Read + ?Sized>(
+    reader: &mut R,
+    writers: Vec<&mut dyn Write>,
+    cancel: &AtomicBool,
+) -> io::Result<u64> {
+    if cancel.load(Ordering::Relaxed) {
+        return Err(CopyCancelled::new(0).into_io_error());
+    }
+
+    let mut multi_writer = MultiWriter::new(writers);
+    let mut buf = vec![0; DEFAULT_BUF_SIZE];
+
+    copy_chunks(reader, &mut multi_writer, &mut buf, |copied| {
+        if cancel.load(Ordering::Relaxed) {
+            Err(CopyCancelled::new(copied).into_io_error())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Copy the entire contents of a buffered reader into multiple writers, one complete line at a time.
+///
+/// Each line, including its trailing newline, is passed to every writer in a single call to [`write_all`](Write::write_all),
+/// so writers shared with other producers, like a locked standard output, never receive a partial line interleaved with other output.
+/// A final line without a trailing newline is written as-is.
+///
+/// The writers are flushed whenever at least [`DEFAULT_BUF_SIZE`] bytes have been copied since the last flush, and once more at the end.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// let mut output = Vec::new();
+/// let copied = streams::copy_lines_into_many(&b"one\ntwo"[..], vec![&mut output])?;
+///
+/// assert_eq!(copied, 7);
+/// assert_eq!(output, b"one\ntwo");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_lines_into_many<R: BufRead>(
+    mut reader: R,
+    writers: Vec<&mut dyn Write>,
+) -> io::Result<u64> {
+    let mut multi_writer = MultiWriter::new(writers);
+    let mut line = Vec::new();
+    let mut copied = 0;
+    let mut unflushed = 0;
+
+    loop {
+        line.clear();
+        let n = match reader.read_until(b'\n', &mut line) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        multi_writer.write_all(&line)?;
+        copied += n as u64;
+        unflushed += n;
+
+        if unflushed >= DEFAULT_BUF_SIZE {
+            multi_writer.flush()?;
+            unflushed = 0;
+        }
+    }
+
+    multi_writer.flush()?;
+
+    Ok(copied)
+}
+
+/// The outcome of [`copy_into_many_report`], telling which writers received the entire input and which failed.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MultiWriteReport {
+    succeeded: Vec<usize>,
+    failed: Vec<(usize, io::Error)>,
+    bytes_copied: u64,
+}
+
+#[cfg(feature = "std")]
+impl MultiWriteReport {
+    /// The indices of the writers that received every byte copied, in order of the writers.
+    pub fn succeeded(&self) -> &[usize] {
+        &self.succeeded
+    }
+
+    /// The index of each failing writer together with the error it returned, in order of the writers.
+    pub fn failed(&self) -> &[(usize, io::Error)] {
+        &self.failed
+    }
+
+    /// The number of bytes read from the reader and written to every writer that had not failed yet.
+    pub fn bytes_copied(&self) -> u64 {
+        self.bytes_copied
+    }
+
+    /// Returns `true` if no writer failed.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Consumes the report, returning the errors of the failing writers. See [`failed`](MultiWriteReport::failed).
+    pub fn into_failed(self) -> Vec<(usize, io::Error)> {
+        self.failed
+    }
+
+    /// Consumes the report, returning the number of bytes copied if every writer succeeded,
+    /// or a [`MultiError`] with the errors of the failing writers otherwise.
+    pub fn into_result(self) -> Result<u64, MultiError> {
+        if self.failed.is_empty() {
+            Ok(self.bytes_copied)
+        } else {
+            let total = self.succeeded.len() + self.failed.len();
+            Err(MultiError::new(total, self.failed))
+        }
+    }
+}
+
+/// Copy the entire contents of a reader into multiple writers, reporting which writers succeeded and which failed.
+///
+/// Like [`ErrorPolicy::ContinueAndCollect`], a failing writer does not abort the copy:
+/// its error is recorded in the returned [`MultiWriteReport`] and it receives no further data, while the others carry on.
+/// Reading stops early once every writer has failed.
+/// The input is read in chunks of [`DEFAULT_BUF_SIZE`] bytes using a manual loop.
+///
+/// # Errors
+///
+/// Failing writers never cause an error to be returned, even if all of them fail.
+/// An error from the reader, however, aborts the copy and is returned as-is,
+/// as the writers can't all have received the same data in that case.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+///
+/// let mut output = Vec::new();
+/// let mut full: &mut [u8] = &mut [];
+/// let writers: Vec<&mut dyn Write> = vec![&mut output, &mut full];
+///
+/// let report = streams::copy_into_many_report(&mut &b"Hello, world!"[..], writers)?;
+///
+/// assert_eq!(report.succeeded(), [0]);
+/// assert_eq!(report.failed()[0].0, 1);
+/// assert_eq!(report.bytes_copied(), 13);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "std")]
+pub fn copy_into_many_report<R: Read + ?Sized>(
+    reader: &mut R,
+    mut writers: Vec<&mut dyn Write>,
+) -> io::Result<MultiWriteReport> {
+    let mut failed = Vec::new();
+    let mut is_failed = vec![false; writers.len()];
+    let mut bytes_copied = 0;
+    let mut buf = vec![0; DEFAULT_BUF_SIZE];
+
+    while writers.is_empty() || failed.len() < writers.len() {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        for (index, writer) in writers.iter_mut().enumerate() {
+            if is_failed[index] {
+                continue;
+            }
+
+            if let Err(e) = writer.write_all(&buf[..n]) {
+                is_failed[index] = true;
+                failed.push((index, e));
+            }
+        }
+
+        bytes_copied += n as u64;
+    }
+
+    let succeeded = (0..writers.len()).filter(|&i| !is_failed[i]).collect();
+    failed.sort_by_key(|&(index, _)| index);
+
+    Ok(MultiWriteReport {
+        succeeded,
+        failed,
+        bytes_copied,
+    })
+}
+
+/// Copy `reader` into `writer` one chunk of at most `buf.len()` bytes at a time,
+/// calling `after_chunk` with the cumulative number of bytes copied after each chunk has been written.
+///
+/// An error returned by `after_chunk` aborts the copy.
+#[cfg(feature = "std")]
+fn copy_chunks<R: Read + ?Sized, W: Write + ?Sized>(
+    reader: &mut R,
+    writer: &mut W,
+    buf: &mut [u8],
+    mut after_chunk: impl FnMut(u64) -> io::Result<()>,
+) -> io::Result<u64> {
+    let mut copied = 0;
+
+    loop {
+        let n = match reader.read(buf) {
+            Ok(0) => return Ok(copied),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        writer.write_all(&buf[..n])?;
+        copied += n as u64;
+
+        after_chunk(copied)?;
+    }
+}
+
+/// Utility macro to avoid manually casting writers to `&mut dyn std::io::Write`.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! copy_into_many {
+    ($reader:expr,$writers:expr) => {{
+        $crate::copy_into_many(
+            $reader,
+            $writers
+                .iter_mut()
+                .map(|w| w as &mut dyn std::io::Write)
+                .collect(),
+        )
+    }};
+}
+
+/// Utility macro to avoid collecting writers into a `Vec<&mut dyn std::io::Write>`.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! copy_into_all {
+    ($reader:expr,$($writer:expr),*) => {{
+        let writers = vec![$(&mut $writer as &mut dyn std::io::Write,)*];
+        $crate::copy_into_many(
+            $reader,
+            writers,
+        )
+    }};
+}
+
+/// Utility macro to avoid collecting writers into a `Vec<&mut dyn std::io::Write>`, returning the number of bytes each writer received.
+///
+/// See [`copy_into_many_counted`].
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! copy_into_all_counted {
+    ($reader:expr,$($writer:expr),*) => {{
+        let writers = vec![$(&mut $writer as &mut dyn std::io::Write,)*];
+        $crate::copy_into_many_counted(
+            $reader,
+            writers,
+        )
+    }};
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::{
+        cell::Cell,
+        collections::VecDeque,
+        io::{self, BufWriter, Cursor, IoSlice, Read, Write},
+        rc::Rc,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use crate::{
+        mocks::{FailingWriter, SharedWriter, ZeroWriter},
+        CopyCancelled, ErrorPolicy, MultiError, MultiWriteError,
+    };
+
+    #[test]
+    fn multi_writer() {
+        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut multi_writer =
+            crate::MultiWriter::new(writers.iter_mut().map(|w| w as &mut dyn Write).collect());
+
+        let input = b"Hello, world!";
+        multi_writer.write_all(input).unwrap();
+
+        for writer in writers {
+            assert_eq!(writer[..], *b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn multi_writer_reports_failing_index() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut writer3 = Vec::<u8>::new();
+        let mut multi_writer =
+            crate::MultiWriter::new(vec![&mut writer1, &mut writer2, &mut writer3]);
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+
+        assert_eq!(writer1, *b"Hello, world!");
+        assert!(writer3.is_empty());
+    }
+
+    #[test]
+    fn multi_writer_flush_reports_failing_index() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut writer3 = Vec::<u8>::new();
+        let mut multi_writer =
+            crate::MultiWriter::new(vec![&mut writer1, &mut writer2, &mut writer3]);
+
+        let err = multi_writer.flush().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+    }
+
+    #[test]
+    fn multi_writer_reports_failing_label() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut writer3 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer = crate::MultiWriter::new_named(vec![
+            ("local".to_string(), &mut writer1),
+            ("backup-s3".to_string(), &mut writer2),
+        ]);
+        multi_writer.push(&mut writer3);
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        assert!(err.to_string().contains("writer 'backup-s3' failed"));
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.label(), Some("backup-s3"));
+        assert_eq!(multi_write_error.index(), 1);
+
+        // Labels move along with their writers, and unlabeled writers fall back to their index.
+        multi_writer.remove(0);
+        assert_eq!(multi_writer.label(0), Some("backup-s3"));
+        multi_writer.remove(0);
+        assert_eq!(multi_writer.label(0), None);
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        assert!(err.to_string().contains("writer 0 failed"));
+    }
+
+    #[test]
+    fn multi_writer_continue_and_collect() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut writer3 = Vec::<u8>::new();
+        let mut writer4 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer1, &mut writer2, &mut writer3, &mut writer4],
+            ErrorPolicy::ContinueAndCollect,
+        );
+
+        let input = b"Hello, world!";
+        assert_eq!(multi_writer.write(input).unwrap(), input.len());
+
+        assert_eq!(multi_writer.bytes_written(), [13, 0, 13, 0]);
+
+        let errors = multi_writer.take_errors();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, 1);
+        assert_eq!(errors[0].1.kind(), io::ErrorKind::StorageFull);
+        assert_eq!(errors[1].0, 3);
+        assert_eq!(errors[1].1.kind(), io::ErrorKind::BrokenPipe);
+        assert!(multi_writer.errors().is_empty());
+
+        assert_eq!(writer1, *b"Hello, world!");
+        assert_eq!(writer3, *b"Hello, world!");
+    }
+
+    #[test]
+    fn multi_writer_continue_and_collect_one_error_per_writer() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut writer3 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer1, &mut writer2, &mut writer3],
+            ErrorPolicy::ContinueAndCollect,
+        );
+
+        for _ in 0..10 {
+            multi_writer.write_all(b"Hello").unwrap();
+        }
+
+        let indices: Vec<_> = multi_writer.errors().iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, [1, 2]);
+
+        // Removing a writer drops its entry and shifts the indices of the following ones.
+        multi_writer.remove(1);
+        multi_writer.write_all(b"!").unwrap();
+        let errors: Vec<_> = multi_writer
+            .take_errors()
+            .into_iter()
+            .map(|(i, e)| (i, e.kind()))
+            .collect();
+        assert_eq!(errors, [(1, io::ErrorKind::BrokenPipe)]);
+
+        multi_writer.write_all(b"!").unwrap();
+        assert_eq!(multi_writer.errors().len(), 1);
+    }
+
+    #[test]
+    fn multi_writer_continue_and_collect_all_failing() {
+        let mut writer1 = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut writer2 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer1, &mut writer2],
+            ErrorPolicy::ContinueAndCollect,
+        );
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        let multi_write_errors = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiError>())
+            .unwrap();
+
+        let indices: Vec<_> = multi_write_errors
+            .errors()
+            .iter()
+            .map(|(i, _)| *i)
+            .collect();
+        assert_eq!(indices, [0, 1]);
+        assert_eq!(multi_write_errors.total(), 2);
+        assert!(multi_writer.errors().is_empty());
+    }
+
+    #[test]
+    fn multi_writer_disable_on_error() {
+        /// Accepts `chunks` writes, then fails every following write.
+        struct FailAfter {
+            buf: Vec<u8>,
+            chunks: usize,
+        }
+
+        impl Write for FailAfter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.chunks == 0 {
+                    return Err(io::Error::from(io::ErrorKind::BrokenPipe));
+                }
+
+                self.chunks -= 1;
+                self.buf.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer0 = Vec::<u8>::new();
+        let mut writer1 = FailAfter {
+            buf: Vec::new(),
+            chunks: 1,
+        };
+        let mut writer2 = Vec::<u8>::new();
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer0, &mut writer1, &mut writer2],
+            ErrorPolicy::DisableOnError,
+        );
+
+        multi_writer.write_all(b"one,").unwrap();
+        assert!(multi_writer.disabled_writers().is_empty());
+
+        multi_writer.write_all(b"two,").unwrap();
+        multi_writer.write_all(b"three").unwrap();
+        multi_writer.flush().unwrap();
+
+        let disabled = multi_writer.disabled_writers();
+        assert_eq!(disabled.len(), 1);
+        assert_eq!(disabled[0].0, 1);
+        assert_eq!(disabled[0].1.kind(), io::ErrorKind::BrokenPipe);
+        assert!(multi_writer.errors().is_empty());
+        assert_eq!(multi_writer.bytes_written(), [13, 4, 13]);
+
+        assert_eq!(writer0, b"one,two,three");
+        assert_eq!(writer1.buf, b"one,");
+        assert_eq!(writer2, b"one,two,three");
+    }
+
+    #[test]
+    fn multi_writer_disable_on_error_all_failing() {
+        let mut writer1 = FailingWriter::new(io::ErrorKind::StorageFull);
+        let mut writer2 = Vec::<u8>::new();
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer1, &mut writer2],
+            ErrorPolicy::DisableOnError,
+        );
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.remove(1);
+        assert_eq!(multi_writer.disabled_writers()[0].0, 0);
+
+        let err = multi_writer.write(b"world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(multi_writer.disabled_writers().len(), 1);
+
+        multi_writer.remove(0);
+        assert!(multi_writer.disabled_writers().is_empty());
+        assert_eq!(multi_writer.write(b"!").unwrap(), 1);
+    }
+
+    #[test]
+    fn multi_writer_stats() {
+        let mut writer0 = Vec::<u8>::new();
+        let mut writer1 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut writer2 = Vec::<u8>::new();
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer0, &mut writer1, &mut writer2],
+            ErrorPolicy::ContinueAndCollect,
+        );
+
+        multi_writer.write_all(b"Hello").unwrap();
+        multi_writer.write_all(b"").unwrap();
+        let bufs = [IoSlice::new(b", "), IoSlice::new(b"world!")];
+        assert_eq!(multi_writer.write_vectored(&bufs).unwrap(), 8);
+
+        let stats = multi_writer.stats();
+        assert_eq!(stats.writes(), 2);
+        assert_eq!(stats.total_bytes(), 13);
+        assert_eq!(stats.writer_bytes(), [13, 0, 13]);
+        assert_eq!(stats.writer_writes(), [2, 0, 2]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn multi_writer_stats_serde() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer = crate::MultiWriter::with_error_policy(
+            vec![&mut writer1, &mut writer2],
+            ErrorPolicy::ContinueAndCollect,
+        );
+        multi_writer.write_all(b"Hello").unwrap();
+
+        let stats = multi_writer.stats();
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "writes": 1,
+                "total_bytes": 5,
+                "writer_bytes": [5, 0],
+                "writer_writes": [1, 0],
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<crate::MultiWriterStats>(json).unwrap(),
+            stats
+        );
+    }
+
+    #[test]
+    fn multi_writer_push() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = Vec::<u8>::new();
+        let mut writer3 = Vec::<u8>::new();
+        let mut multi_writer = crate::MultiWriter::new(vec![&mut writer1, &mut writer2]);
+
+        multi_writer.write_all(b"Hello").unwrap();
+        multi_writer.write_all(b", ").unwrap();
+        multi_writer.push(&mut writer3);
+        assert_eq!(multi_writer.len(), 3);
+        multi_writer.write_all(b"world!").unwrap();
+
+        assert_eq!(writer1, *b"Hello, world!");
+        assert_eq!(writer2, *b"Hello, world!");
+        assert_eq!(writer3, *b"world!");
+    }
+
+    #[test]
+    fn multi_writer_from_iter_and_extend() {
+        let mut writers = [Vec::<u8>::new(), Vec::new()];
+        let mut extra = Vec::<u8>::new();
+        let mut multi_writer: crate::MultiWriter<_> = writers.iter_mut().collect();
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.extend([&mut extra]);
+        assert_eq!(multi_writer.len(), 3);
+        multi_writer.write_all(b"world!").unwrap();
+
+        assert_eq!(writers[0], *b"Hello, world!");
+        assert_eq!(writers[1], *b"Hello, world!");
+        assert_eq!(extra, *b"world!");
+    }
+
+    #[test]
+    fn multi_writer_from_vec_and_into_iter() {
+        let mut multi_writer = crate::MultiWriter::from(vec![Vec::<u8>::new(), Vec::new()]);
+        multi_writer.write_all(b"Hello, world!").unwrap();
+
+        let mut count = 0;
+        for writer in multi_writer {
+            assert_eq!(writer, b"Hello, world!");
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn multi_writer_collect_owned() {
+        let mut multi_writer: crate::MultiWriter<_> =
+            (0..3).map(|_| Cursor::new(Vec::<u8>::new())).collect();
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(multi_writer.len(), 3);
+
+        let contents: Vec<_> = multi_writer.into_iter().map(Cursor::into_inner).collect();
+        assert_eq!(contents, [b"Hello, world!"; 3]);
+    }
+
+    #[test]
+    fn multi_writer_empty_write_skips_writers() {
+        struct CountingCalls(usize);
+
+        impl Write for CountingCalls {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0 += 1;
+                Ok(buf.len())
+            }
+
+            fn write_all(&mut self, _buf: &[u8]) -> io::Result<()> {
+                self.0 += 1;
+                Ok(())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer1 = CountingCalls(0);
+        let mut writer2 = CountingCalls(0);
+        let mut multi_writer = crate::MultiWriter::new(vec![&mut writer1, &mut writer2]);
+
+        assert_eq!(multi_writer.write(&[]).unwrap(), 0);
+        multi_writer.write_all(&[]).unwrap();
+        assert_eq!(multi_writer.bytes_written(), [0, 0]);
+
+        assert_eq!(writer1.0, 0);
+        assert_eq!(writer2.0, 0);
+    }
+
+    #[test]
+    fn multi_writer_master_slaves_short_write() {
+        let mut master = [0; 5];
+        let mut master_slice = &mut master[..];
+        let mut slave1 = Vec::<u8>::new();
+        let mut slave2 = Vec::<u8>::new();
+        let mut multi_writer = crate::MultiWriter::new_master_slaves(vec![
+            &mut master_slice,
+            &mut slave1,
+            &mut slave2,
+        ]);
+
+        assert_eq!(multi_writer.write(b"Hello, world!").unwrap(), 5);
+        assert_eq!(multi_writer.bytes_written(), [5, 5, 5]);
+
+        // The master is full, so nothing reaches the slaves.
+        assert_eq!(multi_writer.write(b"world!").unwrap(), 0);
+        let err = multi_writer.write_all(b"world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        assert_eq!(master, *b"Hello");
+        assert_eq!(slave1, b"Hello");
+        assert_eq!(slave2, b"Hello");
+    }
+
+    #[test]
+    fn multi_writer_master_slaves_failing_slave() {
+        fn write_with(policy: ErrorPolicy) -> (Vec<io::Result<usize>>, Vec<u8>, Vec<u8>) {
+            let mut master = Vec::<u8>::new();
+            let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+            let mut slave = Vec::<u8>::new();
+            let mut multi_writer =
+                crate::MultiWriter::new_master_slaves(vec![&mut master, &mut failing, &mut slave]);
+            multi_writer.set_error_policy(policy);
+
+            let results = vec![
+                multi_writer.write(b"Hello"),
+                multi_writer.write(b"World"),
+                multi_writer.write(b"!"),
+            ];
+
+            if policy == ErrorPolicy::ContinueAndCollect {
+                let errors: Vec<_> = multi_writer.errors().iter().map(|(i, _)| *i).collect();
+                assert_eq!(errors, [1]);
+            }
+            if policy == ErrorPolicy::DisableOnError {
+                assert_eq!(multi_writer.disabled_writers().len(), 1);
+                assert_eq!(multi_writer.disabled_writers()[0].0, 1);
+            }
+
+            (results, master, slave)
+        }
+
+        // The failing slave's error is returned by the following write, which leaves the master untouched.
+        let (results, master, slave) = write_with(ErrorPolicy::FailFast);
+        assert_eq!(results[0].as_ref().unwrap(), &5);
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(results[2].as_ref().unwrap(), &1);
+        assert_eq!(master, b"Hello!");
+        assert_eq!(slave, b"");
+
+        for policy in [ErrorPolicy::ContinueAndCollect, ErrorPolicy::DisableOnError] {
+            let (results, master, slave) = write_with(policy);
+            let written: Vec<_> = results.into_iter().map(Result::unwrap).collect();
+            assert_eq!(written, [5, 5, 1]);
+            assert_eq!(master, b"HelloWorld!");
+            assert_eq!(slave, b"HelloWorld!");
+        }
+    }
+
+    #[test]
+    fn multi_writer_master_slaves_flush_returns_deferred_error() {
+        let mut master = Vec::<u8>::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer =
+            crate::MultiWriter::new_master_slaves(vec![&mut master, &mut failing]);
+
+        assert_eq!(multi_writer.write(b"Hello").unwrap(), 5);
+        let err = multi_writer.flush().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(multi_writer.stats().writes(), 1);
+    }
+
+    #[test]
+    fn multi_writer_master_slaves_write_all() {
+        /// Accepts at most 5 bytes per call.
+        struct ChunkyWriter(Vec<u8>);
+
+        impl Write for ChunkyWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let n = buf.len().min(5);
+                self.0.extend_from_slice(&buf[..n]);
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut master = ChunkyWriter(Vec::new());
+        let mut slave = SharedWriter::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer =
+            crate::MultiWriter::new_master_slaves(vec![&mut master, &mut slave, &mut failing]);
+        multi_writer.set_error_policy(ErrorPolicy::ContinueAndCollect);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(multi_writer.errors().len(), 1);
+        assert_eq!(multi_writer.errors()[0].0, 2);
+
+        assert_eq!(master.0, b"Hello, world!");
+        assert_eq!(slave.contents(), b"Hello, world!");
+        assert_eq!(slave.write_calls(), 3);
+    }
+
+    #[test]
+    fn multi_writer_remove() {
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = Vec::<u8>::new();
+        let mut writer3 = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer =
+            crate::MultiWriter::new(vec![&mut writer1, &mut writer2, &mut writer3]);
+
+        assert!(multi_writer.remove(3).is_none());
+        multi_writer
+            .remove(0)
+            .unwrap()
+            .write_all(b"Hello, ")
+            .unwrap();
+        assert_eq!(multi_writer.len(), 2);
+
+        // The failing writer has shifted from index 2 to index 1.
+        let err = multi_writer.write(b"world!").unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+
+        multi_writer.remove(1).unwrap();
+        multi_writer.write_all(b"world!").unwrap();
+        multi_writer.remove(0).unwrap();
+        assert!(multi_writer.is_empty());
+
+        assert_eq!(writer1, *b"Hello, ");
+        assert_eq!(writer2, *b"world!world!");
+    }
+
+    #[test]
+    fn multi_writer_write_vectored() {
+        // Accepts at most 3 bytes of the first non-empty buffer per call.
+        struct ShortWriter(Vec<u8>);
+
+        impl Write for ShortWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let n = buf.len().min(3);
+                self.0.extend_from_slice(&buf[..n]);
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer1 = Vec::<u8>::new();
+        let mut writer2 = ShortWriter(Vec::new());
+        let mut multi_writer = crate::MultiWriter::new(vec![&mut writer1, &mut writer2]);
+
+        let bufs = [
+            IoSlice::new(b"Hello"),
+            IoSlice::new(b""),
+            IoSlice::new(b", "),
+            IoSlice::new(b"world!"),
+        ];
+        assert_eq!(multi_writer.write_vectored(&bufs).unwrap(), 13);
+        assert_eq!(multi_writer.write_vectored(&[]).unwrap(), 0);
+        assert_eq!(
+            multi_writer.write_vectored(&[IoSlice::new(b"")]).unwrap(),
+            0
+        );
+
+        assert_eq!(writer1, *b"Hello, world!");
+        assert_eq!(writer2.0, *b"Hello, world!");
+    }
+
+    #[test]
+    fn multi_writer_flush_continue_and_collect() {
+        let shared: Vec<_> = (0..4).map(|_| SharedWriter::new()).collect();
+        let mut writers: Vec<Box<dyn Write>> = shared
+            .iter()
+            .map(|w| Box::new(BufWriter::new(w.clone())) as Box<dyn Write>)
+            .collect();
+        writers.insert(2, Box::new(FailingWriter::new(io::ErrorKind::BrokenPipe)));
+        let mut multi_writer = crate::MultiWriter::from_writers(writers);
+        multi_writer.set_error_policy(ErrorPolicy::ContinueAndCollect);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert!(shared.iter().all(|w| w.contents().is_empty()));
+
+        let err = multi_writer.flush().unwrap_err();
+        let multi_write_errors = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiError>())
+            .unwrap();
+        let indices: Vec<_> = multi_write_errors
+            .errors()
+            .iter()
+            .map(|(i, _)| *i)
+            .collect();
+        assert_eq!(indices, [2]);
+        assert_eq!(multi_write_errors.total(), 5);
+
+        // The writers after the failing one were flushed as well.
+        for writer in &shared {
+            assert_eq!(writer.contents(), b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn multi_writer_flush_disable_on_error() {
+        let shared: Vec<_> = (0..2).map(|_| SharedWriter::new()).collect();
+        let mut writers: Vec<Box<dyn Write>> = shared
+            .iter()
+            .map(|w| Box::new(BufWriter::new(w.clone())) as Box<dyn Write>)
+            .collect();
+        writers.insert(
+            1,
+            Box::new(BufWriter::new(FailingWriter::new(
+                io::ErrorKind::BrokenPipe,
+            ))),
+        );
+        let mut multi_writer = crate::MultiWriter::from_writers(writers);
+        multi_writer.set_error_policy(ErrorPolicy::DisableOnError);
+
+        // The failing writer only fails once its buffer is flushed.
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        multi_writer.flush().unwrap();
+
+        let disabled: Vec<_> = multi_writer
+            .disabled_writers()
+            .iter()
+            .map(|(i, e)| (*i, e.kind()))
+            .collect();
+        assert_eq!(disabled, [(1, io::ErrorKind::BrokenPipe)]);
+        for writer in &shared {
+            assert_eq!(writer.contents(), b"Hello, world!");
+        }
+
+        multi_writer.write_all(b"!").unwrap();
+        multi_writer.flush().unwrap();
+        assert_eq!(multi_writer.bytes_written(), [14, 13, 14]);
+
+        multi_writer.remove(2);
+        multi_writer.remove(0);
+        let err = multi_writer.flush().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn multi_writer_flush_fail_fast() {
+        let shared: Vec<_> = (0..2).map(|_| SharedWriter::new()).collect();
+        let mut writers: Vec<Box<dyn Write>> = shared
+            .iter()
+            .map(|w| Box::new(BufWriter::new(w.clone())) as Box<dyn Write>)
+            .collect();
+        writers.insert(1, Box::new(FailingWriter::new(io::ErrorKind::BrokenPipe)));
+        let mut multi_writer = crate::MultiWriter::from_writers(writers);
+        multi_writer.set_error_policy(ErrorPolicy::ContinueAndCollect);
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        multi_writer.set_error_policy(ErrorPolicy::FailFast);
+
+        let err = multi_writer.flush().unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(shared[0].contents(), b"Hello, world!");
+        assert!(shared[1].contents().is_empty());
+    }
+
+    #[test]
+    fn multi_writer_for_each_writer() {
+        let shared: Vec<_> = (0..4).map(|_| SharedWriter::new()).collect();
+        let mut multi_writer = crate::MultiWriter::from_writers(
+            shared.iter().map(|w| BufWriter::new(w.clone())).collect(),
+        );
+        multi_writer.write_all(b"Hello, world!").unwrap();
+
+        let mut visited = Vec::new();
+        multi_writer.for_each_writer(|index, writer| {
+            visited.push(index);
+            if index % 2 == 1 {
+                writer.flush().unwrap();
+            }
+        });
+
+        assert_eq!(visited, [0, 1, 2, 3]);
+        let contents: Vec<_> = shared.iter().map(SharedWriter::contents).collect();
+        assert_eq!(
+            contents,
+            [&b""[..], b"Hello, world!", b"", b"Hello, world!"]
+        );
+        assert_eq!(multi_writer.get_ref()[0].buffer(), b"Hello, world!");
+        assert!(multi_writer.get_mut()[3].buffer().is_empty());
+    }
+
+    #[test]
+    fn copy_into_many_streaming_bounds_outstanding_bytes() {
+        /// A reader that checks that every byte it produced has reached every writer before it is read again.
+        struct TrackingReader<'a> {
+            input: &'a [u8],
+            read: Rc<Cell<usize>>,
+            delivered: Vec<Rc<Cell<usize>>>,
+            max_outstanding: usize,
+        }
+
+        impl Read for TrackingReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                for delivered in &self.delivered {
+                    assert_eq!(delivered.get(), self.read.get(), "read ahead of a writer");
+                }
+
+                let n = self.input.read(buf)?;
+                self.read.set(self.read.get() + n);
+                self.max_outstanding = self.max_outstanding.max(n);
+
+                Ok(n)
+            }
+        }
+
+        /// A writer accepting at most 3 bytes per call, counting the bytes delivered to it.
+        struct SlowWriter {
+            delivered: Rc<Cell<usize>>,
+        }
+
+        impl Write for SlowWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                let n = buf.len().min(3);
+                self.delivered.set(self.delivered.get() + n);
+
+                Ok(n)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let input = b"Hello, world!".repeat(100);
+        let delivered: Vec<_> = (0..3).map(|_| Rc::new(Cell::new(0))).collect();
+        let mut writers: Vec<_> = delivered
+            .iter()
+            .map(|d| SlowWriter {
+                delivered: d.clone(),
+            })
+            .collect();
+        let mut reader = TrackingReader {
+            input: &input,
+            read: Rc::new(Cell::new(0)),
+            delivered: delivered.clone(),
+            max_outstanding: 0,
+        };
+
+        let copied = crate::copy_into_many_streaming(
+            &mut reader,
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            16,
+        )
+        .unwrap();
+
+        assert_eq!(copied, 1300);
+        assert!(reader.max_outstanding <= 16);
+        for delivered in &delivered {
+            assert_eq!(delivered.get(), 1300);
+        }
+
+        let err = crate::copy_into_many_streaming(&mut &input[..], Vec::new(), 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn copy_into_many_flush() {
+        let shared = SharedWriter::new();
+        let mut buffered = BufWriter::new(shared.clone());
+        let mut unbuffered = Vec::<u8>::new();
+
+        let input = b"Hello, world!";
+        crate::copy_into_many(&mut &input[..], vec![&mut buffered, &mut unbuffered]).unwrap();
+        assert!(shared.contents().is_empty());
+
+        let copied = crate::copy_into_many_flush(
+            &mut &input[..],
+            vec![&mut buffered, &mut unbuffered],
+            ErrorPolicy::FailFast,
+        )
+        .unwrap();
+        assert_eq!(copied, 13);
+        assert_eq!(shared.contents(), b"Hello, world!Hello, world!");
+        assert_eq!(unbuffered, b"Hello, world!Hello, world!");
+    }
+
+    #[test]
+    fn copy_into_many_flush_error() {
+        let mut output = Vec::<u8>::new();
+        let mut failing = BufWriter::new(FailingWriter::new(io::ErrorKind::BrokenPipe));
+
+        let err = crate::copy_into_many_flush(
+            &mut &b"Hello"[..],
+            vec![&mut output, &mut failing],
+            ErrorPolicy::FailFast,
+        )
+        .unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[test]
+    fn copy_into_many_flush_continue_and_collect() {
+        let shared = SharedWriter::new();
+        let mut failing1 = BufWriter::new(FailingWriter::new(io::ErrorKind::BrokenPipe));
+        let mut buffered = BufWriter::new(shared.clone());
+        let mut failing2 = BufWriter::new(FailingWriter::new(io::ErrorKind::StorageFull));
+
+        let err = crate::copy_into_many_flush(
+            &mut &b"Hello"[..],
+            vec![&mut failing1, &mut buffered, &mut failing2],
+            ErrorPolicy::ContinueAndCollect,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+
+        let multi_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiError>())
+            .unwrap();
+        let errors: Vec<_> = multi_error
+            .errors()
+            .iter()
+            .map(|(i, e)| (*i, e.kind()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (0, io::ErrorKind::BrokenPipe),
+                (2, io::ErrorKind::StorageFull)
+            ]
+        );
+        assert_eq!(multi_error.total(), 3);
+        assert_eq!(shared.contents(), b"Hello");
+    }
+
+    #[test]
+    fn multi_writer_write_zero_policies() {
+        /// Write into a `Vec`, a writer only ever returning `Ok(0)` and another `Vec` using `policy`.
+        fn write_with(policy: ErrorPolicy) -> (io::Result<()>, crate::MultiWriter<Box<dyn Write>>) {
+            let writers: Vec<Box<dyn Write>> = vec![
+                Box::new(Vec::<u8>::new()),
+                Box::new(ZeroWriter),
+                Box::new(Vec::<u8>::new()),
+            ];
+            let mut multi_writer = crate::MultiWriter::from_writers(writers);
+            multi_writer.set_error_policy(policy);
+
+            let result = multi_writer.write_all(b"Hello, world!");
+            (result, multi_writer)
+        }
+
+        let (result, multi_writer) = write_with(ErrorPolicy::FailFast);
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(multi_writer.bytes_written(), [13, 0, 0]);
+
+        let (result, mut multi_writer) = write_with(ErrorPolicy::ContinueAndCollect);
+        result.unwrap();
+        let errors: Vec<_> = multi_writer
+            .take_errors()
+            .into_iter()
+            .map(|(i, e)| (i, e.kind()))
+            .collect();
+        assert_eq!(errors, [(1, io::ErrorKind::WriteZero)]);
+        assert_eq!(multi_writer.bytes_written(), [13, 0, 13]);
+
+        let (result, mut multi_writer) = write_with(ErrorPolicy::DisableOnError);
+        result.unwrap();
+        let disabled: Vec<_> = multi_writer
+            .disabled_writers()
+            .iter()
+            .map(|(i, e)| (*i, e.kind()))
+            .collect();
+        assert_eq!(disabled, [(1, io::ErrorKind::WriteZero)]);
+        // The disabled writer is skipped from now on.
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(multi_writer.disabled_writers().len(), 1);
+        assert_eq!(multi_writer.bytes_written(), [26, 0, 26]);
+    }
+
+    #[test]
+    fn multi_writer_from_writers() {
+        let mut multi_writer = crate::MultiWriter::from_writers(vec![Vec::<u8>::new(), Vec::new()]);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.write_all(b"world!").unwrap();
+
+        for writer in multi_writer.into_inner() {
+            assert_eq!(writer[..], *b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn multi_writer_from_writers_cursors() {
+        let cursors = vec![Cursor::new(Vec::<u8>::new()), Cursor::new(Vec::new())];
+        let mut multi_writer = crate::MultiWriter::from_writers(cursors);
+        multi_writer.set_error_policy(ErrorPolicy::ContinueAndCollect);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.push(Cursor::new(Vec::new()));
+        multi_writer.write_all(b"world!").unwrap();
+        assert_eq!(multi_writer.bytes_written(), [13, 13, 6]);
+
+        let contents: Vec<_> = multi_writer
+            .into_inner()
+            .into_iter()
+            .map(Cursor::into_inner)
+            .collect();
+        assert_eq!(
+            contents,
+            [&b"Hello, world!"[..], b"Hello, world!", b"world!"]
+        );
+    }
+
+    #[test]
+    fn multi_writer_from_writers_reports_failing_index() {
+        // A boxed slice can't grow, so the second writer runs out of space.
+        let mut multi_writer = crate::MultiWriter::from_writers(vec![
+            Cursor::new(vec![0u8; 13].into_boxed_slice()),
+            Cursor::new(vec![0u8; 5].into_boxed_slice()),
+            Cursor::new(vec![0u8; 13].into_boxed_slice()),
+        ]);
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+    }
+
+    #[test]
+    fn copy_into_many_vec() {
+        let input = b"Hello, world!";
+        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+
+        crate::copy_into_many(
+            &mut &input[..],
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+        )
+        .unwrap();
+
+        for writer in writers {
+            assert_eq!(writer[..], *b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn copy_into_many_boxed() {
+        let input = b"Hello, world!";
+        let shared = [
+            SharedWriter::new(),
+            SharedWriter::new(),
+            SharedWriter::new(),
+        ];
+        let mut writers: Vec<Box<dyn Write>> = shared
+            .iter()
+            .map(|w| Box::new(w.clone()) as Box<dyn Write>)
+            .collect();
+
+        let copied = crate::copy_into_many_boxed(&mut &input[..], &mut writers).unwrap();
+
+        assert_eq!(copied, 13);
+        for writer in shared {
+            assert_eq!(writer.contents(), b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn copy_into_many_counted() {
+        let input = b"Hello, world!";
+        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+
+        let counts = crate::copy_into_many_counted(
+            &mut &input[..],
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+        )
+        .unwrap();
+
+        assert_eq!(counts, [13, 13, 13]);
+        for writer in writers {
+            assert_eq!(writer[..], *b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn copy_into_many_with_progress() {
+        let input = vec![b'a'; 3 * crate::DEFAULT_BUF_SIZE + 1];
+        let mut writers = vec![Vec::<u8>::new(), Vec::new()];
+        let mut progress = Vec::new();
+
+        let copied = crate::copy_into_many_with_progress(
+            &mut &input[..],
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            |copied| progress.push(copied),
+        )
+        .unwrap();
+
+        assert_eq!(copied, input.len() as u64);
+        assert!(progress.len() > 1);
+        assert!(progress.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(progress.last(), Some(&copied));
+        for writer in writers {
+            assert_eq!(writer, input);
+        }
+    }
+
+    #[test]
+    fn copy_into_many_cancellable() {
+        /// Requests cancellation as soon as it has been read from.
+        struct CancellingReader<'a> {
+            inner: &'a [u8],
+            cancel: &'a AtomicBool,
+        }
+
+        impl Read for CancellingReader<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.cancel.store(true, Ordering::Relaxed);
+                self.inner.read(buf)
+            }
+        }
+
+        let input = vec![b'a'; 3 * crate::DEFAULT_BUF_SIZE];
+        let cancel = AtomicBool::new(false);
+        let mut reader = CancellingReader {
+            inner: &input,
+            cancel: &cancel,
+        };
+        let mut writers = [Vec::<u8>::new(), Vec::new()];
+
+        let err = crate::copy_into_many_cancellable(
+            &mut reader,
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            &cancel,
+        )
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        let cancelled = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<CopyCancelled>())
+            .unwrap();
+        assert_eq!(cancelled.copied(), crate::DEFAULT_BUF_SIZE as u64);
+        for writer in writers {
+            assert_eq!(writer.len(), crate::DEFAULT_BUF_SIZE);
+        }
+    }
+
+    #[test]
+    fn copy_into_many_buffered() {
+        let input = b"Hello, world!";
+        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+
+        let copied = crate::copy_into_many_buffered(
+            &mut &input[..],
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            4,
+        )
+        .unwrap();
+
+        assert_eq!(copied, 13);
+        for writer in writers {
+            assert_eq!(writer[..], *b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn copy_into_many_buffered_zero_size() {
+        let mut writer = Vec::<u8>::new();
+
+        let err = crate::copy_into_many_buffered(&mut &b"Hello, world!"[..], vec![&mut writer], 0)
+            .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(writer.is_empty());
+    }
+
+    #[test]
+    fn copy_lines_into_many() {
+        let first = SharedWriter::new();
+        let second = SharedWriter::new();
+
+        let copied = crate::copy_lines_into_many(
+            &b"first\nsecond\nthird"[..],
+            vec![&mut first.clone(), &mut second.clone()],
+        )
+        .unwrap();
+        assert_eq!(copied, 18);
+
+        for writer in [first, second] {
+            assert_eq!(writer.write_calls(), 3);
+            let contents = writer.contents();
+            let lines: Vec<_> = contents.split_inclusive(|&b| b == b'\n').collect();
+            assert_eq!(lines, [&b"first\n"[..], b"second\n", b"third"]);
+        }
+    }
+
+    #[test]
+    fn copy_into_many_report() {
+        let input = vec![b'a'; 10_000];
+        let mut first = Vec::<u8>::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut short = vec![0u8; 9_000];
+        let mut short_slice = &mut short[..];
+        let mut last = Vec::<u8>::new();
+
+        let report = crate::copy_into_many_report(
+            &mut &input[..],
+            vec![&mut first, &mut failing, &mut short_slice, &mut last],
+        )
+        .unwrap();
+
+        assert_eq!(report.succeeded(), [0, 3]);
+        assert_eq!(report.bytes_copied(), 10_000);
+        assert!(!report.is_success());
+
+        let failed = report.into_failed();
+        assert_eq!(failed.len(), 2);
+        assert_eq!(failed[0].0, 1);
+        assert_eq!(failed[0].1.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(failed[1].0, 2);
+        assert_eq!(failed[1].1.kind(), io::ErrorKind::WriteZero);
+
+        assert_eq!(first, input);
+        assert_eq!(last, input);
+    }
+
+    #[test]
+    fn copy_into_many_report_all_failing() {
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut input = &b"Hello, world!"[..];
+
+        let report = crate::copy_into_many_report(&mut input, vec![&mut failing]).unwrap();
+
+        assert!(report.succeeded().is_empty());
+        assert_eq!(report.failed().len(), 1);
+        assert_eq!(report.bytes_copied(), 13);
+
+        let err = report.into_result().unwrap_err();
+        assert_eq!(err.to_string(), "1 of 1 writers failed: [0: broken pipe]");
+    }
+
+    #[test]
+    fn copy_into_many_report_into_result() {
+        let mut first = Vec::<u8>::new();
+        let mut second = Vec::<u8>::new();
+
+        let report =
+            crate::copy_into_many_report(&mut &b"Hello, world!"[..], vec![&mut first, &mut second])
+                .unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(report.into_result().unwrap(), 13);
+    }
+
+    #[test]
+    fn copy_into_many_report_read_error() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::ConnectionReset))
+            }
+        }
+
+        let mut output = Vec::<u8>::new();
+        let mut reader = (&b"Hello, world!"[..]).chain(FailingReader);
+
+        let err = crate::copy_into_many_report(&mut reader, vec![&mut output]).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+        assert_eq!(output, b"Hello, world!");
+    }
+
+    #[test]
+    fn copy_into_many_buffered_source() {
+        let input: Vec<u8> = (0..=255)
+            .cycle()
+            .take(3 * crate::DEFAULT_BUF_SIZE)
+            .collect();
+        let mut streamed = [Vec::<u8>::new(), Vec::new()];
+        let mut buffered = [Vec::<u8>::new(), Vec::new()];
+
+        let streamed_copied = crate::copy_into_many(
+            &mut &input[..],
+            streamed.iter_mut().map(|w| w as &mut dyn Write).collect(),
+        )
+        .unwrap();
+        let buffered_copied = crate::copy_into_many_buffered_source(
+            &mut &input[..],
+            buffered.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(buffered_copied, streamed_copied);
+        assert_eq!(buffered, streamed);
+    }
+
+    #[test]
+    fn copy_into_many_buffered_source_cap() {
+        let mut writer = SharedWriter::new();
+
+        let copied = crate::copy_into_many_buffered_source(
+            &mut &b"Hello, world!"[..],
+            vec![&mut writer],
+            Some(13),
+        )
+        .unwrap();
+        assert_eq!(copied, 13);
+        assert_eq!(writer.write_calls(), 1);
+
+        let err = crate::copy_into_many_buffered_source(
+            &mut &b"Hello, world!"[..],
+            vec![&mut writer],
+            Some(12),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        assert_eq!(err.to_string(), "source exceeds the maximum of 12 bytes");
+        assert_eq!(writer.contents(), b"Hello, world!");
+    }
+
+    #[test]
+    fn copy_into_many_hinted() {
+        let input: Vec<u8> = (0..=255)
+            .cycle()
+            .take(2 * crate::DEFAULT_BUF_SIZE + 7)
+            .collect();
+
+        for size_hint in [
+            None,
+            Some(0),
+            Some(10),
+            Some(input.len() as u64),
+            Some(u64::MAX),
+        ] {
+            let mut output = Vec::<u8>::new();
+            let copied =
+                crate::copy_into_many_hinted(&mut &input[..], vec![&mut output], size_hint)
+                    .unwrap();
+            assert_eq!(copied, input.len() as u64);
+            assert_eq!(output, input);
+
+            let mut output = Vec::<u8>::new();
+            let copied = crate::copy_into_many_buffered_source_hinted(
+                &mut &input[..],
+                vec![&mut output],
+                Some(input.len() as u64),
+                size_hint,
+            )
+            .unwrap();
+            assert_eq!(copied, input.len() as u64);
+            assert_eq!(output, input);
+        }
+    }
+
+    #[test]
+    fn copy_into_many_macro() {
+        let input = b"Hello, world!";
+        let mut writers = vec![Vec::<u8>::new(), Vec::new(), Vec::new()];
+
+        crate::copy_into_many!(&mut &input[..], writers).unwrap();
+
+        for writer in writers {
+            assert_eq!(writer[..], *b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn copy_into_all_macro() {
+        let input = b"Hello, world!";
+        let mut writer1 = Vec::new();
+        let mut writer2 = Cursor::new(Vec::new());
+        let mut writer3 = VecDeque::new();
+
+        crate::copy_into_all!(&mut &input[..], writer1, writer2, writer3).unwrap();
+
+        assert_eq!(writer1, *b"Hello, world!");
+        assert_eq!(writer2.into_inner(), *b"Hello, world!");
+        assert_eq!(writer3, *b"Hello, world!");
+    }
+
+    #[test]
+    fn copy_into_all_counted_macro() {
+        let input = b"Hello, world!";
+        let mut writer1 = Vec::new();
+        let mut writer2 = Cursor::new(Vec::new());
+        let mut writer3 = VecDeque::new();
+
+        let counts =
+            crate::copy_into_all_counted!(&mut &input[..], writer1, writer2, writer3).unwrap();
 
+        assert_eq!(counts, [13, 13, 13]);
         assert_eq!(writer1, *b"Hello, world!");
         assert_eq!(writer2.into_inner(), *b"Hello, world!");
         assert_eq!(writer3, *b"Hello, world!");
@@ -0,0 +1,210 @@
+use std::io::{self, Write};
+
+use crate::Sink;
+
+/// The line ending a [`NewlineWriter`] converts line endings to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NewlineMode {
+    /// `\n`, as used on Unix.
+    #[default]
+    Lf,
+    /// `\r\n`, as used on Windows.
+    CrLf,
+    /// The line ending of the target platform: [`CrLf`](NewlineMode::CrLf) on Windows, [`Lf`](NewlineMode::Lf) elsewhere.
+    Native,
+}
+
+impl NewlineMode {
+    /// The bytes of the line ending.
+    pub fn as_bytes(self) -> &'static [u8] {
+        match self {
+            NewlineMode::Lf => b"\n",
+            NewlineMode::CrLf => b"\r\n",
+            NewlineMode::Native if cfg!(windows) => b"\r\n",
+            NewlineMode::Native => b"\n",
+        }
+    }
+}
+
+/// Wraps a writer, converting every line ending, `\n` or `\r\n`, to the one given by its [`NewlineMode`].
+///
+/// A `\r` not followed by `\n` is forwarded as-is.
+/// Placing one `NewlineWriter` per destination inside a [`MultiWriter`](crate::MultiWriter)
+/// sends the same text with different line endings to each.
+///
+/// A `\r` ending a write may start a `\r\n` completed by the following write, so it stays buffered until then,
+/// even when the `NewlineWriter` is flushed.
+/// It is forwarded by [`finish`](NewlineWriter::finish), or when the `NewlineWriter` is dropped, ignoring any errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{MultiWriter, NewlineMode, NewlineWriter};
+///
+/// let mut windows = NewlineWriter::new(Vec::new(), NewlineMode::CrLf);
+/// let mut unix = NewlineWriter::new(Vec::new(), NewlineMode::Lf);
+/// let mut multi_writer = MultiWriter::new(vec![&mut windows, &mut unix]);
+/// multi_writer.write_all(b"one\r\ntwo\n")?;
+///
+/// assert_eq!(windows.get_ref(), b"one\r\ntwo\r\n");
+/// assert_eq!(unix.get_ref(), b"one\ntwo\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct NewlineWriter<W: Write> {
+    writer: W,
+    mode: NewlineMode,
+    pending_cr: bool,
+}
+
+impl<W: Write> NewlineWriter<W> {
+    pub fn new(writer: W, mode: NewlineMode) -> Self {
+        let pending_cr = false;
+
+        Self {
+            writer,
+            mode,
+            pending_cr,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Forward a buffered trailing `\r`, which can no longer start a `\r\n`.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.pending_cr {
+            self.pending_cr = false;
+            self.writer.write_all(b"\r")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for NewlineWriter<W> {
+    /// Convert the line endings of `buf` and write the result to the internal writer.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let newline = self.mode.as_bytes();
+        let mut converted = Vec::with_capacity(buf.len() + buf.len() / 8);
+
+        for &b in buf {
+            if std::mem::take(&mut self.pending_cr) {
+                if b == b'\n' {
+                    converted.extend_from_slice(newline);
+                    continue;
+                }
+
+                converted.push(b'\r');
+            }
+
+            match b {
+                b'\r' => self.pending_cr = true,
+                b'\n' => converted.extend_from_slice(newline),
+                _ => converted.push(b),
+            }
+        }
+
+        self.writer.write_all(&converted)?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush the internal writer. A trailing `\r` stays buffered.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Sink for NewlineWriter<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Forward a buffered trailing `\r`, then flush the internal writer.
+    fn finish(mut self) -> io::Result<()> {
+        NewlineWriter::finish(&mut self)?;
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for NewlineWriter<W> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{NewlineMode, NewlineWriter};
+
+    fn convert(chunks: &[&[u8]], mode: NewlineMode) -> Vec<u8> {
+        let mut output = Vec::new();
+        let mut newline_writer = NewlineWriter::new(&mut output, mode);
+
+        for chunk in chunks {
+            newline_writer.write_all(chunk).unwrap();
+        }
+
+        newline_writer.finish().unwrap();
+        drop(newline_writer);
+        output
+    }
+
+    #[test]
+    fn newline_writer_lf_to_crlf() {
+        assert_eq!(
+            convert(&[b"one\ntwo\r\nthree\n"], NewlineMode::CrLf),
+            b"one\r\ntwo\r\nthree\r\n"
+        );
+    }
+
+    #[test]
+    fn newline_writer_crlf_to_lf() {
+        assert_eq!(
+            convert(&[b"one\r\ntwo\nthree\r\n"], NewlineMode::Lf),
+            b"one\ntwo\nthree\n"
+        );
+    }
+
+    #[test]
+    fn newline_writer_split_across_writes() {
+        let chunks: &[&[u8]] = &[b"one\r", b"\ntwo\r", b"", b"\n", b"three\r", b"four\r"];
+
+        assert_eq!(convert(chunks, NewlineMode::Lf), b"one\ntwo\nthree\rfour\r");
+        assert_eq!(
+            convert(chunks, NewlineMode::CrLf),
+            b"one\r\ntwo\r\nthree\rfour\r"
+        );
+    }
+
+    #[test]
+    fn newline_writer_holds_trailing_cr() {
+        let mut output = Vec::new();
+        let mut newline_writer = NewlineWriter::new(&mut output, NewlineMode::Lf);
+
+        newline_writer.write_all(b"done\r").unwrap();
+        newline_writer.flush().unwrap();
+        assert_eq!(newline_writer.get_ref().as_slice(), b"done");
+
+        drop(newline_writer);
+        assert_eq!(output, b"done\r");
+    }
+
+    #[test]
+    fn newline_mode_native() {
+        let expected: &[u8] = if cfg!(windows) { b"\r\n" } else { b"\n" };
+        assert_eq!(NewlineMode::Native.as_bytes(), expected);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    io::{self, Write},
+    thread,
+};
+
+use crate::MultiError;
+
+/// Provides a single [`Writer`](Write) that writes to multiple owned writers in parallel.
+///
+/// Each call to [`write`](ParallelMultiWriter::write) calls [`write_all`](Write::write_all) on every internal writer
+/// on a separate [scoped thread](thread::scope) and waits for all of them to finish,
+/// so the latency of a write is roughly that of the slowest writer, rather than the sum of all of them.
+/// This pays off for slow writers such as network sockets,
+/// but spawning threads on every call is far more expensive than writing sequentially to fast writers like files or buffers.
+///
+/// As the writers are moved to other threads, they are owned and must be [`Send`].
+///
+/// # Errors
+///
+/// Every writer is attempted, even if some of them fail.
+/// If any writer fails, an error wrapping a [`MultiError`] with the index and error of each failing writer is returned.
+/// The errors are always ordered by writer index, regardless of the order in which the threads finished,
+/// so the reported errors are reproducible.
+pub struct ParallelMultiWriter<W: Write + Send> {
+    writers: Vec<W>,
+}
+
+impl<W: Write + Send> ParallelMultiWriter<W> {
+    pub fn new(writers: Vec<W>) -> Self {
+        Self { writers }
+    }
+
+    /// Consumes the `ParallelMultiWriter`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// Apply `op` to each internal writer on its own thread, collecting the errors of the failing writers in index order.
+    fn fan_out(&mut self, op: impl Fn(&mut W) -> io::Result<()> + Sync) -> io::Result<()> {
+        let errors: Vec<_> = thread::scope(|s| {
+            let handles: Vec<_> = self
+                .writers
+                .iter_mut()
+                .map(|writer| s.spawn(|| op(writer)))
+                .collect();
+
+            // Joining the threads in the order they were spawned yields the results in index order.
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .enumerate()
+                .filter_map(|(index, result)| result.err().map(|e| (index, e)))
+                .collect()
+        });
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(MultiError::new(self.writers.len(), errors).into_io_error())
+        }
+    }
+}
+
+impl<W: Write + Send> Write for ParallelMultiWriter<W> {
+    /// Write a buffer into each internal writer in parallel.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`). See [`ParallelMultiWriter`] for more information.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.fan_out(|writer| writer.write_all(buf))?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush each internal writer in parallel.
+    ///
+    /// Like for [`write`](ParallelMultiWriter::write), errors are reported in writer-index order rather than completion order.
+    fn flush(&mut self) -> io::Result<()> {
+        self.fan_out(|writer| writer.flush())
+    }
+
+    /// Calls [`write`](ParallelMultiWriter::write) and discards the returned `usize`.
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let _ = self.write(buf)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use crate::{MultiError, ParallelMultiWriter};
+
+    /// Sleeps before every write, simulating a slow network socket.
+    struct DelayedWriter {
+        buf: Vec<u8>,
+        delay: Duration,
+        fail: bool,
+    }
+
+    impl DelayedWriter {
+        fn new(delay: Duration) -> Self {
+            let buf = Vec::new();
+            let fail = false;
+
+            Self { buf, delay, fail }
+        }
+    }
+
+    impl Write for DelayedWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            thread::sleep(self.delay);
+
+            if self.fail {
+                return Err(io::Error::from(io::ErrorKind::ConnectionReset));
+            }
+
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            thread::sleep(self.delay);
+
+            if self.fail {
+                return Err(io::Error::from(io::ErrorKind::ConnectionReset));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parallel_multi_writer() {
+        let delay = Duration::from_millis(200);
+        let mut multi_writer =
+            ParallelMultiWriter::new((0..4).map(|_| DelayedWriter::new(delay)).collect());
+
+        let start = Instant::now();
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        let elapsed = start.elapsed();
+
+        // Sequential writes would take at least 4 * 200 ms.
+        assert!(elapsed >= delay);
+        assert!(elapsed < 3 * delay, "took {elapsed:?}");
+
+        for writer in multi_writer.into_inner() {
+            assert_eq!(writer.buf, b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn parallel_multi_writer_collects_errors() {
+        let mut writers: Vec<_> = (0..4).map(|_| DelayedWriter::new(Duration::ZERO)).collect();
+        writers[1].fail = true;
+        writers[3].fail = true;
+        let mut multi_writer = ParallelMultiWriter::new(writers);
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        let multi_write_errors = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiError>())
+            .unwrap();
+        let indices: Vec<_> = multi_write_errors
+            .errors()
+            .iter()
+            .map(|(i, _)| *i)
+            .collect();
+        assert_eq!(indices, [1, 3]);
+
+        let writers = multi_writer.into_inner();
+        assert_eq!(writers[0].buf, b"Hello, world!");
+        assert_eq!(writers[2].buf, b"Hello, world!");
+    }
+
+    #[test]
+    fn parallel_multi_writer_flush_reports_errors_in_index_order() {
+        // Higher indices fail sooner, so the threads complete in reverse index order.
+        let mut writers: Vec<_> = (0..5u64)
+            .map(|i| DelayedWriter::new(Duration::from_millis(40 * (5 - i))))
+            .collect();
+        for index in [0, 2, 3] {
+            writers[index].fail = true;
+        }
+        let mut multi_writer = ParallelMultiWriter::new(writers);
+
+        let err = multi_writer.flush().unwrap_err();
+        let multi_write_errors = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiError>())
+            .unwrap();
+        let indices: Vec<_> = multi_write_errors
+            .errors()
+            .iter()
+            .map(|(i, _)| *i)
+            .collect();
+        assert_eq!(indices, [0, 2, 3]);
+        assert_eq!(
+            err.to_string(),
+            "3 of 5 writers failed: [0: connection reset, 2: connection reset, 3: connection reset]"
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::io::{self, Write};
+
+/// Wraps a writer, inserting a prefix (e.g. a timestamp or a tag) at the start of every line.
+///
+/// `prefix_fn` is called once per line to produce its prefix, right before the first byte of the line is forwarded.
+/// Prefixes are therefore never inserted in the middle of a line spanning multiple writes,
+/// and no prefix is emitted after the final newline until more bytes are written.
+///
+/// All writes to the internal writer use [`write_all`](Write::write_all).
+/// Placing a `PrefixWriter` inside a [`MultiWriter`](crate::MultiWriter) prefixes the lines of only that destination.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::PrefixWriter;
+///
+/// let mut prefix_writer = PrefixWriter::new(Vec::new(), || b"[app] ".to_vec());
+/// prefix_writer.write_all(b"Hello,\nwor")?;
+/// prefix_writer.write_all(b"ld!\n")?;
+///
+/// assert_eq!(prefix_writer.into_inner(), b"[app] Hello,\n[app] world!\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct PrefixWriter<W: Write, F: FnMut() -> Vec<u8>> {
+    writer: W,
+    prefix_fn: F,
+    at_line_start: bool,
+}
+
+impl<W: Write, F: FnMut() -> Vec<u8>> PrefixWriter<W, F> {
+    pub fn new(writer: W, prefix_fn: F) -> Self {
+        let at_line_start = true;
+
+        Self {
+            writer,
+            prefix_fn,
+            at_line_start,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `PrefixWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write, F: FnMut() -> Vec<u8>> Write for PrefixWriter<W, F> {
+    /// Write `buf`, inserting a prefix before each line it starts.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut rest = buf;
+
+        while !rest.is_empty() {
+            if self.at_line_start {
+                self.writer.write_all(&(self.prefix_fn)())?;
+                self.at_line_start = false;
+            }
+
+            let end = match rest.iter().position(|&b| b == b'\n') {
+                Some(pos) => {
+                    self.at_line_start = true;
+                    pos + 1
+                }
+                None => rest.len(),
+            };
+
+            self.writer.write_all(&rest[..end])?;
+            rest = &rest[end..];
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{MultiWriter, PrefixWriter};
+
+    #[test]
+    fn prefix_writer_split_lines() {
+        let mut line = 0;
+        let mut prefix_writer = PrefixWriter::new(Vec::new(), || {
+            line += 1;
+            format!("{line}: ").into_bytes()
+        });
+
+        prefix_writer.write_all(b"Hel").unwrap();
+        prefix_writer.write_all(b"lo,").unwrap();
+        prefix_writer.write_all(b" world!\n\nHello").unwrap();
+        prefix_writer.write_all(b"\n").unwrap();
+
+        assert_eq!(
+            prefix_writer.into_inner(),
+            b"1: Hello, world!\n2: \n3: Hello\n"
+        );
+    }
+
+    #[test]
+    fn prefix_writer_in_multi_writer() {
+        let mut raw = Vec::<u8>::new();
+        let mut prefixed = PrefixWriter::new(Vec::new(), || b"> ".to_vec());
+        let mut multi_writer = MultiWriter::new(vec![&mut raw, &mut prefixed]);
+
+        multi_writer.write_all(b"one\ntwo\n").unwrap();
+
+        assert_eq!(raw, b"one\ntwo\n");
+        assert_eq!(prefixed.into_inner(), b"> one\n> two\n");
+    }
+}
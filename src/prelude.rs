@@ -0,0 +1,16 @@
+//! Re-exports of the most commonly used types, traits, copy helpers and macros.
+//!
+//! ```
+//! use streams::prelude::*;
+//! ```
+
+pub use crate::{
+    copy_into_all, copy_into_all_counted, copy_into_many, copy_into_many_boxed,
+    copy_into_many_buffered, copy_into_many_buffered_source, copy_into_many_buffered_source_hinted,
+    copy_into_many_cancellable, copy_into_many_counted, copy_into_many_flush,
+    copy_into_many_hinted, copy_into_many_report, copy_into_many_streaming,
+    copy_into_many_with_progress, copy_into_sinks, copy_lines_into_many, copy_many_into,
+    BufferedMultiWriter, CountingWriter, DistributionStrategy, ErrorPolicy, LimitWriter,
+    MultiError, MultiReader, MultiWriteError, MultiWriteReport, MultiWriter, MultiWriterBuilder,
+    RoundRobinCopier, Sink, TeeReader,
+};
@@ -0,0 +1,182 @@
+use std::io::{self, Write};
+
+use crate::MultiWriteError;
+
+/// Provides a single [`Writer`](Write) that writes to multiple writers in order of descending priority.
+///
+/// The writers are ordered once, when the `PriorityMultiWriter` is created; writers of equal priority keep their relative order.
+/// Every write and flush then proceeds from the highest-priority writer to the lowest,
+/// so the most important destinations receive data first.
+///
+/// # Errors
+///
+/// If any of the internal writers fail, execution will immediately halt and the error will be returned,
+/// as with [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast).
+/// As the writers with a higher priority have already been written to by then,
+/// a failing low-priority writer never keeps data from a high-priority one.
+/// The error wraps a [`MultiWriteError`] reporting the index of the failing writer in the `Vec` passed to [`new`](PriorityMultiWriter::new).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::PriorityMultiWriter;
+///
+/// let mut full: &mut [u8] = &mut [];
+/// let mut important = Vec::new();
+/// let mut multi_writer = PriorityMultiWriter::new(vec![(&mut full as &mut dyn Write, 0), (&mut important, 10)]);
+///
+/// assert!(multi_writer.write_all(b"Hello, world!").is_err());
+///
+/// assert_eq!(important, b"Hello, world!");
+/// ```
+pub struct PriorityMultiWriter<'a> {
+    writers: Vec<(usize, i32, &'a mut dyn Write)>,
+}
+
+impl<'a> PriorityMultiWriter<'a> {
+    pub fn new(writers_with_priority: Vec<(&'a mut dyn Write, i32)>) -> Self {
+        let mut writers: Vec<_> = writers_with_priority
+            .into_iter()
+            .enumerate()
+            .map(|(index, (writer, priority))| (index, priority, writer))
+            .collect();
+        // A stable sort keeps writers of equal priority in their original order.
+        writers.sort_by_key(|&(_, priority, _)| std::cmp::Reverse(priority));
+
+        Self { writers }
+    }
+
+    /// The indices of the internal writers in the order they are written to.
+    pub fn order(&self) -> Vec<usize> {
+        self.writers.iter().map(|&(index, _, _)| index).collect()
+    }
+
+    /// The number of internal writers.
+    pub fn len(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns `true` if there are no internal writers.
+    pub fn is_empty(&self) -> bool {
+        self.writers.is_empty()
+    }
+
+    /// Consumes the `PriorityMultiWriter`, returning the internal writers and their priorities in their original order.
+    pub fn into_inner(mut self) -> Vec<(&'a mut dyn Write, i32)> {
+        self.writers.sort_by_key(|&(index, _, _)| index);
+
+        self.writers
+            .into_iter()
+            .map(|(_, priority, writer)| (writer, priority))
+            .collect()
+    }
+}
+
+impl<'a> Write for PriorityMultiWriter<'a> {
+    /// Write a buffer into each internal writer sequentially, in order of descending priority.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for (index, _, writer) in &mut self.writers {
+            writer
+                .write_all(buf)
+                .map_err(|e| MultiWriteError::new(*index, e).into_io_error())?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for (index, _, writer) in &mut self.writers {
+            writer
+                .flush()
+                .map_err(|e| MultiWriteError::new(*index, e).into_io_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{
+        mocks::{FailingWriter, SharedWriter},
+        MultiWriteError, PriorityMultiWriter,
+    };
+
+    #[test]
+    fn priority_multi_writer_failing_low_priority() {
+        let mut low = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut medium = Vec::<u8>::new();
+        let mut high = Vec::<u8>::new();
+        let mut multi_writer = PriorityMultiWriter::new(vec![
+            (&mut low as &mut dyn Write, -5),
+            (&mut medium, 0),
+            (&mut high, 10),
+        ]);
+        assert_eq!(multi_writer.order(), [2, 1, 0]);
+
+        let err = multi_writer.write_all(b"Hello, world!").unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 0);
+
+        assert_eq!(high, b"Hello, world!");
+        assert_eq!(medium, b"Hello, world!");
+    }
+
+    #[test]
+    fn priority_multi_writer_order() {
+        /// Records its own tag into a log shared by every writer instead of the written bytes.
+        struct TaggedWriter {
+            tag: u8,
+            log: SharedWriter,
+        }
+
+        impl Write for TaggedWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.log.write_all(&[self.tag])?;
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let log = SharedWriter::new();
+        let mut writers: Vec<_> = (b'0'..b'4')
+            .map(|tag| TaggedWriter {
+                tag,
+                log: log.clone(),
+            })
+            .collect();
+        let priorities = [1, 3, 1, 2];
+        let mut multi_writer = PriorityMultiWriter::new(
+            writers
+                .iter_mut()
+                .zip(priorities)
+                .map(|(w, p)| (w as &mut dyn Write, p))
+                .collect(),
+        );
+
+        // Writers of equal priority keep their original order.
+        assert_eq!(multi_writer.order(), [1, 3, 0, 2]);
+        multi_writer.write_all(b"x").unwrap();
+        assert_eq!(log.contents(), b"1302");
+        multi_writer.write_all(b"x").unwrap();
+        assert_eq!(log.contents(), b"13021302");
+
+        let priorities: Vec<_> = multi_writer
+            .into_inner()
+            .into_iter()
+            .map(|(_, p)| p)
+            .collect();
+        assert_eq!(priorities, [1, 3, 1, 2]);
+    }
+}
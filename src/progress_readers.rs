@@ -0,0 +1,120 @@
+use std::io::{self, Read};
+
+/// Wraps a reader, calling `on_progress` with the cumulative number of bytes read after each successful read.
+///
+/// This reports progress on the source side of a copy, e.g. when feeding [`copy_into_many`](fn@crate::copy_into_many),
+/// complementing [`copy_into_many_with_progress`](crate::copy_into_many_with_progress) on the destination side.
+/// Reads returning `Ok(0)`, such as at end of file, and failed reads don't call `on_progress`.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Write};
+/// use streams::ProgressReader;
+///
+/// let mut progress = Vec::new();
+/// let mut reader = ProgressReader::new(&b"Hello, world!"[..], |read| progress.push(read));
+/// let mut output = Vec::new();
+/// streams::copy_into_many(&mut reader, vec![&mut output])?;
+///
+/// assert_eq!(progress, [13]);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct ProgressReader<R: Read, F: FnMut(u64)> {
+    reader: R,
+    on_progress: F,
+    bytes_read: u64,
+}
+
+impl<R: Read, F: FnMut(u64)> ProgressReader<R, F> {
+    pub fn new(reader: R, on_progress: F) -> Self {
+        let bytes_read = 0;
+
+        Self {
+            reader,
+            on_progress,
+            bytes_read,
+        }
+    }
+
+    /// The number of bytes read so far.
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Get a reference to the internal reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes the `ProgressReader`, returning the internal reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, F: FnMut(u64)> Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.reader.read(buf)?;
+
+        if n > 0 {
+            self.bytes_read += n as u64;
+            (self.on_progress)(self.bytes_read);
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use crate::{MultiReader, ProgressReader};
+
+    #[test]
+    fn progress_reader() {
+        let mut part1 = &b"Hello, "[..];
+        let mut part2 = &b"world!"[..];
+        let mut progress = Vec::new();
+        let mut reader =
+            ProgressReader::new(MultiReader::new(vec![&mut part1, &mut part2]), |read| {
+                progress.push(read)
+            });
+
+        let mut buf = [0; 4];
+        let mut output = Vec::new();
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            output.extend_from_slice(&buf[..n]);
+        }
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+        assert_eq!(reader.bytes_read(), 13);
+
+        assert_eq!(output, b"Hello, world!");
+        assert!(progress.windows(2).all(|w| w[0] < w[1]));
+        assert!(progress.len() >= 4);
+        assert_eq!(progress.last(), Some(&13));
+    }
+
+    #[test]
+    fn progress_reader_error() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::ConnectionReset))
+            }
+        }
+
+        let mut calls = 0;
+        let mut reader = ProgressReader::new(FailingReader, |_| calls += 1);
+
+        let err = reader.read(&mut [0; 4]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+        assert_eq!(calls, 0);
+    }
+}
@@ -0,0 +1,92 @@
+use std::io::{self, Read, Write};
+
+use crate::{RandomStrategy, StrategyCopier};
+
+/// Copies each reader into an internal writer picked uniformly at random,
+/// a [`StrategyCopier`] using a [`RandomStrategy`].
+///
+/// Every writer has the same probability of being picked for each copy, independently of previous copies,
+/// so unlike [`RoundRobinCopier`](crate::RoundRobinCopier) the distribution is only even on average.
+/// With a single writer, it is always picked.
+///
+/// The pseudorandom generator is not cryptographically secure.
+/// Use [`new_seeded`](RandomCopier::new_seeded) for a reproducible sequence of picks.
+///
+/// # Examples
+///
+/// ```
+/// use streams::RandomCopier;
+///
+/// let mut copier = RandomCopier::new_seeded(vec![Vec::new(), Vec::new(), Vec::new()], 42);
+/// for _ in 0..100 {
+///     copier.copy(&mut &b"sample"[..])?;
+/// }
+///
+/// let total: usize = copier.into_inner().iter().map(|w| w.len()).sum();
+/// assert_eq!(total, 600);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct RandomCopier<W: Write> {
+    copier: StrategyCopier<W, RandomStrategy>,
+}
+
+impl<W: Write> RandomCopier<W> {
+    /// Create a `RandomCopier` with a randomly chosen seed.
+    pub fn new(writers: Vec<W>) -> Self {
+        let copier = StrategyCopier::new(writers, RandomStrategy::new());
+
+        Self { copier }
+    }
+
+    /// Create a `RandomCopier` that picks the same sequence of writers for the same seed.
+    pub fn new_seeded(writers: Vec<W>, seed: u64) -> Self {
+        let copier = StrategyCopier::new(writers, RandomStrategy::new_seeded(seed));
+
+        Self { copier }
+    }
+
+    /// Consumes the `RandomCopier`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.copier.into_inner()
+    }
+
+    /// Copy the entire contents of `reader` into a randomly picked writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// Errors during the copy are returned as-is.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        self.copier.copy(reader)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RandomCopier;
+
+    #[test]
+    fn random_copier_seeded() {
+        let mut copier =
+            RandomCopier::new_seeded(vec![Vec::<u8>::new(), Vec::new(), Vec::new()], 7);
+
+        for input in [b"0", b"1", b"2", b"3", b"4", b"5", b"6", b"7"] {
+            copier.copy(&mut &input[..]).unwrap();
+        }
+
+        // The seed 7 picks the writers 1, 0, 2, 1, 1, 0, 1 and 0.
+        let writers = copier.into_inner();
+        assert_eq!(writers, [&b"157"[..], b"0346", b"2"]);
+    }
+
+    #[test]
+    fn random_copier_single_writer() {
+        let mut copier = RandomCopier::new(vec![Vec::<u8>::new()]);
+
+        for _ in 0..10 {
+            copier.copy(&mut &b"a"[..]).unwrap();
+        }
+
+        assert_eq!(copier.into_inner(), [b"aaaaaaaaaa"]);
+    }
+}
@@ -0,0 +1,135 @@
+use std::io::{self, Read, Write};
+
+/// Scatters a reader across its internal writers in caller-specified lengths:
+/// the first writer receives the first range of bytes, the second writer the range right after it, and so on,
+/// e.g. to split a download of known size among storage shards.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::RangeScatterCopier;
+///
+/// let mut head = Vec::new();
+/// let mut tail = Vec::new();
+/// let mut copier = RangeScatterCopier::new(vec![(&mut head as &mut dyn Write, 5), (&mut tail, 8)]);
+///
+/// copier.copy_all(&mut &b"Hello, world!"[..])?;
+///
+/// assert_eq!(head, b"Hello");
+/// assert_eq!(tail, b", world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct RangeScatterCopier<'a> {
+    writers: Vec<(&'a mut dyn Write, u64)>,
+}
+
+impl<'a> RangeScatterCopier<'a> {
+    pub fn new(writers_with_lengths: Vec<(&'a mut dyn Write, u64)>) -> Self {
+        Self {
+            writers: writers_with_lengths,
+        }
+    }
+
+    /// The total number of bytes scattered by [`copy_all`](RangeScatterCopier::copy_all), the sum of all lengths.
+    pub fn total_len(&self) -> u64 {
+        self.writers.iter().map(|&(_, len)| len).sum()
+    }
+
+    /// Copy exactly [`total_len`](RangeScatterCopier::total_len) bytes of `reader`,
+    /// writing the range of each writer into it in order.
+    ///
+    /// Bytes of `reader` beyond the last range are left unread.
+    /// Returns the total number of bytes copied.
+    ///
+    /// # Errors
+    ///
+    /// If `reader` ends before every range is filled, an error of kind [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) is returned,
+    /// with the writers up to the one whose range was cut short holding the bytes read.
+    /// Other errors during the copy are returned as-is.
+    pub fn copy_all<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        let mut copied = 0;
+
+        for (index, (writer, len)) in self.writers.iter_mut().enumerate() {
+            let n = io::copy(&mut reader.take(*len), writer)?;
+            copied += n;
+
+            if n < *len {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!("source ended after {copied} bytes, {n} bytes into the {len}-byte range of writer {index}"),
+                ));
+            }
+        }
+
+        Ok(copied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::RangeScatterCopier;
+
+    #[test]
+    fn range_scatter_copier() {
+        let input = b"0123456789abcdefghijKLMNOPQRST";
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier = RangeScatterCopier::new(
+            writers
+                .iter_mut()
+                .map(|w| (w as &mut dyn Write, 10))
+                .collect(),
+        );
+        assert_eq!(copier.total_len(), 30);
+
+        assert_eq!(copier.copy_all(&mut &input[..]).unwrap(), 30);
+
+        assert_eq!(writers[0], b"0123456789");
+        assert_eq!(writers[1], b"abcdefghij");
+        assert_eq!(writers[2], b"KLMNOPQRST");
+    }
+
+    #[test]
+    fn range_scatter_copier_leaves_excess_unread() {
+        let mut input = &b"Hello, world!"[..];
+        let mut first = Vec::<u8>::new();
+        let mut empty = Vec::<u8>::new();
+        let mut second = Vec::<u8>::new();
+        let mut copier = RangeScatterCopier::new(vec![
+            (&mut first as &mut dyn Write, 5),
+            (&mut empty, 0),
+            (&mut second, 2),
+        ]);
+
+        assert_eq!(copier.copy_all(&mut input).unwrap(), 7);
+        assert_eq!(input, b"world!");
+
+        assert_eq!(first, b"Hello");
+        assert!(empty.is_empty());
+        assert_eq!(second, b", ");
+    }
+
+    #[test]
+    fn range_scatter_copier_short_source() {
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier = RangeScatterCopier::new(
+            writers
+                .iter_mut()
+                .map(|w| (w as &mut dyn Write, 10))
+                .collect(),
+        );
+
+        let err = copier.copy_all(&mut &[0u8; 15][..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            err.to_string(),
+            "source ended after 15 bytes, 5 bytes into the 10-byte range of writer 1"
+        );
+
+        assert_eq!(writers[0].len(), 10);
+        assert_eq!(writers[1].len(), 5);
+        assert!(writers[2].is_empty());
+    }
+}
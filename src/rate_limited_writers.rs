@@ -0,0 +1,141 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Wraps a writer, throttling the throughput to at most a fixed number of bytes per second.
+///
+/// Throttling uses a token bucket: the bucket fills up at a rate of `bytes_per_sec` tokens per second,
+/// up to its size, and every byte written consumes a token.
+/// A write sleeps until enough tokens are available, so the sustained throughput never exceeds the limit,
+/// while bursts of up to the bucket size are allowed after idle periods.
+/// The bucket starts out empty.
+///
+/// Each call to [`write`](RateLimitedWriter::write) writes at most the bucket size,
+/// so a larger write is completed by [`write_all`](Write::write_all) over several calls, at the throttled rate.
+///
+/// Placing a `RateLimitedWriter` inside a [`MultiWriter`](crate::MultiWriter) throttles that destination,
+/// and, as the fan-out is sequential, all of the others along with it.
+pub struct RateLimitedWriter<W: Write> {
+    writer: W,
+    bytes_per_sec: u64,
+    bucket_size: u64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl<W: Write> RateLimitedWriter<W> {
+    /// Create a `RateLimitedWriter` with a bucket size of `bytes_per_sec`, allowing bursts of up to one second's worth of bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` is zero.
+    pub fn new(writer: W, bytes_per_sec: u64) -> Self {
+        Self::with_bucket_size(writer, bytes_per_sec, bytes_per_sec)
+    }
+
+    /// Create a `RateLimitedWriter` allowing bursts of up to `bucket_size` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes_per_sec` or `bucket_size` is zero.
+    pub fn with_bucket_size(writer: W, bytes_per_sec: u64, bucket_size: u64) -> Self {
+        assert!(bytes_per_sec > 0, "bytes_per_sec must be greater than zero");
+        assert!(bucket_size > 0, "bucket_size must be greater than zero");
+
+        let tokens = 0.0;
+        let last_refill = Instant::now();
+
+        Self {
+            writer,
+            bytes_per_sec,
+            bucket_size,
+            tokens,
+            last_refill,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `RateLimitedWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Add the tokens accumulated since the last refill, up to the bucket size.
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+
+        self.tokens =
+            (self.tokens + elapsed * self.bytes_per_sec as f64).min(self.bucket_size as f64);
+        self.last_refill = now;
+    }
+}
+
+impl<W: Write> Write for RateLimitedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        let len = buf
+            .len()
+            .min(usize::try_from(self.bucket_size).unwrap_or(usize::MAX));
+
+        self.refill();
+        let missing = len as f64 - self.tokens;
+        if missing > 0.0 {
+            thread::sleep(Duration::from_secs_f64(missing / self.bytes_per_sec as f64));
+            self.refill();
+        }
+
+        let n = self.writer.write(&buf[..len])?;
+        self.tokens -= n as f64;
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        time::{Duration, Instant},
+    };
+
+    use crate::RateLimitedWriter;
+
+    #[test]
+    fn rate_limited_writer() {
+        const MIB: usize = 1024 * 1024;
+
+        let mut rate_limited_writer = RateLimitedWriter::new(Vec::new(), 2 * MIB as u64);
+        let input = vec![0xA5; 4 * MIB];
+
+        let start = Instant::now();
+        rate_limited_writer.write_all(&input).unwrap();
+        let elapsed = start.elapsed();
+
+        assert!(elapsed >= Duration::from_millis(1900), "took {elapsed:?}");
+        assert_eq!(rate_limited_writer.into_inner(), input);
+    }
+
+    #[test]
+    fn rate_limited_writer_limits_write_size() {
+        let mut rate_limited_writer = RateLimitedWriter::with_bucket_size(Vec::new(), 1000, 4);
+
+        assert_eq!(rate_limited_writer.write(b"Hello, world!").unwrap(), 4);
+        rate_limited_writer.write_all(b"Hello, world!").unwrap();
+
+        assert_eq!(rate_limited_writer.into_inner(), b"HellHello, world!");
+    }
+}
@@ -0,0 +1,198 @@
+use std::io::{self, Write};
+
+use crate::Sink;
+
+/// Wraps a writer, re-framing the stream into fixed-length records by inserting a delimiter after every `record_len` bytes.
+///
+/// The position within the current record is tracked across writes, so records may span any number of calls to [`write`](RecordFramingWriter::write).
+/// The delimiter follows every complete record, including the last one.
+/// A trailing partial record is forwarded without a delimiter,
+/// unless a padding byte was set with [`with_padding`](RecordFramingWriter::with_padding),
+/// in which case [`finish`](RecordFramingWriter::finish) pads it to a complete record.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::RecordFramingWriter;
+///
+/// let mut framing_writer = RecordFramingWriter::new(Vec::new(), 4, b"|".to_vec()).with_padding(b'.');
+/// framing_writer.write_all(b"abcdefghij")?;
+/// framing_writer.finish()?;
+///
+/// assert_eq!(framing_writer.into_inner(), b"abcd|efgh|ij..|");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct RecordFramingWriter<W: Write> {
+    writer: W,
+    record_len: usize,
+    delimiter: Vec<u8>,
+    pad: Option<u8>,
+    position: usize,
+}
+
+impl<W: Write> RecordFramingWriter<W> {
+    /// # Panics
+    ///
+    /// Panics if `record_len` is zero.
+    pub fn new(writer: W, record_len: usize, delimiter: Vec<u8>) -> Self {
+        assert!(record_len > 0, "record length must be greater than zero");
+        let pad = None;
+        let position = 0;
+
+        Self {
+            writer,
+            record_len,
+            delimiter,
+            pad,
+            position,
+        }
+    }
+
+    /// Complete a trailing partial record with copies of `pad` when finishing, see [`finish`](RecordFramingWriter::finish).
+    pub fn with_padding(mut self, pad: u8) -> Self {
+        self.pad = Some(pad);
+        self
+    }
+
+    /// The number of bytes of the current, incomplete record written so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `RecordFramingWriter`, returning the internal writer.
+    ///
+    /// A trailing partial record is left as-is, see [`finish`](RecordFramingWriter::finish).
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// End the current record.
+    ///
+    /// If a padding byte was set and the last record is incomplete, it is completed with copies of it, followed by the delimiter.
+    /// Otherwise, a trailing partial record stays without a delimiter.
+    /// Bytes written afterwards start a new record.
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(pad) = self.pad {
+            if self.position > 0 {
+                let mut padding = vec![pad; self.record_len - self.position];
+                padding.extend_from_slice(&self.delimiter);
+                self.writer.write_all(&padding)?;
+            }
+        }
+        self.position = 0;
+
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for RecordFramingWriter<W> {
+    /// Write `buf` to the internal writer, inserting the delimiter after every completed record.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut framed = Vec::with_capacity(
+            buf.len() + (self.position + buf.len()) / self.record_len * self.delimiter.len(),
+        );
+        let mut rest = buf;
+
+        while !rest.is_empty() {
+            let n = rest.len().min(self.record_len - self.position);
+            framed.extend_from_slice(&rest[..n]);
+            rest = &rest[n..];
+            self.position += n;
+
+            if self.position == self.record_len {
+                framed.extend_from_slice(&self.delimiter);
+                self.position = 0;
+            }
+        }
+
+        self.writer.write_all(&framed)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Sink for RecordFramingWriter<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// End the current record, then flush the internal writer.
+    fn finish(mut self) -> io::Result<()> {
+        RecordFramingWriter::finish(&mut self)?;
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::RecordFramingWriter;
+
+    #[test]
+    fn record_framing_writer() {
+        let input: Vec<u8> = (b'a'..).take(25).collect();
+        let mut framing_writer = RecordFramingWriter::new(Vec::new(), 10, b"\r\n".to_vec());
+
+        // Writes of 7 bytes never line up with the records.
+        for chunk in input.chunks(7) {
+            framing_writer.write_all(chunk).unwrap();
+        }
+        assert_eq!(framing_writer.position(), 5);
+
+        framing_writer.finish().unwrap();
+        assert_eq!(framing_writer.position(), 0);
+        assert_eq!(
+            framing_writer.into_inner(),
+            b"abcdefghij\r\nklmnopqrst\r\nuvwxy"
+        );
+    }
+
+    #[test]
+    fn record_framing_writer_padded() {
+        let input: Vec<u8> = (b'a'..).take(25).collect();
+        let mut framing_writer =
+            RecordFramingWriter::new(Vec::new(), 10, b"\n".to_vec()).with_padding(b' ');
+        framing_writer.write_all(&input).unwrap();
+        framing_writer.finish().unwrap();
+
+        // A record started after finishing is padded on its own.
+        framing_writer.write_all(b"z").unwrap();
+        framing_writer.finish().unwrap();
+
+        assert_eq!(
+            framing_writer.into_inner(),
+            b"abcdefghij\nklmnopqrst\nuvwxy     \nz         \n"
+        );
+    }
+
+    #[test]
+    fn record_framing_writer_exact_records() {
+        let mut framing_writer =
+            RecordFramingWriter::new(Vec::new(), 5, b"|".to_vec()).with_padding(b' ');
+        framing_writer.write_all(b"Hello").unwrap();
+        framing_writer.write_all(b"world").unwrap();
+        assert_eq!(framing_writer.position(), 0);
+
+        framing_writer.finish().unwrap();
+        assert_eq!(framing_writer.into_inner(), b"Hello|world|");
+    }
+
+    #[test]
+    #[should_panic(expected = "record length must be greater than zero")]
+    fn record_framing_writer_zero_record_len() {
+        RecordFramingWriter::new(Vec::new(), 0, b"\n".to_vec());
+    }
+}
@@ -0,0 +1,202 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// Wraps a writer, retrying writes and flushes that fail with a retryable error.
+///
+/// By default, errors of kind [`Interrupted`](io::ErrorKind::Interrupted) and [`WouldBlock`](io::ErrorKind::WouldBlock)
+/// are considered retryable; use [`with_predicate`](RetryWriter::with_predicate) to choose differently.
+/// Each call to [`write`](RetryWriter::write) or [`flush`](RetryWriter::flush) is retried up to `max_retries` times,
+/// optionally sleeping for a fixed backoff delay before each retry.
+/// Non-retryable errors, and the last error once the retries are exhausted, are returned immediately.
+///
+/// A successful write may still be partial, in which case only the remaining bytes are written by a following call,
+/// e.g. from [`write_all`](Write::write_all), which starts with a fresh budget of retries.
+///
+/// Placing a `RetryWriter` inside a [`MultiWriter`](crate::MultiWriter) prevents one flaky writer from aborting the whole fan-out.
+pub struct RetryWriter<W: Write, P = fn(&io::Error) -> bool> {
+    writer: W,
+    max_retries: u32,
+    backoff: Option<Duration>,
+    is_retryable: P,
+}
+
+/// The default predicate of a [`RetryWriter`], accepting [`Interrupted`](io::ErrorKind::Interrupted)
+/// and [`WouldBlock`](io::ErrorKind::WouldBlock) errors.
+pub fn is_transient(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::Interrupted | io::ErrorKind::WouldBlock
+    )
+}
+
+impl<W: Write> RetryWriter<W> {
+    pub fn new(writer: W, max_retries: u32) -> Self {
+        let backoff = None;
+
+        Self {
+            writer,
+            max_retries,
+            backoff,
+            is_retryable: is_transient,
+        }
+    }
+}
+
+impl<W: Write, P: FnMut(&io::Error) -> bool> RetryWriter<W, P> {
+    /// Sleep for `backoff` before each retry.
+    pub fn with_backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = Some(backoff);
+        self
+    }
+
+    /// Use `is_retryable` to decide which errors are retried.
+    pub fn with_predicate<Q: FnMut(&io::Error) -> bool>(
+        self,
+        is_retryable: Q,
+    ) -> RetryWriter<W, Q> {
+        RetryWriter {
+            writer: self.writer,
+            max_retries: self.max_retries,
+            backoff: self.backoff,
+            is_retryable,
+        }
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `RetryWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Call `op` on the internal writer, retrying it according to the configuration.
+    fn retry<T>(&mut self, mut op: impl FnMut(&mut W) -> io::Result<T>) -> io::Result<T> {
+        let mut retries = 0;
+
+        loop {
+            match op(&mut self.writer) {
+                Err(e) if retries < self.max_retries && (self.is_retryable)(&e) => {
+                    retries += 1;
+
+                    if let Some(backoff) = self.backoff {
+                        thread::sleep(backoff);
+                    }
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<W: Write, P: FnMut(&io::Error) -> bool> Write for RetryWriter<W, P> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.retry(|writer| writer.write(buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.retry(|writer| writer.flush())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        time::Duration,
+    };
+
+    use crate::RetryWriter;
+
+    /// Fails `failures` times with `kind`, then accepts at most `max_write` bytes per call.
+    struct FlakyWriter {
+        buf: Vec<u8>,
+        kind: io::ErrorKind,
+        failures: usize,
+        max_write: usize,
+        attempts: usize,
+    }
+
+    impl FlakyWriter {
+        fn new(kind: io::ErrorKind, failures: usize) -> Self {
+            Self {
+                buf: Vec::new(),
+                kind,
+                failures,
+                max_write: usize::MAX,
+                attempts: 0,
+            }
+        }
+    }
+
+    impl Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.attempts += 1;
+
+            if self.failures > 0 {
+                self.failures -= 1;
+                return Err(io::Error::from(self.kind));
+            }
+
+            let n = buf.len().min(self.max_write);
+            self.buf.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn retry_writer() {
+        let writer = FlakyWriter::new(io::ErrorKind::WouldBlock, 2);
+        let mut retry_writer = RetryWriter::new(writer, 3).with_backoff(Duration::from_millis(1));
+
+        assert_eq!(retry_writer.write(b"Hello, world!").unwrap(), 13);
+
+        let writer = retry_writer.into_inner();
+        assert_eq!(writer.attempts, 3);
+        assert_eq!(writer.buf, b"Hello, world!");
+    }
+
+    #[test]
+    fn retry_writer_exhausts_retries() {
+        let writer = FlakyWriter::new(io::ErrorKind::WouldBlock, 2);
+        let mut retry_writer = RetryWriter::new(writer, 1);
+
+        let err = retry_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+        assert_eq!(retry_writer.get_ref().attempts, 2);
+    }
+
+    #[test]
+    fn retry_writer_non_retryable() {
+        let writer = FlakyWriter::new(io::ErrorKind::PermissionDenied, 1);
+        let mut retry_writer = RetryWriter::new(writer, 3);
+
+        let err = retry_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(retry_writer.get_ref().attempts, 1);
+    }
+
+    #[test]
+    fn retry_writer_partial_writes() {
+        let mut writer = FlakyWriter::new(io::ErrorKind::ConnectionReset, 2);
+        writer.max_write = 5;
+        let mut retry_writer = RetryWriter::new(writer, 2)
+            .with_predicate(|e| e.kind() == io::ErrorKind::ConnectionReset);
+
+        retry_writer.write_all(b"Hello, world!").unwrap();
+
+        // Two failures, then three partial writes of the remaining bytes.
+        let writer = retry_writer.into_inner();
+        assert_eq!(writer.attempts, 5);
+        assert_eq!(writer.buf, b"Hello, world!");
+    }
+}
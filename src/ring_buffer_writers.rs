@@ -0,0 +1,126 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+};
+
+/// A writer retaining only the last `capacity` bytes written to it, overwriting the oldest bytes.
+///
+/// Placing a `RingBufferWriter` inside a [`MultiWriter`](crate::MultiWriter) alongside the real destinations
+/// keeps the recent history of a stream at hand, e.g. to dump the context leading up to an error.
+/// Every write succeeds in full.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::RingBufferWriter;
+///
+/// let mut ring_buffer_writer = RingBufferWriter::new(6);
+/// ring_buffer_writer.write_all(b"Hello, world!")?;
+///
+/// assert_eq!(ring_buffer_writer.contents(), b"world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingBufferWriter {
+    buf: VecDeque<u8>,
+    capacity: usize,
+}
+
+impl RingBufferWriter {
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be greater than zero");
+        let buf = VecDeque::with_capacity(capacity);
+
+        Self { buf, capacity }
+    }
+
+    /// The maximum number of bytes retained.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The number of bytes currently retained, at most [`capacity`](RingBufferWriter::capacity).
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if nothing has been retained.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// The retained bytes, from oldest to newest.
+    pub fn contents(&self) -> Vec<u8> {
+        self.buf.iter().copied().collect()
+    }
+
+    /// Discard the retained bytes.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+}
+
+impl Write for RingBufferWriter {
+    /// Append `buf`, discarding the oldest bytes beyond the capacity.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Only the tail of a buffer larger than the capacity can be retained.
+        let tail = &buf[buf.len().saturating_sub(self.capacity)..];
+
+        let overflow = (self.buf.len() + tail.len()).saturating_sub(self.capacity);
+        self.buf.drain(..overflow);
+        self.buf.extend(tail);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{MultiWriter, RingBufferWriter};
+
+    #[test]
+    fn ring_buffer_writer() {
+        let mut output = Vec::<u8>::new();
+        let mut ring_buffer_writer = RingBufferWriter::new(8);
+        let mut multi_writer = MultiWriter::new(vec![&mut output, &mut ring_buffer_writer]);
+
+        multi_writer.write_all(b"Hello").unwrap();
+        multi_writer.write_all(b", ").unwrap();
+        multi_writer.write_all(b"world!").unwrap();
+
+        assert_eq!(output, b"Hello, world!");
+        assert_eq!(ring_buffer_writer.len(), 8);
+        assert_eq!(ring_buffer_writer.contents(), b", world!");
+    }
+
+    #[test]
+    fn ring_buffer_writer_larger_write() {
+        let mut ring_buffer_writer = RingBufferWriter::new(4);
+        ring_buffer_writer.write_all(b"ab").unwrap();
+        assert_eq!(ring_buffer_writer.contents(), b"ab");
+
+        assert_eq!(ring_buffer_writer.write(b"Hello, world!").unwrap(), 13);
+        assert_eq!(ring_buffer_writer.contents(), b"rld!");
+
+        ring_buffer_writer.clear();
+        assert!(ring_buffer_writer.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity must be greater than zero")]
+    fn ring_buffer_writer_zero_capacity() {
+        RingBufferWriter::new(0);
+    }
+}
@@ -0,0 +1,159 @@
+use std::io::{self, Read, Write};
+
+/// Copies readers into a sequence of writers created on demand, starting a new writer every `max_bytes` bytes, like log rotation.
+///
+/// Writers are created by a factory closure, which is called with the index of the writer to create: 0 for the first, 1 for the next, and so on.
+/// A new writer is only created once there are bytes to write to it, so no empty writers are created,
+/// and copies are split across writers wherever necessary to keep every writer at `max_bytes` bytes or less.
+/// A writer is flushed and dropped when the copier moves on to the next one.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use streams::RollingFileCopier;
+///
+/// let mut copier = RollingFileCopier::new(|index| File::create(format!("app.log.{index}")), 1024 * 1024);
+/// copier.copy(&mut std::io::stdin().lock())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct RollingFileCopier<W: Write, F: FnMut(usize) -> io::Result<W>> {
+    factory: F,
+    max_bytes: u64,
+    writer: Option<W>,
+    next_index: usize,
+    current_bytes: u64,
+}
+
+impl<W: Write, F: FnMut(usize) -> io::Result<W>> RollingFileCopier<W, F> {
+    /// # Panics
+    ///
+    /// Panics if `max_bytes` is zero.
+    pub fn new(factory: F, max_bytes: u64) -> Self {
+        assert!(max_bytes > 0, "max bytes must be greater than zero");
+        let writer = None;
+        let next_index = 0;
+        let current_bytes = 0;
+
+        Self {
+            factory,
+            max_bytes,
+            writer,
+            next_index,
+            current_bytes,
+        }
+    }
+
+    /// The number of writers created so far.
+    pub fn writers_created(&self) -> usize {
+        self.next_index
+    }
+
+    /// The number of bytes the current writer has received.
+    pub fn current_bytes(&self) -> u64 {
+        self.current_bytes
+    }
+
+    /// Copy the entire contents of `reader`, rolling over to a new writer whenever the current one has received `max_bytes` bytes.
+    ///
+    /// Returns the total number of bytes copied.
+    ///
+    /// # Errors
+    ///
+    /// Errors from the factory, from flushing a full writer and from the copy are returned as-is, aborting the copy.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        let mut copied = 0;
+        let mut buf = [0; 1];
+
+        loop {
+            // Peek a single byte to avoid creating a writer for an exhausted reader.
+            let first = match reader.read(&mut buf) {
+                Ok(0) => return Ok(copied),
+                Ok(_) => buf[0],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            self.roll_over_if_full()?;
+            let writer = self.writer.as_mut().unwrap();
+            writer.write_all(&[first])?;
+
+            let remaining = self.max_bytes - self.current_bytes - 1;
+            let n = 1 + io::copy(&mut reader.take(remaining), writer)?;
+            self.current_bytes += n;
+            copied += n;
+        }
+    }
+
+    /// Consume the `RollingFileCopier`, returning the current writer, if one has been created.
+    pub fn into_inner(self) -> Option<W> {
+        self.writer
+    }
+
+    /// Ensure there is a current writer with room for at least one byte, rolling over to a new one if it is full.
+    fn roll_over_if_full(&mut self) -> io::Result<()> {
+        if self.current_bytes >= self.max_bytes {
+            if let Some(mut writer) = self.writer.take() {
+                writer.flush()?;
+            }
+        }
+
+        if self.writer.is_none() {
+            self.writer = Some((self.factory)(self.next_index)?);
+            self.next_index += 1;
+            self.current_bytes = 0;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::{mocks::SharedWriter, RollingFileCopier};
+
+    #[test]
+    fn rolling_file_copier() {
+        let mut outputs = Vec::new();
+        let mut copier = RollingFileCopier::new(
+            |index| {
+                assert_eq!(index, outputs.len());
+                let writer = SharedWriter::new();
+                outputs.push(writer.clone());
+                Ok(writer)
+            },
+            10,
+        );
+
+        assert_eq!(copier.copy(&mut &b"0123456789abcdef"[..]).unwrap(), 16);
+        assert_eq!(copier.current_bytes(), 6);
+        assert_eq!(copier.copy(&mut &b"ghij"[..]).unwrap(), 4);
+        assert_eq!(copier.writers_created(), 2);
+
+        // Reaching the limit exactly doesn't create a writer until more bytes arrive.
+        assert_eq!(copier.copy(&mut &b""[..]).unwrap(), 0);
+        assert_eq!(copier.writers_created(), 2);
+        assert_eq!(copier.copy(&mut &b"K"[..]).unwrap(), 1);
+        assert_eq!(copier.writers_created(), 3);
+
+        let contents: Vec<_> = outputs.iter().map(|w| w.contents()).collect();
+        assert_eq!(contents, [&b"0123456789"[..], b"abcdefghij", b"K"]);
+    }
+
+    #[test]
+    fn rolling_file_copier_factory_error() {
+        let mut copier = RollingFileCopier::new(
+            |index| match index {
+                0 => Ok(Vec::new()),
+                _ => Err(io::Error::from(io::ErrorKind::PermissionDenied)),
+            },
+            4,
+        );
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(copier.into_inner(), None);
+    }
+}
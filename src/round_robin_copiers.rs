@@ -1,20 +1,121 @@
+use alloc::{vec, vec::Vec};
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
 use std::io::{self, Read, Write};
 
-pub struct RoundRobinCopier<'a> {
-    writers: Vec<&'a mut dyn Write>,
+/// Copies each reader into the next of its internal writers in turn, wrapping around after the last.
+///
+/// The writers can be borrowed as trait objects, as in the default `RoundRobinCopier<'a>` (`RoundRobinCopier<'a, &'a mut dyn Write>`),
+/// or owned, as in a `RoundRobinCopier<'static, File>`,
+/// so the copier can be stored in a struct and reused without borrowing its writers from elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use streams::RoundRobinCopier;
+///
+/// let mut copier = RoundRobinCopier::new(vec![Vec::new(), Vec::new()]);
+/// copier.copy(&mut &b"Hello"[..])?;
+/// copier.copy(&mut &b"world"[..])?;
+///
+/// assert_eq!(copier.into_inner(), [b"Hello", b"world"]);
+/// # }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # I/O Traits
+///
+/// [`copy`](RoundRobinCopier::copy) copies [`std::io::Read`] readers into [`std::io::Write`] writers, requiring the default `std` feature.
+/// With the `embedded-io` feature, `copy_embedded` copies `embedded_io::Read` readers
+/// into `embedded_io::Write` writers instead, which only requires `alloc`, so it can be used with the `std` feature disabled.
+/// Without the `std` feature, `RoundRobinCopier<'a>` borrows `embedded_io::Write` trait objects reporting an `embedded_io::ErrorKind`.
+pub struct RoundRobinCopier<'a, W = BorrowedWriter<'a>> {
+    writers: Vec<W>,
     current: usize,
+    bytes_written: Vec<u64>,
+    lifetime: PhantomData<&'a ()>,
 }
 
-impl<'a> RoundRobinCopier<'a> {
-    pub fn new(writers: Vec<&'a mut dyn Write>) -> Self {
+/// The writers of a `RoundRobinCopier<'a>`, borrowed as trait objects.
+#[cfg(feature = "std")]
+type BorrowedWriter<'a> = &'a mut dyn Write;
+#[cfg(not(feature = "std"))]
+type BorrowedWriter<'a> = &'a mut dyn embedded_io::Write<Error = embedded_io::ErrorKind>;
+
+impl<'a, W> RoundRobinCopier<'a, W> {
+    pub fn new(writers: Vec<W>) -> Self {
         let current = 0;
+        let bytes_written = vec![0; writers.len()];
 
-        Self { writers, current }
+        Self {
+            writers,
+            current,
+            bytes_written,
+            lifetime: PhantomData,
+        }
     }
 
-    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+    /// The index of the writer the next call to [`copy`](RoundRobinCopier::copy) will copy into.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// Rotate back to the first writer, as if the copier was newly created. The byte counts are kept.
+    pub fn reset(&mut self) {
+        self.current = 0;
+    }
+
+    /// Set the index of the writer the next call to [`copy`](RoundRobinCopier::copy) will copy into,
+    /// for instance to restore a position previously saved with [`current_index`](RoundRobinCopier::current_index).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the number of internal writers.
+    pub fn set_index(&mut self, index: usize) {
+        assert!(
+            index < self.writers.len(),
+            "index {index} out of range for {} writers",
+            self.writers.len()
+        );
+
+        self.current = index;
+    }
+
+    /// Consumes the `RoundRobinCopier`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// The number of bytes each internal writer has received through [`copy`](RoundRobinCopier::copy), indexed like the writers.
+    pub fn stats(&self) -> &[u64] {
+        &self.bytes_written
+    }
+
+    /// The total number of bytes copied into all internal writers.
+    pub fn total_bytes(&self) -> u64 {
+        self.bytes_written.iter().sum()
+    }
+
+    /// Take a snapshot of the copier's position and byte counts.
+    pub fn snapshot(&self) -> RoundRobinStats {
+        RoundRobinStats {
+            current_index: self.current,
+            total_bytes: self.total_bytes(),
+            writer_bytes: self.bytes_written.clone(),
+        }
+    }
+
+    /// Run `copy` on the current writer, then advance to the next writer, counting the bytes `copy` returns.
+    ///
+    /// If there are no internal writers, the error returned by `no_writers` is returned instead.
+    pub(crate) fn copy_with<E>(
+        &mut self,
+        no_writers: impl FnOnce() -> E,
+        copy: impl FnOnce(&mut W) -> Result<u64, E>,
+    ) -> Result<u64, E> {
         if self.writers.is_empty() {
-            return Err(io::Error::from(io::ErrorKind::WriteZero));
+            return Err(no_writers());
         }
 
         let index = self.current;
@@ -22,13 +123,65 @@ impl<'a> RoundRobinCopier<'a> {
         // Increment the current index, wrapping around if we exceed the number of internal writers.
         self.current = (self.current + 1) % self.writers.len();
 
-        io::copy(reader, self.writers[index])
+        let copied = copy(&mut self.writers[index])?;
+        self.bytes_written[index] += copied;
+
+        Ok(copied)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, W: Write> RoundRobinCopier<'a, W> {
+    /// Copy the entire contents of `reader` into the current writer, then advance to the next writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// Errors during the copy are returned as-is; the copier advances to the next writer regardless.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        self.copy_with(
+            || {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "round-robin copier has no writers to copy into",
+                )
+            },
+            |writer| io::copy(reader, writer),
+        )
     }
 }
 
-#[cfg(test)]
+/// A snapshot of the statistics of a [`RoundRobinCopier`], taken by [`RoundRobinCopier::snapshot`].
+///
+/// With the `serde` feature enabled, it can be serialized, with a field for each of its accessors.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundRobinStats {
+    current_index: usize,
+    total_bytes: u64,
+    writer_bytes: Vec<u64>,
+}
+
+impl RoundRobinStats {
+    /// The index of the writer the next copy would have gone into, like [`RoundRobinCopier::current_index`].
+    pub fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    /// The total number of bytes copied into all internal writers, like [`RoundRobinCopier::total_bytes`].
+    pub fn total_bytes(&self) -> u64 {
+        self.total_bytes
+    }
+
+    /// The number of bytes each internal writer has received, like [`RoundRobinCopier::stats`].
+    pub fn writer_bytes(&self) -> &[u64] {
+        &self.writer_bytes
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
-    use std::io::Write;
+    use std::io::{self, Write};
 
     use crate::RoundRobinCopier;
 
@@ -49,4 +202,128 @@ mod tests {
         assert_eq!(writers[1], b"Hello, world!");
         assert_eq!(writers[2], b"Hello, world!");
     }
+
+    #[test]
+    fn round_robin_copier_owned() {
+        let mut copier = RoundRobinCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+
+        let input = b"Hello, world!";
+
+        copier.copy(&mut &input[..]).unwrap();
+        copier.copy(&mut &input[..]).unwrap();
+        copier.copy(&mut &input[..]).unwrap();
+        copier.copy(&mut &input[..]).unwrap();
+
+        let writers = copier.into_inner();
+        assert_eq!(writers[0], b"Hello, world!Hello, world!");
+        assert_eq!(writers[1], b"Hello, world!");
+        assert_eq!(writers[2], b"Hello, world!");
+    }
+
+    #[test]
+    fn round_robin_copier_borrowed_type() {
+        struct Pipeline<'a> {
+            copier: RoundRobinCopier<'a>,
+        }
+
+        let mut first = Vec::<u8>::new();
+        let mut second = Vec::<u8>::new();
+        let mut pipeline = Pipeline {
+            copier: RoundRobinCopier::new(vec![&mut first, &mut second]),
+        };
+
+        pipeline.copier.copy(&mut &b"Hello"[..]).unwrap();
+        pipeline.copier.copy(&mut &b"world"[..]).unwrap();
+
+        assert_eq!(first, b"Hello");
+        assert_eq!(second, b"world");
+    }
+
+    #[test]
+    fn round_robin_copier_stats() {
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier =
+            RoundRobinCopier::new(writers.iter_mut().map(|w| w as &mut dyn Write).collect());
+
+        let input = b"Hello, world!";
+        for _ in 0..4 {
+            copier.copy(&mut &input[..]).unwrap();
+        }
+
+        assert_eq!(copier.stats(), [26, 13, 13]);
+        assert_eq!(copier.total_bytes(), 52);
+
+        let snapshot = copier.snapshot();
+        assert_eq!(snapshot.current_index(), 1);
+        assert_eq!(snapshot.total_bytes(), 52);
+        assert_eq!(snapshot.writer_bytes(), [26, 13, 13]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_robin_copier_snapshot_serde() {
+        let mut copier = RoundRobinCopier::new(vec![Vec::<u8>::new(), Vec::new()]);
+        copier.copy(&mut &b"Hello"[..]).unwrap();
+        copier.copy(&mut &b"world!"[..]).unwrap();
+        copier.copy(&mut &b"!"[..]).unwrap();
+
+        let snapshot = copier.snapshot();
+        let json = serde_json::to_value(&snapshot).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "current_index": 1,
+                "total_bytes": 12,
+                "writer_bytes": [6, 6],
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<crate::RoundRobinStats>(json).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn round_robin_copier_without_writers() {
+        let mut copier = RoundRobinCopier::new(Vec::<&mut dyn Write>::new());
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "round-robin copier has no writers to copy into"
+        );
+        assert_eq!(copier.total_bytes(), 0);
+    }
+
+    #[test]
+    fn round_robin_copier_current_index() {
+        let mut copier = RoundRobinCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+        assert_eq!(copier.current_index(), 0);
+
+        for expected in [1, 2, 0, 1] {
+            copier.copy(&mut &b"Hello"[..]).unwrap();
+            assert_eq!(copier.current_index(), expected);
+        }
+
+        copier.reset();
+        assert_eq!(copier.current_index(), 0);
+        copier.copy(&mut &b"world"[..]).unwrap();
+
+        copier.set_index(2);
+        copier.copy(&mut &b"!"[..]).unwrap();
+        assert_eq!(copier.current_index(), 0);
+
+        let writers = copier.into_inner();
+        assert_eq!(writers[0], b"HelloHelloworld");
+        assert_eq!(writers[1], b"Hello");
+        assert_eq!(writers[2], b"Hello!");
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of range for 3 writers")]
+    fn round_robin_copier_set_index_out_of_range() {
+        let mut copier = RoundRobinCopier::new(vec![Vec::<u8>::new(), Vec::new(), Vec::new()]);
+        copier.set_index(3);
+    }
 }
@@ -0,0 +1,155 @@
+use std::io::{self, Seek, SeekFrom, Write};
+
+use crate::MultiWriteError;
+
+/// Provides a single [`Writer`](Write) and [`Seek`] that mirrors writes and seeks to multiple seekable writers,
+/// keeping their positions synchronized so the mirrored destinations stay byte-aligned.
+///
+/// Writes behave like those of a [`MultiWriter`](crate::MultiWriter) using [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Cursor, Seek, SeekFrom, Write};
+/// use streams::SeekableMultiWriter;
+///
+/// let mut multi_writer = SeekableMultiWriter::new(vec![Cursor::new(Vec::new()), Cursor::new(Vec::new())]);
+/// multi_writer.write_all(b"Hello, world!")?;
+/// multi_writer.seek(SeekFrom::Start(7))?;
+/// multi_writer.write_all(b"there")?;
+///
+/// for writer in multi_writer.into_inner() {
+///     assert_eq!(writer.into_inner(), b"Hello, there!");
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Errors returned from the internal writers wrap a [`MultiWriteError`] reporting the index of the failing writer.
+pub struct SeekableMultiWriter<W: Write + Seek> {
+    writers: Vec<W>,
+}
+
+impl<W: Write + Seek> SeekableMultiWriter<W> {
+    pub fn new(writers: Vec<W>) -> Self {
+        Self { writers }
+    }
+
+    /// Get a reference to the internal writers.
+    pub fn get_ref(&self) -> &[W] {
+        &self.writers
+    }
+
+    /// Consumes the `SeekableMultiWriter`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+}
+
+impl<W: Write + Seek> Write for SeekableMultiWriter<W> {
+    /// Write a buffer into each internal writer sequentially.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            writer
+                .write_all(buf)
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            writer
+                .flush()
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(())
+    }
+
+    /// Calls [`write`](SeekableMultiWriter::write) and discards the returned `usize`.
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let _ = self.write(buf)?;
+
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> Seek for SeekableMultiWriter<W> {
+    /// Apply the same seek to each internal writer sequentially, returning the common resulting position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// If a writer ends up at a different position than the first writer,
+    /// for instance after seeking relative to the ends of destinations of differing lengths,
+    /// an error of kind [`InvalidData`](io::ErrorKind::InvalidData) reporting the diverging writer is returned.
+    /// The writers are left at their diverging positions.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let mut position = None;
+
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            let new = writer
+                .seek(pos)
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+
+            match position {
+                None => position = Some(new),
+                Some(expected) if new != expected => {
+                    let e = io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("moved to position {new} instead of {expected}"),
+                    );
+                    return Err(MultiWriteError::new(index, e).into_io_error());
+                }
+                Some(_) => {}
+            }
+        }
+
+        position.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no writers to seek"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor, Seek, SeekFrom, Write};
+
+    use crate::SeekableMultiWriter;
+
+    #[test]
+    fn seekable_multi_writer_overwrite() {
+        let mut multi_writer = SeekableMultiWriter::new(vec![
+            Cursor::new(Vec::<u8>::new()),
+            Cursor::new(Vec::new()),
+            Cursor::new(Vec::new()),
+        ]);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        assert_eq!(multi_writer.seek(SeekFrom::Current(-6)).unwrap(), 7);
+        multi_writer.write_all(b"WORLD").unwrap();
+        assert_eq!(multi_writer.stream_position().unwrap(), 12);
+
+        for writer in multi_writer.into_inner() {
+            assert_eq!(writer.into_inner(), b"Hello, WORLD!");
+        }
+    }
+
+    #[test]
+    fn seekable_multi_writer_divergence() {
+        let mut multi_writer = SeekableMultiWriter::new(vec![
+            Cursor::new(b"Hello".to_vec()),
+            Cursor::new(b"Hello, world!".to_vec()),
+        ]);
+
+        let err = multi_writer.seek(SeekFrom::End(0)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "writer 1 failed: moved to position 13 instead of 5"
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use crate::MultiWriteError;
+
+/// Provides a single [`Writer`](Write) that writes to multiple shared writers sequentially, each behind an [`Arc<Mutex<_>>`](Mutex).
+///
+/// This allows fanning out to writers that are also written to by other parts of the program, possibly from other threads,
+/// where a `&mut` borrow for a [`MultiWriter`](crate::MultiWriter) can't be handed out.
+///
+/// Writes behave like those of a [`MultiWriter`](crate::MultiWriter) using [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast):
+/// each internal writer receives the entire buffer through [`write_all`](Write::write_all), in order,
+/// and the first failure is returned, wrapping a [`MultiWriteError`] reporting its index.
+///
+/// # Locking
+///
+/// Each internal writer is locked in turn, only while it is being written to or flushed,
+/// and its lock is released before the next writer is locked.
+/// As a `SharedMultiWriter` never holds more than one lock at a time, it can't deadlock against other code locking the same writers in a different order,
+/// but other code may write to a writer between two writes into the `SharedMultiWriter`, or even between the first and last writer of a single write.
+/// To keep a buffer from being interleaved with other output, write it in a single call.
+///
+/// # Poisoning
+///
+/// If a writer's mutex was poisoned by a thread panicking while holding it, the state of that writer is unknown,
+/// so writing or flushing returns an error of kind [`Other`](io::ErrorKind::Other) for it, wrapped like any other failure.
+///
+/// # Examples
+///
+/// ```
+/// use std::{io::Write, sync::{Arc, Mutex}};
+/// use streams::SharedMultiWriter;
+///
+/// let log = Arc::new(Mutex::new(Vec::new()));
+/// let mut multi_writer = SharedMultiWriter::new(vec![log.clone(), Arc::new(Mutex::new(std::io::sink()))]);
+///
+/// multi_writer.write_all(b"Hello, ")?;
+/// log.lock().unwrap().write_all(b"world!")?;
+///
+/// assert_eq!(*log.lock().unwrap(), b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct SharedMultiWriter {
+    writers: Vec<Arc<Mutex<dyn Write + Send>>>,
+}
+
+impl SharedMultiWriter {
+    pub fn new(writers: Vec<Arc<Mutex<dyn Write + Send>>>) -> Self {
+        Self { writers }
+    }
+
+    /// Append a writer, which will receive all data written from now on.
+    pub fn push(&mut self, writer: Arc<Mutex<dyn Write + Send>>) {
+        self.writers.push(writer);
+    }
+
+    /// The number of internal writers.
+    pub fn len(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns `true` if there are no internal writers.
+    pub fn is_empty(&self) -> bool {
+        self.writers.is_empty()
+    }
+
+    /// Consumes the `SharedMultiWriter`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<Arc<Mutex<dyn Write + Send>>> {
+        self.writers
+    }
+
+    /// Lock each internal writer in turn and call `op` on it, stopping at the first failure.
+    fn fan_out(&self, mut op: impl FnMut(&mut dyn Write) -> io::Result<()>) -> io::Result<()> {
+        for (index, writer) in self.writers.iter().enumerate() {
+            let result = match writer.lock() {
+                Ok(mut writer) => op(&mut *writer),
+                Err(_) => Err(io::Error::other("writer lock poisoned")),
+            };
+
+            result.map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Write for SharedMultiWriter {
+    /// Write a buffer into each internal writer sequentially, locking one writer at a time.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.fan_out(|writer| writer.write_all(buf))?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush each internal writer sequentially, locking one writer at a time.
+    fn flush(&mut self) -> io::Result<()> {
+        self.fan_out(|writer| writer.flush())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use crate::{MultiWriteError, SharedMultiWriter};
+
+    #[test]
+    fn shared_multi_writer() {
+        let sink1 = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let sink2 = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let mut multi_writer = SharedMultiWriter::new(vec![sink1.clone(), sink2.clone()]);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        sink1.lock().unwrap().write_all(b"big ").unwrap();
+        multi_writer.write_all(b"world!").unwrap();
+
+        let handle = {
+            let sink2 = sink2.clone();
+            thread::spawn(move || sink2.lock().unwrap().write_all(b" Bye!").unwrap())
+        };
+        handle.join().unwrap();
+        multi_writer.flush().unwrap();
+
+        assert_eq!(*sink1.lock().unwrap(), b"Hello, big world!");
+        assert_eq!(*sink2.lock().unwrap(), b"Hello, world! Bye!");
+    }
+
+    #[test]
+    fn shared_multi_writer_poisoned() {
+        let sink1 = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let sink2 = Arc::new(Mutex::new(Vec::<u8>::new()));
+        let mut multi_writer = SharedMultiWriter::new(vec![sink1.clone(), sink2.clone()]);
+
+        let poisoner = sink2.clone();
+        thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poisoning the lock");
+        })
+        .join()
+        .unwrap_err();
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(*sink1.lock().unwrap(), b"Hello, world!");
+    }
+}
@@ -0,0 +1,45 @@
+use std::io::{self, IoSlice, Write};
+
+/// A zero-sized writer accepting and discarding every byte.
+///
+/// This behaves like [`io::sink`], but is a concrete type that can be created in place,
+/// e.g. to put in a `Vec<&mut dyn Write>` alongside other writers.
+/// As it neither allocates nor copies, it is the recommended baseline writer when benchmarking fan-outs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SinkWriter;
+
+impl Write for SinkWriter {
+    /// Discard `buf`, always returning exactly its length.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    /// Discard `bufs`, always returning exactly their total length.
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        Ok(bufs.iter().map(|b| b.len()).sum())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{IoSlice, Write};
+
+    use crate::SinkWriter;
+
+    #[test]
+    fn sink_writer() {
+        let mut sink = SinkWriter;
+
+        assert_eq!(sink.write(b"Hello, world!").unwrap(), 13);
+        assert_eq!(
+            sink.write_vectored(&[IoSlice::new(b"Hello, "), IoSlice::new(b"world!")])
+                .unwrap(),
+            13
+        );
+        sink.flush().unwrap();
+    }
+}
@@ -0,0 +1,287 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Read, Write},
+};
+
+use crate::{MultiWriteError, MultiWriter, DEFAULT_BUF_SIZE};
+
+/// A destination for copied data that must be explicitly finished once all data has been written.
+///
+/// Some writers, like [`Base64Writer`](crate::Base64Writer) and `CompressWriter` with the `flate2` feature,
+/// only produce complete output once they are told no more data will follow.
+/// Calling [`finish`](Sink::finish) on a [`MultiWriter`] of sinks, or copying with [`copy_into_sinks`],
+/// finishes every destination, reporting any errors instead of leaving them to a best-effort [`Drop`].
+///
+/// # Implementations
+///
+/// The writers of this crate needing to be finished implement `Sink` by value, finishing them.
+/// The common standard library writers, whose [`finish`](Sink::finish) simply flushes them,
+/// implement `Sink` both by value and through mutable references.
+/// There is no implementation for every [`Write`] or every mutable reference to one,
+/// as it would silently truncate the output of a writer that is only flushed instead of being finished.
+pub trait Sink {
+    /// Write an entire chunk of data to the sink.
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Finish the sink after the last chunk has been written, flushing it.
+    fn finish(self) -> io::Result<()>
+    where
+        Self: Sized;
+}
+
+/// Implement [`Sink`] for plain writers and mutable references to them, finishing them by flushing.
+macro_rules! impl_flushing_sink {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Sink for $ty {
+                fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+                    self.write_all(buf)
+                }
+
+                /// Flush the writer.
+                fn finish(mut self) -> io::Result<()> {
+                    self.flush()
+                }
+            }
+
+            impl Sink for &mut $ty {
+                fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+                    self.write_all(buf)
+                }
+
+                /// Flush the writer.
+                fn finish(self) -> io::Result<()> {
+                    self.flush()
+                }
+            }
+        )*
+    };
+}
+
+impl_flushing_sink!(Vec<u8>, File, io::Stdout, io::Stderr, io::Sink);
+
+impl<W: Write + Sink> Sink for BufWriter<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Flush the buffered data, then finish the internal writer.
+    fn finish(self) -> io::Result<()> {
+        self.into_inner()
+            .map_err(io::IntoInnerError::into_error)?
+            .finish()
+    }
+}
+
+impl<W: Write + Sink> Sink for MultiWriter<W> {
+    fn write_chunk(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write_all(buf)
+    }
+
+    /// Finish every internal writer sequentially, skipping those disabled under [`ErrorPolicy::DisableOnError`](crate::ErrorPolicy::DisableOnError).
+    ///
+    /// Every writer is finished even if an earlier one fails,
+    /// as the writers are consumed and can't be finished later.
+    /// The error of the first failing writer is returned, wrapping a [`MultiWriteError`] reporting its index.
+    fn finish(self) -> io::Result<()> {
+        let labels: Vec<_> = (0..self.len())
+            .map(|index| self.label(index).map(str::to_owned))
+            .collect();
+        let disabled: Vec<_> = self
+            .disabled_writers()
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+        let mut result = Ok(());
+
+        for (index, (writer, label)) in self.into_inner().into_iter().zip(labels).enumerate() {
+            if disabled.contains(&index) {
+                continue;
+            }
+
+            if let Err(e) = writer.finish() {
+                if result.is_ok() {
+                    result = Err(MultiWriteError::new(index, e)
+                        .with_label(label)
+                        .into_io_error());
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Copy the entire contents of a reader into multiple sinks, then finish every sink.
+///
+/// The input is read in chunks of [`DEFAULT_BUF_SIZE`] bytes, each of which is written to every sink using [`Sink::write_chunk`].
+///
+/// # Errors
+///
+/// Errors from the reader are returned as-is, and the sinks are dropped without being finished.
+/// If a sink fails, the returned error wraps a [`MultiWriteError`] reporting its index.
+/// A failure while writing aborts the copy, dropping the sinks without finishing them,
+/// while every sink is finished even if an earlier one fails to, as with [`MultiWriter`]'s [`Sink::finish`].
+///
+/// # Examples
+///
+/// ```
+/// use streams::Base64Writer;
+///
+/// let mut raw = Vec::new();
+/// let mut encoded = Vec::new();
+/// let sinks = vec![Base64Writer::new(&mut raw), Base64Writer::new(&mut encoded)];
+///
+/// let copied = streams::copy_into_sinks(&mut &b"Hi"[..], sinks)?;
+///
+/// assert_eq!(copied, 2);
+/// assert_eq!(encoded, b"SGk=");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn copy_into_sinks<R: Read + ?Sized, S: Sink>(
+    reader: &mut R,
+    mut sinks: Vec<S>,
+) -> io::Result<u64> {
+    let mut buf = vec![0; DEFAULT_BUF_SIZE];
+    let mut copied = 0;
+
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        for (index, sink) in sinks.iter_mut().enumerate() {
+            sink.write_chunk(&buf[..n])
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        copied += n as u64;
+    }
+
+    let mut result = Ok(copied);
+
+    for (index, sink) in sinks.into_iter().enumerate() {
+        if let Err(e) = sink.finish() {
+            if result.is_ok() {
+                result = Err(MultiWriteError::new(index, e).into_io_error());
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{
+        mocks::{FailingWriter, SharedWriter},
+        Base64Writer, DedupWriter, ErrorPolicy, MultiWriteError, MultiWriter, RecordFramingWriter,
+        Sink,
+    };
+
+    #[test]
+    fn multi_writer_finish() {
+        let first = SharedWriter::new();
+        let second = SharedWriter::new();
+        let mut multi_writer = MultiWriter::from_writers(vec![
+            Base64Writer::new(first.clone()),
+            Base64Writer::new(second.clone()),
+        ]);
+
+        multi_writer.write_all(b"Hello").unwrap();
+        multi_writer.flush().unwrap();
+        assert_eq!(first.contents(), b"SGVs");
+
+        multi_writer.finish().unwrap();
+        assert_eq!(first.contents(), b"SGVsbG8=");
+        assert_eq!(second.contents(), b"SGVsbG8=");
+    }
+
+    #[test]
+    fn multi_writer_finish_reports_failing_index() {
+        let multi_writer = MultiWriter::from_writers(vec![
+            Base64Writer::new(Box::new(Vec::new()) as Box<dyn Write>),
+            Base64Writer::new(Box::new(FailingWriter::new(io::ErrorKind::BrokenPipe))),
+        ]);
+
+        let err = multi_writer.finish().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+    }
+
+    #[test]
+    fn multi_writer_finish_skips_disabled_writers() {
+        let output = SharedWriter::new();
+        let mut multi_writer = MultiWriter::from_writers(vec![
+            Base64Writer::new(Box::new(output.clone()) as Box<dyn Write>),
+            Base64Writer::new(Box::new(FailingWriter::new(io::ErrorKind::BrokenPipe))),
+        ]);
+        multi_writer.set_error_policy(ErrorPolicy::DisableOnError);
+
+        multi_writer.write_all(b"Hi").unwrap();
+        multi_writer.flush().unwrap();
+        assert_eq!(multi_writer.disabled_writers().len(), 1);
+
+        multi_writer.finish().unwrap();
+        assert_eq!(output.contents(), b"SGk=");
+    }
+
+    #[test]
+    fn copy_into_sinks() {
+        let mut first = Vec::<u8>::new();
+        let mut second = Vec::<u8>::new();
+
+        let copied = crate::copy_into_sinks(
+            &mut &b"ab\nab\nc"[..],
+            vec![DedupWriter::new(&mut first), DedupWriter::new(&mut second)],
+        )
+        .unwrap();
+
+        assert_eq!(copied, 7);
+        assert_eq!(first, b"ab (repeated 2 times)\nc");
+        assert_eq!(second, b"ab (repeated 2 times)\nc");
+    }
+
+    #[test]
+    fn copy_into_sinks_record_framing() {
+        let mut first = Vec::<u8>::new();
+        let mut second = Vec::<u8>::new();
+
+        crate::copy_into_sinks(
+            &mut &b"abcdefg"[..],
+            vec![
+                RecordFramingWriter::new(&mut first, 3, b"\n".to_vec()),
+                RecordFramingWriter::new(&mut second, 3, b"\n".to_vec()).with_padding(b'.'),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(first, b"abc\ndef\ng");
+        assert_eq!(second, b"abc\ndef\ng..\n");
+    }
+
+    #[test]
+    fn copy_into_sinks_nested() {
+        let mut raw = Vec::<u8>::new();
+        let encoded = SharedWriter::new();
+
+        let sinks = vec![MultiWriter::from_writers(vec![Base64Writer::new(
+            encoded.clone(),
+        )])];
+        crate::copy_into_sinks(&mut &b"Hi"[..], sinks).unwrap();
+        crate::copy_into_sinks(&mut &b"Hi"[..], vec![&mut raw]).unwrap();
+
+        assert_eq!(encoded.contents(), b"SGk=");
+        assert_eq!(raw, b"Hi");
+    }
+}
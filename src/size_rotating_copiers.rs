@@ -0,0 +1,117 @@
+use std::io::{self, Read, Write};
+
+/// Copies readers into its current internal writer until that writer has received more than a fixed number of bytes,
+/// then rotates to the next writer, wrapping around after the last.
+///
+/// Rotation only happens between copies: a single [`copy`](SizeRotatingCopier::copy) larger than the threshold
+/// is still copied entirely into the current writer, and the copier rotates afterwards.
+/// A writer's byte count restarts from zero every time the copier rotates to it.
+pub struct SizeRotatingCopier<'a> {
+    writers: Vec<&'a mut dyn Write>,
+    bytes_per_writer: u64,
+    current: usize,
+    current_bytes: u64,
+}
+
+impl<'a> SizeRotatingCopier<'a> {
+    pub fn new(writers: Vec<&'a mut dyn Write>, bytes_per_writer: u64) -> Self {
+        let current = 0;
+        let current_bytes = 0;
+
+        Self {
+            writers,
+            bytes_per_writer,
+            current,
+            current_bytes,
+        }
+    }
+
+    /// The index of the writer the next call to [`copy`](SizeRotatingCopier::copy) will copy into.
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    /// The number of bytes the current writer has received since the copier rotated to it.
+    pub fn current_bytes(&self) -> u64 {
+        self.current_bytes
+    }
+
+    /// Copy the entire contents of `reader` into the current writer,
+    /// rotating to the next writer afterwards if the current writer has now received more than `bytes_per_writer` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers.
+    /// Errors during the copy are returned as-is, without rotating.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "size-rotating copier has no writers to copy into",
+            ));
+        }
+
+        let copied = io::copy(reader, self.writers[self.current])?;
+        self.current_bytes += copied;
+
+        if self.current_bytes > self.bytes_per_writer {
+            self.current = (self.current + 1) % self.writers.len();
+            self.current_bytes = 0;
+        }
+
+        Ok(copied)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::SizeRotatingCopier;
+
+    #[test]
+    fn size_rotating_copier() {
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier = SizeRotatingCopier::new(
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+            10,
+        );
+
+        // 6 bytes don't exceed the threshold, but 12 do.
+        copier.copy(&mut &b"Hello,"[..]).unwrap();
+        assert_eq!(copier.current_index(), 0);
+        copier.copy(&mut &b" world"[..]).unwrap();
+        assert_eq!(copier.current_index(), 1);
+
+        // A copy larger than the threshold isn't split.
+        copier
+            .copy(&mut &b"a copy larger than ten bytes"[..])
+            .unwrap();
+        assert_eq!(copier.current_index(), 2);
+
+        // Exactly reaching the threshold doesn't rotate.
+        copier.copy(&mut &b"0123456789"[..]).unwrap();
+        assert_eq!(copier.current_index(), 2);
+        assert_eq!(copier.current_bytes(), 10);
+        copier.copy(&mut &b"!"[..]).unwrap();
+        assert_eq!(copier.current_index(), 0);
+
+        copier.copy(&mut &b"again"[..]).unwrap();
+
+        assert_eq!(writers[0], b"Hello, worldagain");
+        assert_eq!(writers[1], b"a copy larger than ten bytes");
+        assert_eq!(writers[2], b"0123456789!");
+    }
+
+    #[test]
+    fn size_rotating_copier_without_writers() {
+        let mut copier = SizeRotatingCopier::new(Vec::new(), 4);
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "size-rotating copier has no writers to copy into"
+        );
+    }
+}
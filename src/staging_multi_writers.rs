@@ -0,0 +1,277 @@
+use std::{
+    fs::File,
+    io::{self, Cursor, Seek, SeekFrom, Write},
+};
+
+use crate::{MultiError, MultiWriteError};
+
+/// A writer whose contents can be cut off at a given length, used by a [`StagingMultiWriter`] to roll back failed commits.
+pub trait Truncate {
+    /// Discard everything after the first `len` bytes.
+    fn truncate(&mut self, len: u64) -> io::Result<()>;
+}
+
+impl Truncate for File {
+    fn truncate(&mut self, len: u64) -> io::Result<()> {
+        self.set_len(len)
+    }
+}
+
+impl Truncate for Cursor<Vec<u8>> {
+    fn truncate(&mut self, len: u64) -> io::Result<()> {
+        self.get_mut()
+            .truncate(len.try_into().unwrap_or(usize::MAX));
+        Ok(())
+    }
+}
+
+impl Truncate for Cursor<&mut Vec<u8>> {
+    fn truncate(&mut self, len: u64) -> io::Result<()> {
+        self.get_mut()
+            .truncate(len.try_into().unwrap_or(usize::MAX));
+        Ok(())
+    }
+}
+
+impl<T: Truncate + ?Sized> Truncate for &mut T {
+    fn truncate(&mut self, len: u64) -> io::Result<()> {
+        (**self).truncate(len)
+    }
+}
+
+/// Provides a single [`Writer`](Write) that stages a message and writes it to either all or none of its internal writers.
+///
+/// Writes only append to an in-memory staging buffer.
+/// [`commit`](StagingMultiWriter::commit) then writes and flushes the staged message into each internal writer in turn;
+/// if one of them fails, every writer attempted so far, including the failing one,
+/// is rolled back by truncating it to the position it had before the commit and seeking back there.
+///
+/// True atomicity is impossible for arbitrary writers: a crash during a commit still leaves it half done.
+/// As rolling back requires both [`Seek`] and [`Truncate`], writers that can't be rolled back are rejected at compile time.
+/// Since a writer is truncated at its pre-commit position, the internal writers are expected to be written at their end,
+/// as when appending to a file.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Cursor, Write};
+/// use streams::StagingMultiWriter;
+///
+/// let mut multi_writer = StagingMultiWriter::new(vec![Cursor::new(Vec::new()), Cursor::new(Vec::new())]);
+/// multi_writer.write_all(b"Hello, ")?;
+/// multi_writer.write_all(b"world!")?;
+/// assert!(multi_writer.get_ref()[0].get_ref().is_empty());
+///
+/// multi_writer.commit()?;
+/// for writer in multi_writer.into_inner() {
+///     assert_eq!(writer.into_inner(), b"Hello, world!");
+/// }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct StagingMultiWriter<W: Write + Seek + Truncate> {
+    writers: Vec<W>,
+    staged: Vec<u8>,
+}
+
+impl<W: Write + Seek + Truncate> StagingMultiWriter<W> {
+    pub fn new(writers: Vec<W>) -> Self {
+        let staged = Vec::new();
+
+        Self { writers, staged }
+    }
+
+    /// The message staged for the next [`commit`](StagingMultiWriter::commit).
+    pub fn staged(&self) -> &[u8] {
+        &self.staged
+    }
+
+    /// Discard the staged message without writing it.
+    pub fn discard(&mut self) {
+        self.staged.clear();
+    }
+
+    /// Get a reference to the internal writers.
+    pub fn get_ref(&self) -> &[W] {
+        &self.writers
+    }
+
+    /// Consumes the `StagingMultiWriter`, returning the internal writers. The staged message is discarded.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// Write and flush the staged message into every internal writer, or roll back the writers attempted if one fails.
+    ///
+    /// The staged message is cleared once every writer has received it.
+    ///
+    /// # Errors
+    ///
+    /// If a writer fails, the staged message is kept so the commit can be retried,
+    /// and the returned error wraps a [`MultiWriteError`] reporting the index of the failing writer.
+    /// If any writer also fails to roll back, and may thus keep a partial message,
+    /// an error of kind [`Other`](io::ErrorKind::Other) wrapping a [`MultiError`] is returned instead,
+    /// holding the error of the failing writer first, then the rollback errors.
+    pub fn commit(&mut self) -> io::Result<()> {
+        let mut positions = Vec::with_capacity(self.writers.len());
+
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            let position = writer
+                .stream_position()
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+            positions.push(position);
+        }
+
+        for index in 0..self.writers.len() {
+            let writer = &mut self.writers[index];
+
+            if let Err(e) = writer.write_all(&self.staged).and_then(|_| writer.flush()) {
+                return Err(self.roll_back(&positions[..=index], index, e));
+            }
+        }
+
+        self.staged.clear();
+
+        Ok(())
+    }
+
+    /// Roll back the writers at the start of `positions` after the writer at `index` failed with `error`.
+    fn roll_back(&mut self, positions: &[u64], index: usize, error: io::Error) -> io::Error {
+        let mut errors = Vec::new();
+
+        for (i, (writer, &position)) in self.writers.iter_mut().zip(positions).enumerate() {
+            let result = writer
+                .truncate(position)
+                .and_then(|_| writer.seek(SeekFrom::Start(position)));
+
+            if let Err(e) = result {
+                errors.push((i, e));
+            }
+        }
+
+        if errors.is_empty() {
+            MultiWriteError::new(index, error).into_io_error()
+        } else {
+            errors.insert(0, (index, error));
+            MultiError::new(self.writers.len(), errors).into_io_error()
+        }
+    }
+}
+
+impl<W: Write + Seek + Truncate> Write for StagingMultiWriter<W> {
+    /// Append a buffer to the staged message. Nothing is written to the internal writers until [`commit`](StagingMultiWriter::commit).
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.staged.extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    /// Flush each internal writer sequentially. This does not commit the staged message.
+    fn flush(&mut self) -> io::Result<()> {
+        for (index, writer) in self.writers.iter_mut().enumerate() {
+            writer
+                .flush()
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Cursor, Seek, SeekFrom, Write};
+
+    use crate::{MultiWriteError, StagingMultiWriter, Truncate};
+
+    /// A cursor over a vector that refuses to grow past `capacity` bytes.
+    struct BoundedCursor {
+        cursor: Cursor<Vec<u8>>,
+        capacity: u64,
+    }
+
+    impl Write for BoundedCursor {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let available = self.capacity.saturating_sub(self.cursor.position()) as usize;
+            if available == 0 && !buf.is_empty() {
+                return Err(io::Error::from(io::ErrorKind::StorageFull));
+            }
+
+            self.cursor.write(&buf[..buf.len().min(available)])
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Seek for BoundedCursor {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.cursor.seek(pos)
+        }
+    }
+
+    impl Truncate for BoundedCursor {
+        fn truncate(&mut self, len: u64) -> io::Result<()> {
+            self.cursor.truncate(len)
+        }
+    }
+
+    fn bounded(capacity: u64) -> BoundedCursor {
+        BoundedCursor {
+            cursor: Cursor::new(Vec::new()),
+            capacity,
+        }
+    }
+
+    #[test]
+    fn staging_multi_writer_rolls_back() {
+        let mut multi_writer =
+            StagingMultiWriter::new(vec![bounded(100), bounded(20), bounded(100)]);
+
+        multi_writer.write_all(b"Hello, ").unwrap();
+        multi_writer.commit().unwrap();
+        assert!(multi_writer.staged().is_empty());
+
+        multi_writer.write_all(b"world! Goodbye!").unwrap();
+        let err = multi_writer.commit().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::StorageFull);
+
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(multi_write_error.index(), 1);
+        assert_eq!(multi_writer.staged(), b"world! Goodbye!");
+
+        // The first writer is rolled back, the second is cut off after its partial write, and the third is never written.
+        for writer in multi_writer.get_ref() {
+            assert_eq!(writer.cursor.get_ref(), b"Hello, ");
+            assert_eq!(writer.cursor.position(), 7);
+        }
+
+        multi_writer.discard();
+        multi_writer.write_all(b"world!").unwrap();
+        multi_writer.commit().unwrap();
+
+        for writer in multi_writer.into_inner() {
+            assert_eq!(writer.cursor.into_inner(), b"Hello, world!");
+        }
+    }
+
+    #[test]
+    fn staging_multi_writer_borrowed() {
+        let mut first = Vec::new();
+        let mut second = Vec::new();
+        let mut multi_writer =
+            StagingMultiWriter::new(vec![Cursor::new(&mut first), Cursor::new(&mut second)]);
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+        multi_writer.flush().unwrap();
+        multi_writer.commit().unwrap();
+
+        assert_eq!(first, b"Hello, world!");
+        assert_eq!(second, b"Hello, world!");
+    }
+}
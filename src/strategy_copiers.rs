@@ -0,0 +1,327 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    io::{self, Read, Write},
+};
+
+/// What a [`DistributionStrategy`] knows about each writer of a [`StrategyCopier`] when selecting one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriterState {
+    bytes_written: u64,
+    copies: u64,
+}
+
+impl WriterState {
+    /// The number of bytes the writer has received so far.
+    ///
+    /// Bytes from a failed copy are not counted.
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    /// The number of successful copies into the writer so far.
+    pub fn copies(&self) -> u64 {
+        self.copies
+    }
+}
+
+/// Decides which writer of a [`StrategyCopier`] receives the next copy.
+///
+/// # Examples
+///
+/// A strategy always selecting the writer that has received the fewest copies:
+///
+/// ```
+/// use streams::{DistributionStrategy, WriterState};
+///
+/// struct FewestCopies;
+///
+/// impl DistributionStrategy for FewestCopies {
+///     fn select(&mut self, writers: &[WriterState]) -> usize {
+///         (0..writers.len()).min_by_key(|&i| writers[i].copies()).unwrap()
+///     }
+/// }
+/// ```
+pub trait DistributionStrategy {
+    /// Select the index of the writer to copy into, given the state of every writer, indexed like the writers.
+    ///
+    /// `writers` is never empty, and the returned index must be less than `writers.len()`.
+    fn select(&mut self, writers: &[WriterState]) -> usize;
+}
+
+/// Selects each writer in turn, wrapping around after the last, like [`RoundRobinCopier`](crate::RoundRobinCopier).
+#[derive(Debug, Clone, Default)]
+pub struct RoundRobinStrategy {
+    next: usize,
+}
+
+impl RoundRobinStrategy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl DistributionStrategy for RoundRobinStrategy {
+    fn select(&mut self, writers: &[WriterState]) -> usize {
+        let index = self.next % writers.len();
+        self.next = index + 1;
+
+        index
+    }
+}
+
+/// Selects the writer that has received the fewest bytes, like [`LeastLoadedCopier`](crate::LeastLoadedCopier).
+///
+/// Ties are broken by selecting the writer with the lowest index.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LeastLoadedStrategy;
+
+impl DistributionStrategy for LeastLoadedStrategy {
+    fn select(&mut self, writers: &[WriterState]) -> usize {
+        (0..writers.len())
+            .min_by_key(|&index| writers[index].bytes_written)
+            .unwrap_or(0)
+    }
+}
+
+/// Selects a writer uniformly at random.
+///
+/// The pseudorandom generator is not cryptographically secure.
+/// Use [`new_seeded`](RandomStrategy::new_seeded) for a reproducible sequence of selections.
+#[derive(Debug, Clone)]
+pub struct RandomStrategy {
+    rng: SplitMix64,
+}
+
+impl RandomStrategy {
+    /// Create a `RandomStrategy` with a randomly chosen seed.
+    pub fn new() -> Self {
+        Self::new_seeded(random_seed())
+    }
+
+    /// Create a `RandomStrategy` that makes the same selections for the same seed.
+    pub fn new_seeded(seed: u64) -> Self {
+        let rng = SplitMix64::new(seed);
+
+        Self { rng }
+    }
+}
+
+impl Default for RandomStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DistributionStrategy for RandomStrategy {
+    fn select(&mut self, writers: &[WriterState]) -> usize {
+        self.rng.below(writers.len())
+    }
+}
+
+/// Copies each reader into the writer selected by a [`DistributionStrategy`].
+///
+/// # Examples
+///
+/// ```
+/// use streams::{LeastLoadedStrategy, StrategyCopier};
+///
+/// let mut copier = StrategyCopier::new(vec![Vec::new(), Vec::new()], LeastLoadedStrategy);
+/// copier.copy(&mut &b"Hello, world!"[..])?;
+/// copier.copy(&mut &b"Hello"[..])?;
+/// copier.copy(&mut &b"world"[..])?;
+///
+/// assert_eq!(copier.into_inner(), [&b"Hello, world!"[..], b"Helloworld"]);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct StrategyCopier<W: Write, S: DistributionStrategy> {
+    writers: Vec<W>,
+    states: Vec<WriterState>,
+    strategy: S,
+}
+
+impl<W: Write, S: DistributionStrategy> StrategyCopier<W, S> {
+    pub fn new(writers: Vec<W>, strategy: S) -> Self {
+        let states = vec![WriterState::default(); writers.len()];
+
+        Self {
+            writers,
+            states,
+            strategy,
+        }
+    }
+
+    /// The state of each internal writer, indexed like the writers.
+    pub fn states(&self) -> &[WriterState] {
+        &self.states
+    }
+
+    /// Get a reference to the strategy.
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    /// Consumes the `StrategyCopier`, returning the internal writers.
+    pub fn into_inner(self) -> Vec<W> {
+        self.writers
+    }
+
+    /// Copy the entire contents of `reader` into the writer selected by the strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) if there are no internal writers
+    /// or the strategy selects an index out of range.
+    /// Errors during the copy are returned as-is.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "strategy copier has no writers to copy into",
+            ));
+        }
+
+        let index = self.strategy.select(&self.states);
+        let writer = self.writers.get_mut(index).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "strategy selected writer {index} out of {}",
+                    self.states.len()
+                ),
+            )
+        })?;
+
+        let copied = io::copy(reader, writer)?;
+        self.states[index].bytes_written += copied;
+        self.states[index].copies += 1;
+
+        Ok(copied)
+    }
+}
+
+/// A seed that differs between calls and processes, derived from the randomly keyed hasher of the standard library.
+pub(crate) fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// The SplitMix64 pseudorandom generator: tiny, fast and good enough for load balancing.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, using the multiply-shift reduction to avoid the bias of the modulo.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        ((u128::from(self.next_u64()) * n as u128) >> 64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::{
+        DistributionStrategy, LeastLoadedStrategy, RandomStrategy, RoundRobinStrategy,
+        StrategyCopier, WriterState,
+    };
+
+    #[test]
+    fn round_robin_strategy() {
+        let mut copier = StrategyCopier::new(
+            vec![Vec::<u8>::new(), Vec::new(), Vec::new()],
+            RoundRobinStrategy::new(),
+        );
+
+        for _ in 0..4 {
+            copier.copy(&mut &b"Hello, world!"[..]).unwrap();
+        }
+
+        let copies: Vec<_> = copier.states().iter().map(|s| s.copies()).collect();
+        assert_eq!(copies, [2, 1, 1]);
+        assert_eq!(copier.into_inner()[0], b"Hello, world!Hello, world!");
+    }
+
+    #[test]
+    fn least_loaded_strategy() {
+        let mut copier = StrategyCopier::new(
+            vec![Vec::<u8>::new(), Vec::new(), Vec::new()],
+            LeastLoadedStrategy,
+        );
+
+        copier.copy(&mut &[b'a'; 100][..]).unwrap();
+        copier.copy(&mut &[b'b'; 10][..]).unwrap();
+        copier.copy(&mut &[b'c'; 50][..]).unwrap();
+        copier.copy(&mut &[b'd'; 10][..]).unwrap();
+
+        let loads: Vec<_> = copier.states().iter().map(|s| s.bytes_written()).collect();
+        assert_eq!(loads, [100, 20, 50]);
+    }
+
+    #[test]
+    fn random_strategy() {
+        let selections = |seed| {
+            let mut strategy = RandomStrategy::new_seeded(seed);
+            let states = [WriterState::default(); 4];
+            (0..1000)
+                .map(|_| strategy.select(&states))
+                .collect::<Vec<_>>()
+        };
+
+        let first = selections(42);
+        assert_eq!(first, selections(42));
+        assert_ne!(first, selections(43));
+        for index in 0..4 {
+            let count = first.iter().filter(|&&i| i == index).count();
+            assert!(
+                (200..300).contains(&count),
+                "{index} selected {count} times"
+            );
+        }
+    }
+
+    #[test]
+    fn custom_strategy() {
+        /// Always selects the last writer.
+        struct Last;
+
+        impl DistributionStrategy for Last {
+            fn select(&mut self, writers: &[WriterState]) -> usize {
+                writers.len() - 1
+            }
+        }
+
+        /// Selects an index that doesn't exist.
+        struct OutOfRange;
+
+        impl DistributionStrategy for OutOfRange {
+            fn select(&mut self, writers: &[WriterState]) -> usize {
+                writers.len()
+            }
+        }
+
+        let mut copier = StrategyCopier::new(vec![Vec::<u8>::new(), Vec::new()], Last);
+        copier.copy(&mut &b"Hello"[..]).unwrap();
+        copier.copy(&mut &b"world"[..]).unwrap();
+        assert_eq!(copier.into_inner(), [&b""[..], b"Helloworld"]);
+
+        let mut copier = StrategyCopier::new(vec![Vec::<u8>::new()], OutOfRange);
+        let err = copier.copy(&mut &b"Hello"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(err.to_string(), "strategy selected writer 1 out of 1");
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::{self, Read, Write};
+
+/// Wraps a reader, copying every byte read into a side writer.
+///
+/// Each call to [`read`](TeeReader::read) fills the caller's buffer from the internal reader,
+/// then writes those same bytes to the side writer using [`write_all`](Write::write_all) before returning.
+/// This allows checksumming or logging a stream while it flows to its consumer, without buffering it.
+///
+/// # Errors
+///
+/// Errors from the internal reader are returned immediately.
+///
+/// If the side writer fails, the bytes already read are still returned,
+/// so the consumer never loses data from the internal reader.
+/// The side writer's error is instead stored and returned by the next call to [`read`](TeeReader::read),
+/// before any further bytes are read.
+/// The side writer is not retried with the bytes of the failed write, so it may be missing them.
+pub struct TeeReader<R: Read, W: Write> {
+    reader: R,
+    writer: W,
+    error: Option<io::Error>,
+}
+
+impl<R: Read, W: Write> TeeReader<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        let error = None;
+
+        Self {
+            reader,
+            writer,
+            error,
+        }
+    }
+
+    /// Get a reference to the internal reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Get a reference to the side writer.
+    pub fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `TeeReader`, returning the internal reader and the side writer.
+    pub fn into_inner(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+}
+
+impl<R: Read, W: Write> Read for TeeReader<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+
+        let n = self.reader.read(buf)?;
+
+        if let Err(e) = self.writer.write_all(&buf[..n]) {
+            self.error = Some(e);
+        }
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Read};
+
+    use crate::{mocks::FailingWriter, TeeReader};
+
+    #[test]
+    fn tee_reader() {
+        let input = b"Hello, world!";
+        let mut tee_reader = TeeReader::new(&input[..], Vec::new());
+
+        let mut output = Vec::new();
+        let mut buf = [0; 3];
+        loop {
+            match tee_reader.read(&mut buf).unwrap() {
+                0 => break,
+                n => output.extend_from_slice(&buf[..n]),
+            }
+        }
+
+        let (_, side) = tee_reader.into_inner();
+        assert_eq!(output, input);
+        assert_eq!(side, input);
+    }
+
+    #[test]
+    fn tee_reader_side_error_on_next_read() {
+        let input = b"Hello, world!";
+        let mut tee_reader =
+            TeeReader::new(&input[..], FailingWriter::new(io::ErrorKind::BrokenPipe));
+
+        let mut buf = [0; 5];
+        assert_eq!(tee_reader.read(&mut buf).unwrap(), 5);
+        assert_eq!(buf, *b"Hello");
+
+        let err = tee_reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+
+        // The failed read consumed nothing from the internal reader.
+        assert_eq!(*tee_reader.get_ref(), b", world!");
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    io::{self, Write},
+    sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+/// Wraps a writer, failing calls that take longer than a timeout with an error of kind [`TimedOut`](io::ErrorKind::TimedOut).
+///
+/// As [`Write`] has no native notion of timeouts, the internal writer is moved to a dedicated worker thread,
+/// which performs each [`write_all`](Write::write_all) and [`flush`](Write::flush) while the calling thread waits for at most the timeout.
+/// This lets a hanging destination, such as a stalled socket, be dropped from a [`MultiWriter`](crate::MultiWriter)
+/// using [`ErrorPolicy::ContinueAndCollect`](crate::ErrorPolicy::ContinueAndCollect) without blocking the others.
+///
+/// # Limitations
+///
+/// A timed out operation is abandoned, not cancelled: there is no portable way to interrupt a blocking system call,
+/// so it keeps running on the worker thread and may still complete later, even though an error was returned.
+/// Until it does, every further call fails immediately with [`TimedOut`](io::ErrorKind::TimedOut).
+/// Each buffer is also copied to be sent to the worker thread.
+///
+/// # Examples
+///
+/// ```
+/// use std::{io::Write, time::Duration};
+/// use streams::TimeoutWriter;
+///
+/// let mut timeout_writer = TimeoutWriter::new(Vec::new(), Duration::from_secs(1));
+/// timeout_writer.write_all(b"Hello, world!")?;
+///
+/// assert_eq!(timeout_writer.into_inner(), b"Hello, world!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct TimeoutWriter<W: Write + Send + 'static> {
+    requests: Option<Sender<Request>>,
+    responses: Receiver<io::Result<()>>,
+    worker: Option<JoinHandle<W>>,
+    timeout: Duration,
+    pending: bool,
+}
+
+enum Request {
+    Write(Vec<u8>),
+    Flush,
+}
+
+impl<W: Write + Send + 'static> TimeoutWriter<W> {
+    pub fn new(writer: W, timeout: Duration) -> Self {
+        let (requests, worker_requests) = mpsc::channel();
+        let (worker_responses, responses) = mpsc::channel();
+
+        let worker = thread::spawn(move || {
+            let mut writer = writer;
+
+            for request in worker_requests {
+                let result = match request {
+                    Request::Write(buf) => writer.write_all(&buf),
+                    Request::Flush => writer.flush(),
+                };
+
+                if worker_responses.send(result).is_err() {
+                    break;
+                }
+            }
+
+            writer
+        });
+
+        Self {
+            requests: Some(requests),
+            responses,
+            worker: Some(worker),
+            timeout,
+            pending: false,
+        }
+    }
+
+    /// The maximum duration of each call.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Consumes the `TimeoutWriter`, returning the internal writer.
+    ///
+    /// This blocks until any timed out operation has completed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal writer panicked.
+    pub fn into_inner(mut self) -> W {
+        drop(self.requests.take());
+
+        match self.worker.take().unwrap().join() {
+            Ok(writer) => writer,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    }
+
+    /// Send `request` to the worker thread and wait for at most the timeout for its result.
+    fn call(&mut self, request: Request) -> io::Result<()> {
+        // The result of an abandoned operation has already been reported as timed out.
+        if self.pending {
+            match self.responses.try_recv() {
+                Ok(_) => self.pending = false,
+                Err(mpsc::TryRecvError::Empty) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "previous operation still in progress",
+                    ))
+                }
+                Err(mpsc::TryRecvError::Disconnected) => return Err(worker_panicked()),
+            }
+        }
+
+        let requests = self.requests.as_ref().unwrap();
+        requests.send(request).map_err(|_| worker_panicked())?;
+
+        match self.responses.recv_timeout(self.timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                self.pending = true;
+                Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "operation timed out",
+                ))
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(worker_panicked()),
+        }
+    }
+}
+
+fn worker_panicked() -> io::Error {
+    io::Error::other("writer panicked on its worker thread")
+}
+
+impl<W: Write + Send + 'static> Write for TimeoutWriter<W> {
+    /// Write the entire buffer on the worker thread, waiting for at most the timeout.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`TimedOut`](io::ErrorKind::TimedOut) if the write takes longer than the timeout,
+    /// or a previously timed out operation has not completed yet.
+    /// Errors from the internal writer are returned as-is.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        self.call(Request::Write(buf.to_vec()))?;
+
+        Ok(buf.len())
+    }
+
+    /// Flush the internal writer on the worker thread, waiting for at most the timeout.
+    ///
+    /// # Errors
+    ///
+    /// See [`write`](TimeoutWriter::write).
+    fn flush(&mut self) -> io::Result<()> {
+        self.call(Request::Flush)
+    }
+}
+
+impl<W: Write + Send + 'static> Drop for TimeoutWriter<W> {
+    /// Stop the worker thread once it has completed any timed out operation, without waiting for it.
+    fn drop(&mut self) {
+        drop(self.requests.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Write},
+        thread,
+        time::Duration,
+    };
+
+    use crate::{mocks::SyncSharedWriter, ErrorPolicy, MultiWriter, TimeoutWriter};
+
+    /// Sleeps for the given duration before every write.
+    struct SlowWriter {
+        delay: Duration,
+        buf: Vec<u8>,
+    }
+
+    impl Write for SlowWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            thread::sleep(self.delay);
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn timeout_writer_times_out() {
+        let slow_writer = SlowWriter {
+            delay: Duration::from_millis(300),
+            buf: Vec::new(),
+        };
+        let mut timeout_writer = TimeoutWriter::new(slow_writer, Duration::from_millis(50));
+
+        let err = timeout_writer.write(b"Hello").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        let err = timeout_writer.write(b", ").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+
+        // The abandoned write completes eventually, after which the writer can be used again.
+        thread::sleep(Duration::from_millis(400));
+        timeout_writer.flush().unwrap();
+
+        assert_eq!(timeout_writer.into_inner().buf, b"Hello");
+    }
+
+    #[test]
+    fn timeout_writer_in_multi_writer() {
+        let shared = SyncSharedWriter::new();
+        let mut fast = TimeoutWriter::new(shared.clone(), Duration::from_secs(5));
+        let mut slow = TimeoutWriter::new(
+            SlowWriter {
+                delay: Duration::from_millis(300),
+                buf: Vec::new(),
+            },
+            Duration::from_millis(50),
+        );
+        let mut multi_writer = MultiWriter::with_error_policy(
+            vec![&mut fast, &mut slow],
+            ErrorPolicy::ContinueAndCollect,
+        );
+
+        multi_writer.write_all(b"Hello, world!").unwrap();
+
+        let errors = multi_writer.take_errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, 1);
+        assert_eq!(errors[0].1.kind(), io::ErrorKind::TimedOut);
+
+        assert_eq!(fast.into_inner().contents(), b"Hello, world!");
+    }
+}
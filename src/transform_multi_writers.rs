@@ -0,0 +1,152 @@
+use std::io::{self, Write};
+
+use crate::MultiWriteError;
+
+/// A transform applied by a [`TransformMultiWriter`] to each buffer before forwarding it to its writer.
+pub type Transform<'a> = Box<dyn FnMut(&[u8]) -> Vec<u8> + 'a>;
+
+/// Provides a single [`Writer`](Write) that writes an independently transformed version of each buffer to multiple writers sequentially.
+///
+/// Each internal writer is paired with its own [`Transform`], which is called on every buffer written
+/// before the result is written to that writer using [`write_all`](Write::write_all).
+/// This is equivalent to wrapping each writer in a [`MapWriter`](crate::MapWriter) inside a [`MultiWriter`](crate::MultiWriter),
+/// but keeps the transforms of differing types in a single fan-out.
+///
+/// Like for a [`MapWriter`](crate::MapWriter), the transforms see the stream in whatever chunks it was written in,
+/// and the returned `usize` is relative to the input: it is always exactly `buf.len()` on success.
+///
+/// # Errors
+///
+/// If any of the internal writers fail, execution will immediately halt and the error will be returned,
+/// wrapping a [`MultiWriteError`] reporting the index of the writer that failed, as with [`ErrorPolicy::FailFast`](crate::ErrorPolicy::FailFast).
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{Transform, TransformMultiWriter};
+///
+/// let mut raw = Vec::new();
+/// let mut upper = Vec::new();
+/// let mut multi_writer = TransformMultiWriter::new(vec![
+///     (Box::new(|buf: &[u8]| buf.to_vec()) as Transform, &mut raw as &mut dyn Write),
+///     (Box::new(|buf: &[u8]| buf.to_ascii_uppercase()), &mut upper),
+/// ]);
+/// multi_writer.write_all(b"Hello, world!")?;
+///
+/// drop(multi_writer);
+/// assert_eq!(raw, b"Hello, world!");
+/// assert_eq!(upper, b"HELLO, WORLD!");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct TransformMultiWriter<'a> {
+    writers: Vec<(Transform<'a>, &'a mut dyn Write)>,
+}
+
+impl<'a> TransformMultiWriter<'a> {
+    pub fn new(writers: Vec<(Transform<'a>, &'a mut dyn Write)>) -> Self {
+        Self { writers }
+    }
+
+    /// The number of internal writers.
+    pub fn len(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns `true` if there are no internal writers.
+    pub fn is_empty(&self) -> bool {
+        self.writers.is_empty()
+    }
+
+    /// Consumes the `TransformMultiWriter`, returning the internal writers and their transforms.
+    pub fn into_inner(self) -> Vec<(Transform<'a>, &'a mut dyn Write)> {
+        self.writers
+    }
+}
+
+impl<'a> Write for TransformMultiWriter<'a> {
+    /// Transform a buffer for each internal writer and write the result into it, sequentially.
+    ///
+    /// The returned `usize` will always be exactly the length of the input buffer (`buf.len()`).
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for (index, (transform, writer)) in self.writers.iter_mut().enumerate() {
+            writer
+                .write_all(&transform(buf))
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(buf.len())
+    }
+
+    /// Flush each internal writer sequentially.
+    fn flush(&mut self) -> io::Result<()> {
+        for (index, (_, writer)) in self.writers.iter_mut().enumerate() {
+            writer
+                .flush()
+                .map_err(|e| MultiWriteError::new(index, e).into_io_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::{mocks::FailingWriter, MultiWriteError, Transform, TransformMultiWriter};
+
+    fn hex(buf: &[u8]) -> Vec<u8> {
+        buf.iter()
+            .flat_map(|b| format!("{b:02x}").into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn transform_multi_writer() {
+        let mut raw = Vec::<u8>::new();
+        let mut upper = Vec::<u8>::new();
+        let mut hexed = Vec::<u8>::new();
+        let mut multi_writer = TransformMultiWriter::new(vec![
+            (
+                Box::new(|buf: &[u8]| buf.to_vec()) as Transform,
+                &mut raw as &mut dyn Write,
+            ),
+            (Box::new(|buf: &[u8]| buf.to_ascii_uppercase()), &mut upper),
+            (Box::new(hex), &mut hexed),
+        ]);
+
+        assert_eq!(multi_writer.write(b"Hi, ").unwrap(), 4);
+        multi_writer.write_all(b"you!").unwrap();
+        multi_writer.flush().unwrap();
+
+        drop(multi_writer);
+        assert_eq!(raw, b"Hi, you!");
+        assert_eq!(upper, b"HI, YOU!");
+        assert_eq!(hexed, b"48692c20796f7521");
+    }
+
+    #[test]
+    fn transform_multi_writer_reports_failing_index() {
+        let mut sink = Vec::<u8>::new();
+        let mut failing = FailingWriter::new(io::ErrorKind::BrokenPipe);
+        let mut multi_writer = TransformMultiWriter::new(vec![
+            (
+                Box::new(|buf: &[u8]| buf.to_vec()) as Transform,
+                &mut sink as &mut dyn Write,
+            ),
+            (Box::new(hex), &mut failing),
+        ]);
+
+        let err = multi_writer.write(b"Hello, world!").unwrap_err();
+        let multi_write_error = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<MultiWriteError>())
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(multi_write_error.index(), 1);
+
+        drop(multi_writer);
+        assert_eq!(sink, b"Hello, world!");
+    }
+}
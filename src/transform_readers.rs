@@ -0,0 +1,147 @@
+use std::io::{self, Read};
+
+use crate::DEFAULT_BUF_SIZE;
+
+/// Wraps a reader, applying `transform` to each chunk read from it, so the transformed stream can be fanned out once,
+/// e.g. by passing it to [`copy_into_many`](fn@crate::copy_into_many).
+///
+/// The reader is read in chunks of at most [`DEFAULT_BUF_SIZE`] bytes, and `transform` sees the stream in whatever chunks the reader returns.
+/// Its output may be longer or shorter than its input: output that doesn't fit into the buffer passed to [`read`](TransformReader::read)
+/// is kept for the following reads, and chunks transformed into nothing are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+/// use streams::TransformReader;
+///
+/// let mut reader = TransformReader::new(&b"Hello, world!"[..], |buf: &[u8]| buf.to_ascii_uppercase());
+/// let mut output = String::new();
+/// reader.read_to_string(&mut output)?;
+///
+/// assert_eq!(output, "HELLO, WORLD!");
+/// # Ok::<(), io::Error>(())
+/// ```
+pub struct TransformReader<R: Read, F: FnMut(&[u8]) -> Vec<u8>> {
+    reader: R,
+    transform: F,
+    pending: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: Read, F: FnMut(&[u8]) -> Vec<u8>> TransformReader<R, F> {
+    pub fn new(reader: R, transform: F) -> Self {
+        let pending = Vec::new();
+        let pos = 0;
+
+        Self {
+            reader,
+            transform,
+            pending,
+            pos,
+        }
+    }
+
+    /// Get a reference to the internal reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Consumes the `TransformReader`, returning the internal reader.
+    ///
+    /// Transformed output not read yet is lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, F: FnMut(&[u8]) -> Vec<u8>> Read for TransformReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        while self.pos == self.pending.len() {
+            let mut chunk = [0; DEFAULT_BUF_SIZE];
+            let n = self.reader.read(&mut chunk)?;
+
+            if n == 0 {
+                return Ok(0);
+            }
+
+            self.pending = (self.transform)(&chunk[..n]);
+            self.pos = 0;
+        }
+
+        let n = buf.len().min(self.pending.len() - self.pos);
+        buf[..n].copy_from_slice(&self.pending[self.pos..self.pos + n]);
+        self.pos += n;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use crate::TransformReader;
+
+    fn double(buf: &[u8]) -> Vec<u8> {
+        buf.iter().flat_map(|&b| [b, b]).collect()
+    }
+
+    #[test]
+    fn transform_reader_into_many() {
+        let input = b"Hello, world!";
+        let mut reader = TransformReader::new(&input[..], double);
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+
+        let copied = crate::copy_into_many(
+            &mut reader,
+            writers.iter_mut().map(|w| w as &mut dyn Write).collect(),
+        )
+        .unwrap();
+
+        assert_eq!(copied, 26);
+        for writer in writers {
+            assert_eq!(writer, b"HHeelllloo,,  wwoorrlldd!!");
+        }
+    }
+
+    #[test]
+    fn transform_reader_small_reads() {
+        // The doubled output of each chunk exceeds the read buffer, so it is carried over to the following reads.
+        let input = b"Hello, world!".repeat(1000);
+        let mut reader = TransformReader::new(&input[..], double);
+        let mut output = Vec::new();
+        let mut buf = [0; 3];
+
+        loop {
+            let n = reader.read(&mut buf).unwrap();
+            if n == 0 {
+                break;
+            }
+            output.extend_from_slice(&buf[..n]);
+        }
+
+        assert_eq!(output, double(&input));
+    }
+
+    #[test]
+    fn transform_reader_empty_output() {
+        // A chunk transformed into nothing is skipped rather than reported as end of file.
+        let source = (&b"drop"[..]).chain(&b"keep"[..]);
+        let mut reader = TransformReader::new(source, |buf: &[u8]| {
+            if buf == b"drop" {
+                Vec::new()
+            } else {
+                buf.to_vec()
+            }
+        });
+        let mut output = Vec::new();
+
+        assert_eq!(reader.read_to_end(&mut output).unwrap(), 4);
+        assert_eq!(output, b"keep");
+    }
+}
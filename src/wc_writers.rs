@@ -0,0 +1,212 @@
+use std::io::{self, IoSlice, Write};
+
+/// Line, word and byte counts, as reported by `wc`. See [`WcWriter`].
+///
+/// With the `serde` feature enabled, it can be serialized, with a field for each of its accessors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WcCounts {
+    lines: u64,
+    words: u64,
+    bytes: u64,
+}
+
+impl WcCounts {
+    /// The number of newlines (`\n`).
+    pub fn lines(&self) -> u64 {
+        self.lines
+    }
+
+    /// The number of maximal runs of non-whitespace bytes.
+    pub fn words(&self) -> u64 {
+        self.words
+    }
+
+    /// The number of bytes.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+}
+
+/// Wraps a writer, counting the lines, words and bytes passing through it like `wc` does.
+///
+/// Like for a [`CountingWriter`](crate::CountingWriter), only bytes the internal writer actually accepted are counted.
+/// Words are separated by runs of ASCII whitespace (space, `\t`, `\n`, `\v`, `\f` and `\r`), matching GNU `wc` in the C locale,
+/// and a word or whitespace run split across writes is counted once.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use streams::{MultiWriter, WcWriter};
+///
+/// let mut wc_writer = WcWriter::new(Vec::new());
+/// let mut multi_writer = MultiWriter::new(vec![&mut wc_writer]);
+/// multi_writer.write_all(b"Hello, wo")?;
+/// multi_writer.write_all(b"rld!\n")?;
+///
+/// let counts = wc_writer.counts();
+/// assert_eq!((counts.lines(), counts.words(), counts.bytes()), (1, 2, 14));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct WcWriter<W: Write> {
+    writer: W,
+    counts: WcCounts,
+    in_word: bool,
+}
+
+impl<W: Write> WcWriter<W> {
+    pub fn new(writer: W) -> Self {
+        let counts = WcCounts::default();
+        let in_word = false;
+
+        Self {
+            writer,
+            counts,
+            in_word,
+        }
+    }
+
+    /// The counts of the bytes the internal writer has accepted so far.
+    pub fn counts(&self) -> WcCounts {
+        self.counts
+    }
+
+    /// Get a reference to the internal writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Get a mutable reference to the internal writer.
+    ///
+    /// Bytes written directly to the internal writer are not counted.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Consumes the `WcWriter`, returning the internal writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Count the accepted bytes `buf`, carrying whether the last byte was part of a word over to the next call.
+    fn count(&mut self, buf: &[u8]) {
+        for &byte in buf {
+            if byte == b'\n' {
+                self.counts.lines += 1;
+            }
+
+            // `u8::is_ascii_whitespace` doesn't include the vertical tab, which `wc` treats as whitespace.
+            let is_space = byte.is_ascii_whitespace() || byte == b'\x0b';
+            if !is_space && !self.in_word {
+                self.counts.words += 1;
+            }
+            self.in_word = !is_space;
+        }
+
+        self.counts.bytes += buf.len() as u64;
+    }
+}
+
+impl<W: Write> Write for WcWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.writer.write(buf)?;
+        self.count(&buf[..n]);
+
+        Ok(n)
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let mut remaining = self.writer.write_vectored(bufs)?;
+        let n = remaining;
+
+        for buf in bufs {
+            let len = remaining.min(buf.len());
+            self.count(&buf[..len]);
+            remaining -= len;
+        }
+
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{IoSlice, Write};
+
+    use crate::WcWriter;
+
+    const PARAGRAPH: &[u8] =
+        b"The quick brown fox\tjumps over\n\nthe  lazy dog.\r\nPack my box with\x0bfive dozen liquor jugs";
+
+    #[test]
+    fn wc_writer() {
+        let mut wc_writer = WcWriter::new(Vec::new());
+
+        // Split in the middle of a word, which must be counted once.
+        wc_writer.write_all(&PARAGRAPH[..40]).unwrap();
+        wc_writer.write_all(&PARAGRAPH[40..]).unwrap();
+
+        // `wc` reports "3 17 87" for the same input.
+        let counts = wc_writer.counts();
+        assert_eq!(counts.lines(), 3);
+        assert_eq!(counts.words(), 17);
+        assert_eq!(counts.bytes(), 87);
+        assert_eq!(wc_writer.into_inner(), PARAGRAPH);
+    }
+
+    #[test]
+    fn wc_writer_split_boundaries() {
+        let mut whole = WcWriter::new(Vec::new());
+        whole.write_all(PARAGRAPH).unwrap();
+
+        // Every word and whitespace run is split across writes.
+        let mut bytewise = WcWriter::new(Vec::new());
+        for byte in PARAGRAPH.chunks(1) {
+            bytewise.write_all(byte).unwrap();
+        }
+
+        let mut vectored = WcWriter::new(Vec::new());
+        let (a, b) = PARAGRAPH.split_at(42);
+        let n = vectored
+            .write_vectored(&[IoSlice::new(a), IoSlice::new(b)])
+            .unwrap();
+        assert_eq!(n, PARAGRAPH.len());
+
+        assert_eq!(bytewise.counts(), whole.counts());
+        assert_eq!(vectored.counts(), whole.counts());
+    }
+
+    #[test]
+    fn wc_writer_counts_accepted_bytes() {
+        let mut buf = [0u8; 8];
+        let mut wc_writer = WcWriter::new(&mut buf[..]);
+
+        assert_eq!(wc_writer.write(b"one two three\n").unwrap(), 8);
+
+        let counts = wc_writer.counts();
+        assert_eq!((counts.lines(), counts.words(), counts.bytes()), (0, 2, 8));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn wc_counts_serde() {
+        let mut wc_writer = WcWriter::new(Vec::new());
+        wc_writer.write_all(PARAGRAPH).unwrap();
+
+        let counts = wc_writer.counts();
+        let json = serde_json::to_value(counts).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "lines": 3, "words": 17, "bytes": 87 })
+        );
+        assert_eq!(
+            serde_json::from_value::<crate::WcCounts>(json).unwrap(),
+            counts
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use std::io::{self, Read, Write};
+
+/// Copies each reader into one of its internal writers, distributing copies proportionally to the writers' weights.
+///
+/// A writer with weight 3 receives three copies for every one received by a writer with weight 1.
+/// Selection uses smooth weighted round robin (as used by nginx),
+/// which spreads each writer's copies evenly over time instead of selecting the same writer several times in a row:
+/// with weights `[1, 2, 3]`, the writers are selected in the repeating sequence `[2, 1, 0, 2, 1, 2]`.
+///
+/// Writers with a weight of zero are never selected.
+pub struct WeightedRoundRobinCopier<'a> {
+    writers: Vec<&'a mut dyn Write>,
+    weights: Vec<u32>,
+    current_weights: Vec<i64>,
+}
+
+impl<'a> WeightedRoundRobinCopier<'a> {
+    pub fn new(writers_with_weights: Vec<(&'a mut dyn Write, u32)>) -> Self {
+        let (writers, weights): (Vec<_>, Vec<_>) = writers_with_weights.into_iter().unzip();
+        let current_weights = vec![0; writers.len()];
+
+        Self {
+            writers,
+            weights,
+            current_weights,
+        }
+    }
+
+    /// The indices of the writers the next `n` calls to [`copy`](WeightedRoundRobinCopier::copy) will select, in order.
+    ///
+    /// This does not advance the selection.
+    pub fn upcoming(&self, n: usize) -> Vec<usize> {
+        let mut current_weights = self.current_weights.clone();
+
+        (0..n)
+            .map_while(|_| select(&self.weights, &mut current_weights))
+            .collect()
+    }
+
+    /// Copy the entire contents of `reader` into the next selected writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`InvalidInput`](io::ErrorKind::InvalidInput)
+    /// if there are no internal writers, or if all of them have a weight of zero.
+    pub fn copy<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<u64> {
+        if self.writers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "weighted round-robin copier has no writers to copy into",
+            ));
+        }
+
+        let index = select(&self.weights, &mut self.current_weights).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "weighted round-robin copier has no writers with a weight greater than zero",
+            )
+        })?;
+
+        io::copy(reader, self.writers[index])
+    }
+}
+
+/// Advance the smooth weighted round robin state, returning the selected index.
+///
+/// Every writer's current weight is increased by its weight,
+/// then the writer with the highest current weight is selected (the lowest index among ties)
+/// and its current weight is decreased by the total weight.
+fn select(weights: &[u32], current_weights: &mut [i64]) -> Option<usize> {
+    let total: i64 = weights.iter().map(|&w| i64::from(w)).sum();
+
+    if total == 0 {
+        return None;
+    }
+
+    for (current, &weight) in current_weights.iter_mut().zip(weights) {
+        *current += i64::from(weight);
+    }
+
+    let mut selected = 0;
+    for (index, &current) in current_weights.iter().enumerate() {
+        if current > current_weights[selected] {
+            selected = index;
+        }
+    }
+
+    current_weights[selected] -= total;
+
+    Some(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::WeightedRoundRobinCopier;
+
+    #[test]
+    fn weighted_round_robin_copier() {
+        let mut writers = [Vec::<u8>::new(), Vec::new(), Vec::new()];
+        let mut copier = WeightedRoundRobinCopier::new(
+            writers
+                .iter_mut()
+                .zip([1, 2, 3])
+                .map(|(w, weight)| (w as &mut dyn Write, weight))
+                .collect(),
+        );
+
+        assert_eq!(copier.upcoming(6), [2, 1, 0, 2, 1, 2]);
+
+        let input = b"Hello, world!";
+        for _ in 0..12 {
+            copier.copy(&mut &input[..]).unwrap();
+        }
+
+        assert_eq!(writers[0].len(), 2 * input.len());
+        assert_eq!(writers[1].len(), 4 * input.len());
+        assert_eq!(writers[2].len(), 6 * input.len());
+    }
+
+    #[test]
+    fn weighted_round_robin_copier_zero_weights() {
+        let mut writer = Vec::<u8>::new();
+        let mut copier = WeightedRoundRobinCopier::new(vec![(&mut writer, 0)]);
+
+        assert!(copier.upcoming(3).is_empty());
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "weighted round-robin copier has no writers with a weight greater than zero"
+        );
+    }
+
+    #[test]
+    fn weighted_round_robin_copier_without_writers() {
+        let mut copier = WeightedRoundRobinCopier::new(Vec::new());
+
+        let err = copier.copy(&mut &b"Hello, world!"[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "weighted round-robin copier has no writers to copy into"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Helpers shared by the integration tests running the examples.
+
+use std::{
+    env, fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+    thread,
+};
+
+/// A command running the example `name`.
+///
+/// `cargo test` only builds the examples when testing all targets, not for `cargo test --test <name>`,
+/// so the example is built here, using the cargo running the tests.
+pub fn example(name: &str) -> Command {
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "build",
+            "--quiet",
+            "--message-format=json",
+            "--example",
+            name,
+        ])
+        .arg("--manifest-path")
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        .stderr(Stdio::inherit())
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "failed to build example {name}");
+
+    // Find the path of the built binary among the messages, as the target directory may be configured anywhere.
+    let executable = serde_json::Deserializer::from_slice(&output.stdout)
+        .into_iter::<serde_json::Value>()
+        .map(Result::unwrap)
+        .filter(|message| {
+            message["reason"] == "compiler-artifact" && message["target"]["name"] == name
+        })
+        .find_map(|message| message["executable"].as_str().map(PathBuf::from))
+        .unwrap();
+
+    Command::new(executable)
+}
+
+/// A fresh temporary directory unique to the calling test.
+pub fn temp_dir(example: &str, name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("streams-{example}-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+/// Run `command`, feeding it `input` on standard input and collecting its output.
+pub fn run(command: &mut Command, input: &[u8]) -> Output {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // Write from another thread while the output is collected, so neither side blocks on a full pipe.
+    // The child may exit before consuming all of its input, so write errors are ignored.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_vec();
+    let writer = thread::spawn(move || {
+        let _ = stdin.write_all(&input);
+    });
+
+    let output = child.wait_with_output().unwrap();
+    writer.join().unwrap();
+
+    output
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use std::{fs, path::PathBuf, process::Command};
+
+use common::run;
+
+/// The `logsplit` example binary.
+fn logsplit() -> Command {
+    common::example("logsplit")
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    common::temp_dir("logsplit", name)
+}
+
+/// Strip the timestamp prefix from each line.
+fn messages(log: &str) -> Vec<&str> {
+    log.lines()
+        .map(|line| {
+            assert!(line.starts_with('['), "{line}");
+            line.split_once("] ").unwrap().1
+        })
+        .collect()
+}
+
+const INPUT: &[u8] = b"starting\nlistening on port 8080\naccepted connection\nshutting down\n";
+const MESSAGES: [&str; 4] = [
+    "starting",
+    "listening on port 8080",
+    "accepted connection",
+    "shutting down",
+];
+
+#[test]
+fn logsplit_rotates_log_file() {
+    let dir = temp_dir("rotates");
+    let log_file = dir.join("app.log");
+
+    let output = run(
+        logsplit()
+            .arg("--log-file")
+            .arg(&log_file)
+            .arg("--max-bytes")
+            .arg("64"),
+        INPUT,
+    );
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(messages(&stderr), MESSAGES);
+
+    // Reassembling the rotated files in order yields the same log as standard error.
+    let mut log = fs::read_to_string(&log_file).unwrap();
+    assert_eq!(log.len(), 64);
+    for index in 1.. {
+        match fs::read_to_string(format!("{}.{index}", log_file.display())) {
+            Ok(rotated) => log.push_str(&rotated),
+            Err(_) => break,
+        }
+    }
+    assert_eq!(log, stderr);
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn logsplit_without_log_file() {
+    let dir = temp_dir("unopenable");
+    let log_file = dir.join("missing").join("app.log");
+
+    let output = run(logsplit().arg("--log-file").arg(&log_file), INPUT);
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let (warning, log) = stderr.split_once('\n').unwrap();
+    assert!(
+        warning.contains(&log_file.display().to_string()),
+        "{warning}"
+    );
+    assert_eq!(messages(log), MESSAGES);
+
+    fs::remove_dir_all(dir).unwrap();
+}
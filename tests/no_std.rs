@@ -0,0 +1,38 @@
+//! Uses the `embedded-io` API through `core` and `alloc` only, as a `no_std` crate would.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use embedded_io::{ErrorKind, Write};
+use streams::{MultiWriter, RoundRobinCopier};
+
+#[test]
+fn no_std_multi_writer() {
+    let mut buf1 = [0u8; 13];
+    let mut buf2 = [0u8; 13];
+    let mut multi_writer = MultiWriter::from_writers(vec![&mut buf1[..], &mut buf2[..]]);
+
+    multi_writer.write_all(b"Hello, world!").unwrap();
+    assert_eq!(multi_writer.bytes_written(), [13, 13]);
+
+    let err = multi_writer.write_all(b"!").unwrap_err();
+    assert_eq!(err.index(), 0);
+    assert_eq!(embedded_io::Error::kind(&err), ErrorKind::WriteZero);
+    assert_eq!(buf1, *b"Hello, world!");
+    assert_eq!(buf2, *b"Hello, world!");
+}
+
+#[test]
+fn no_std_round_robin_copier() {
+    let mut buf1 = [0u8; 5];
+    let mut buf2 = [0u8; 6];
+    let mut copier = RoundRobinCopier::new(vec![&mut buf1[..], &mut buf2[..]]);
+
+    assert_eq!(copier.copy_embedded(&mut &b"Hello"[..]).unwrap(), 5);
+    assert_eq!(copier.copy_embedded(&mut &b"world!"[..]).unwrap(), 6);
+    assert_eq!(copier.current_index(), 0);
+    assert_eq!(buf1, *b"Hello");
+    assert_eq!(buf2, *b"world!");
+}
@@ -0,0 +1,40 @@
+use std::io::{Read, Write};
+
+use streams::prelude::*;
+
+#[test]
+fn prelude_items() {
+    let mut output = Vec::new();
+    let mut counting_writer = CountingWriter::new(Vec::new());
+    let mut multi_writer = MultiWriter::with_error_policy(
+        vec![&mut output, &mut counting_writer],
+        ErrorPolicy::ContinueAndCollect,
+    );
+    multi_writer.write_all(b"Hello, ").unwrap();
+
+    let mut reader1 = &b"wor"[..];
+    let mut reader2 = &b"ld!"[..];
+    let mut multi_reader = MultiReader::new(vec![&mut reader1, &mut reader2]);
+    let mut tee_reader = TeeReader::new(&mut multi_reader, Vec::new());
+    let mut rest = Vec::new();
+    tee_reader.read_to_end(&mut rest).unwrap();
+
+    let copied = copy_into_all!(&mut &rest[..], output, counting_writer).unwrap();
+    assert_eq!(copied, 6);
+    assert_eq!(output, b"Hello, world!");
+    assert_eq!(counting_writer.bytes_written(), 13);
+
+    let mut copier = RoundRobinCopier::new(vec![Vec::new(), Vec::new()]);
+    copier.copy(&mut &b"Hello"[..]).unwrap();
+    assert_eq!(copier.current_index(), 1);
+}
+
+#[test]
+fn prelude_sink_finish() {
+    let mut multi_writer = MultiWriter::from_writers(vec![Vec::new(), Vec::new()]);
+    multi_writer.write_chunk(b"Hello, world!").unwrap();
+    multi_writer.finish().unwrap();
+
+    let copied = copy_into_sinks(&mut &b"Hello"[..], vec![Vec::new()]).unwrap();
+    assert_eq!(copied, 5);
+}
@@ -0,0 +1,63 @@
+mod common;
+
+use std::{fs, path::PathBuf, process::Command};
+
+use common::run;
+
+/// The `tee` example binary.
+fn tee() -> Command {
+    common::example("tee")
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    common::temp_dir("tee", name)
+}
+
+#[test]
+fn tee_skips_unopenable_path() {
+    let dir = temp_dir("unopenable");
+    let good = dir.join("good.txt");
+    let bad = dir.join("missing").join("bad.txt");
+
+    let output = run(tee().arg(&good).arg(&bad), b"Hello, world!");
+
+    assert!(!output.status.success());
+    assert_eq!(output.stdout, b"Hello, world!");
+    assert_eq!(fs::read(&good).unwrap(), b"Hello, world!");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains(&bad.display().to_string()), "{stderr}");
+
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn tee_output_error_modes() {
+    let dir = temp_dir("modes");
+    let good = dir.join("good.txt");
+    let input = vec![b'x'; 100_000];
+
+    // `/dev/full` fails every write, but `warn` keeps writing to the other outputs.
+    let output = run(
+        tee().arg("--output-error=warn").arg("/dev/full").arg(&good),
+        &input,
+    );
+    assert!(!output.status.success());
+    assert_eq!(output.stdout, input);
+    assert_eq!(fs::read(&good).unwrap(), input);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(stderr.matches("/dev/full").count(), 1, "{stderr}");
+
+    // `exit` stops at the first failure.
+    let output = run(
+        tee().arg("--output-error=exit").arg("/dev/full").arg(&good),
+        &input,
+    );
+    assert!(!output.status.success());
+    assert!(output.stdout.len() < input.len());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("/dev/full"));
+
+    fs::remove_dir_all(dir).unwrap();
+}